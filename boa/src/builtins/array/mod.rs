@@ -16,7 +16,7 @@ mod tests;
 use crate::{
     builtins::array::array_iterator::{ArrayIterationKind, ArrayIterator},
     builtins::BuiltIn,
-    builtins::Number,
+    builtins::{iterable::IteratorRecord, Number},
     object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData, PROTOTYPE},
     property::{Attribute, DataDescriptor},
     symbol::WellKnownSymbols,
@@ -25,8 +25,9 @@ use crate::{
 };
 use num_traits::*;
 use std::{
-    cmp::{max, min},
+    cmp::{max, min, Ordering},
     convert::{TryFrom, TryInto},
+    iter,
 };
 
 /// JavaScript `Array` built-in implementation.
@@ -79,19 +80,24 @@ impl BuiltIn for Array {
         .method(Self::index_of, "indexOf", 1)
         .method(Self::last_index_of, "lastIndexOf", 1)
         .method(Self::includes_value, "includes", 1)
+        .method(Self::at, "at", 1)
         .method(Self::map, "map", 1)
         .method(Self::fill, "fill", 1)
+        .method(Self::copy_within, "copyWithin", 2)
         .method(Self::for_each, "forEach", 1)
         .method(Self::filter, "filter", 1)
         .method(Self::pop, "pop", 0)
         .method(Self::join, "join", 1)
         .method(Self::to_string, "toString", 0)
         .method(Self::reverse, "reverse", 0)
+        .method(Self::sort, "sort", 1)
         .method(Self::shift, "shift", 0)
         .method(Self::unshift, "unshift", 1)
         .method(Self::every, "every", 1)
         .method(Self::find, "find", 1)
         .method(Self::find_index, "findIndex", 1)
+        .method(Self::find_last, "findLast", 1)
+        .method(Self::find_last_index, "findLastIndex", 1)
         .method(Self::flat, "flat", 0)
         .method(Self::flat_map, "flatMap", 1)
         .method(Self::slice, "slice", 2)
@@ -103,6 +109,7 @@ impl BuiltIn for Array {
         // Static Methods
         .static_method(Self::is_array, "isArray", 1)
         .static_method(Self::of, "of", 0)
+        .static_method(Self::from, "from", 1)
         .build();
 
         (Self::NAME, array.into(), Self::attribute())
@@ -379,6 +386,94 @@ impl Array {
         Ok(array)
     }
 
+    /// `Array.from(items[, mapFn[, thisArg]])`
+    ///
+    /// The Array.from method creates a new Array instance from an iterable or array-like object,
+    /// optionally calling `mapFn` on every element and its index.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.from
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/from
+    pub(crate) fn from(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let items = args.get(0).cloned().unwrap_or_default();
+        let this_arg = args.get(2).cloned().unwrap_or_default();
+
+        // 2. If mapfn is undefined, let mapping be false.
+        // 3. Else, if IsCallable(mapfn) is false, throw a TypeError exception.
+        let map_fn = match args.get(1) {
+            None => None,
+            Some(map_fn) if map_fn.is_undefined() => None,
+            Some(map_fn) if map_fn.is_function() => Some(map_fn),
+            Some(_) => return context.throw_type_error("Array.from: mapFn is not callable"),
+        };
+
+        let constructor = this.as_object().filter(GcObject::is_constructable);
+
+        // 4. Let usingIterator be ? GetMethod(items, @@iterator).
+        let items_object = items.to_object(context)?;
+        let using_iterator = items_object.get_method(context, WellKnownSymbols::iterator())?;
+
+        // 5. If usingIterator is not undefined, then
+        if let Some(using_iterator) = using_iterator {
+            let array = match constructor {
+                Some(ref constructor) => constructor.construct(&[], this, context)?,
+                None => Self::array_create(0, None, context),
+            };
+
+            let iterator_object = context.call(&using_iterator.into(), &items, &[])?;
+            let next_function = iterator_object.get_field("next", context)?;
+            let iterator_record = IteratorRecord::new(iterator_object, next_function);
+
+            let mut k = 0;
+            loop {
+                let next = iterator_record.next(context)?;
+                if next.is_done() {
+                    array.set_field("length", k, context)?;
+                    return Ok(array);
+                }
+
+                let value = next.value();
+                let mapped_value = match map_fn {
+                    Some(map_fn) => match context.call(map_fn, &this_arg, &[value, k.into()]) {
+                        Ok(mapped_value) => mapped_value,
+                        Err(error) => return iterator_record.close(Err(error), context),
+                    },
+                    None => value,
+                };
+
+                array.set_property(k, DataDescriptor::new(mapped_value, Attribute::all()));
+                k += 1;
+            }
+        }
+
+        // 6. NOTE: items is not an Iterable so assume it is an array-like object.
+        let array_like = Value::from(items_object);
+        let len = array_like
+            .get_field("length", context)?
+            .to_length(context)?;
+
+        let array = match constructor {
+            Some(ref constructor) => constructor.construct(&[len.into()], this, context)?,
+            None => Self::array_create(len as u32, None, context),
+        };
+
+        for k in 0..len {
+            let value = array_like.get_field(k, context)?;
+            let mapped_value = match map_fn {
+                Some(map_fn) => context.call(map_fn, &this_arg, &[value, k.into()])?,
+                None => value,
+            };
+            array.set_property(k, DataDescriptor::new(mapped_value, Attribute::all()));
+        }
+
+        array.set_field("length", len, context)?;
+
+        Ok(array)
+    }
+
     /// `Array.prototype.concat(...arguments)`
     ///
     /// When the concat method is called with zero or more arguments, it returns an
@@ -602,6 +697,125 @@ impl Array {
         Ok(this.clone())
     }
 
+    /// `Array.prototype.sort( [ comparefn ] )`
+    ///
+    /// The elements of the array are sorted in place and the array is returned. The sort is stable,
+    /// `undefined` values are moved to the end of the array and holes after them.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.sort
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/sort
+    pub(crate) fn sort(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. If comparefn is not undefined and IsCallable(comparefn) is false, throw a TypeError exception.
+        let comparefn = match args.get(0) {
+            None => None,
+            Some(comparefn) if comparefn.is_undefined() => None,
+            Some(comparefn) if comparefn.is_function() => Some(comparefn),
+            Some(_) => {
+                return context.throw_type_error(
+                    "The comparison function must be either a function or undefined",
+                )
+            }
+        };
+
+        let len = this.get_field("length", context)?.to_length(context)?;
+
+        // Holes are skipped, and `undefined` values are never passed to the comparison function.
+        let mut items = Vec::with_capacity(len);
+        let mut undefined_count = 0;
+        for k in 0..len {
+            if this.has_field(k) {
+                let value = this.get_field(k, context)?;
+                if value.is_undefined() {
+                    undefined_count += 1;
+                } else {
+                    items.push(value);
+                }
+            }
+        }
+
+        let items = Self::merge_sort(items, &mut |x: &Value, y: &Value| {
+            Self::sort_compare(x, y, comparefn, context)
+        })?;
+
+        let item_count = items.len() + undefined_count;
+        let sorted = items
+            .into_iter()
+            .chain(iter::repeat(Value::undefined()).take(undefined_count));
+        for (k, value) in sorted.enumerate() {
+            this.set_field(k, value, context)?;
+        }
+        for k in item_count..len {
+            this.remove_property(k);
+        }
+
+        Ok(this.clone())
+    }
+
+    /// `SortCompare ( x, y )`
+    ///
+    /// Neither `x` nor `y` can be `undefined`, those are handled by `Array.prototype.sort`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-sortcompare
+    fn sort_compare(
+        x: &Value,
+        y: &Value,
+        comparefn: Option<&Value>,
+        context: &mut Context,
+    ) -> Result<Ordering> {
+        if let Some(comparefn) = comparefn {
+            let v = context
+                .call(comparefn, &Value::undefined(), &[x.clone(), y.clone()])?
+                .to_number(context)?;
+            // A `NaN` result is treated as `+0`.
+            return Ok(v.partial_cmp(&0.0).unwrap_or(Ordering::Equal));
+        }
+
+        // Strings are compared by their UTF-16 code units.
+        let x = x.to_string(context)?;
+        let y = y.to_string(context)?;
+        Ok(x.encode_utf16().cmp(y.encode_utf16()))
+    }
+
+    /// Sorts the values with a stable merge sort, returning the first error thrown by `compare`.
+    ///
+    /// `slice::sort_by` can't be used here, as a user-provided comparison function can throw and
+    /// isn't guaranteed to be a consistent total order.
+    fn merge_sort<F>(mut items: Vec<Value>, compare: &mut F) -> Result<Vec<Value>>
+    where
+        F: FnMut(&Value, &Value) -> Result<Ordering>,
+    {
+        if items.len() <= 1 {
+            return Ok(items);
+        }
+
+        let right = items.split_off(items.len() / 2);
+        let left = Self::merge_sort(items, compare)?;
+        let right = Self::merge_sort(right, compare)?;
+
+        let mut merged = Vec::with_capacity(left.len() + right.len());
+        let mut left = left.into_iter().peekable();
+        let mut right = right.into_iter().peekable();
+        while let (Some(x), Some(y)) = (left.peek(), right.peek()) {
+            // Only take from the right when it is strictly smaller, to keep the sort stable.
+            if compare(y, x)? == Ordering::Less {
+                merged.extend(right.next());
+            } else {
+                merged.extend(left.next());
+            }
+        }
+        merged.extend(left);
+        merged.extend(right);
+
+        Ok(merged)
+    }
+
     /// `Array.prototype.shift()`
     ///
     /// The first element of the array is removed from the array and returned.
@@ -964,6 +1178,83 @@ impl Array {
         Ok(Value::integer(-1))
     }
 
+    /// `Array.prototype.findLast( predicate [ , thisArg ] )`
+    ///
+    /// This method executes the provided predicate function for each element of the array,
+    /// starting from the end. If the predicate function returns `true` for an element, this method
+    /// returns the element. If all elements return `false`, `undefined` is returned.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-array-find-from-last/#sec-array.prototype.findlast
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/findLast
+    pub(crate) fn find_last(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        if args.is_empty() {
+            return Err(Value::from(
+                "missing callback when calling function Array.prototype.findLast",
+            ));
+        }
+        let callback = &args[0];
+        let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
+        let len = this.get_field("length", context)?.to_length(context)?;
+        for i in (0..len).rev() {
+            let element = this.get_field(i, context)?;
+            let arguments = [element.clone(), Value::from(i), this.clone()];
+            let result = context.call(callback, &this_arg, &arguments)?;
+            if result.to_boolean() {
+                return Ok(element);
+            }
+        }
+        Ok(Value::undefined())
+    }
+
+    /// `Array.prototype.findLastIndex( predicate [ , thisArg ] )`
+    ///
+    /// This method executes the provided predicate function for each element of the array,
+    /// starting from the end. If the predicate function returns `true` for an element, this method
+    /// returns the index of the element. If all elements return `false`, the value `-1` is
+    /// returned.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-array-find-from-last/#sec-array.prototype.findlastindex
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/findLastIndex
+    pub(crate) fn find_last_index(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        if args.is_empty() {
+            return Err(Value::from(
+                "Missing argument for Array.prototype.findLastIndex",
+            ));
+        }
+
+        let predicate_arg = &args[0];
+
+        let this_arg = args.get(1).cloned().unwrap_or_else(Value::undefined);
+
+        let length = this.get_field("length", context)?.to_length(context)?;
+
+        for i in (0..length).rev() {
+            let element = this.get_field(i, context)?;
+            let arguments = [element, Value::from(i), this.clone()];
+
+            let result = context.call(predicate_arg, &this_arg, &arguments)?;
+
+            if result.to_boolean() {
+                let result = i32::try_from(i).map_err(interror_to_value)?;
+                return Ok(Value::integer(result));
+            }
+        }
+
+        Ok(Value::integer(-1))
+    }
+
     /// `Array.prototype.flat( [depth] )`
     ///
     /// This method creates a new array with all sub-array elements concatenated into it
@@ -1202,12 +1493,104 @@ impl Array {
         let fin = Self::get_relative_end(context, args.get(2), len)?;
 
         for i in start..fin {
-            this.set_property(i, DataDescriptor::new(value.clone(), Attribute::all()));
+            this.set_field(i, value.clone(), context)?;
+        }
+
+        Ok(this.clone())
+    }
+
+    /// `Array.prototype.copyWithin( target, start [ , end ] )`
+    ///
+    /// The method shallow copies the elements of the array from start index (default 0) to an
+    /// end index (default array length) to the target index, overwriting the elements there,
+    /// without modifying its length. It returns the modified array.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-array.prototype.copywithin
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/copyWithin
+    pub(crate) fn copy_within(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let len = this.get_field("length", context)?.to_length(context)?;
+
+        let mut to = Self::get_relative_start(context, args.get(0), len)?;
+        let mut from = Self::get_relative_start(context, args.get(1), len)?;
+        let fin = Self::get_relative_end(context, args.get(2), len)?;
+
+        // Let count be min(final - from, len - to).
+        let mut count = fin.saturating_sub(from).min(len - to);
+
+        // If the ranges overlap with the target after the source, copy backwards so that the
+        // elements are read before they are overwritten.
+        let backwards = from < to && to < from + count;
+        if backwards {
+            from += count - 1;
+            to += count - 1;
+        }
+
+        while count > 0 {
+            if this.has_field(from) {
+                let element = this.get_field(from, context)?;
+                this.set_field(to, element, context)?;
+            } else {
+                this.remove_property(to);
+            }
+
+            if backwards {
+                from = from.saturating_sub(1);
+                to = to.saturating_sub(1);
+            } else {
+                from += 1;
+                to += 1;
+            }
+            count -= 1;
         }
 
         Ok(this.clone())
     }
 
+    /// `Array.prototype.at( index )`
+    ///
+    /// Returns the element at the given index, counting back from the end of the array if the index is negative.
+    /// Returns `undefined` if the index is out of range.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-relative-indexing-method/#sec-array.prototype.at
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array/at
+    pub(crate) fn at(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 2. Let len be ? LengthOfArrayLike(O).
+        let len = this.get_field("length", context)?.to_length(context)? as i64;
+
+        // 3. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        // 4-5. If relativeIndex ≥ 0, let k be relativeIndex, else let k be len + relativeIndex.
+        let k = match args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_integer_or_infinity(context)?
+        {
+            IntegerOrInfinity::Integer(i) if i >= 0 => i,
+            IntegerOrInfinity::Integer(i) => len + i,
+            _ => return Ok(Value::undefined()),
+        };
+
+        // 6. If k < 0 or k ≥ len, return undefined.
+        if k < 0 || k >= len {
+            return Ok(Value::undefined());
+        }
+
+        // 7. Return ? Get(O, ! ToString(𝔽(k))).
+        this.get_field(k as usize, context)
+    }
+
     /// `Array.prototype.includes( valueToFind [, fromIndex] )`
     ///
     /// Determines whether an array includes a certain value among its entries, returning `true` or `false` as appropriate.
@@ -1226,8 +1609,14 @@ impl Array {
         let search_element = args.get(0).cloned().unwrap_or_else(Value::undefined);
 
         let length = this.get_field("length", context)?.to_length(context)?;
+        if length == 0 {
+            return Ok(Value::from(false));
+        }
 
-        for idx in 0..length {
+        // A negative `fromIndex` is counted back from the end of the array.
+        let start = Self::get_relative_start(context, args.get(1), length)?;
+
+        for idx in start..length {
             let check_element = this.get_field(idx, context)?.clone();
 
             if Value::same_value_zero(&check_element, &search_element) {
@@ -117,6 +117,76 @@ fn of() {
     assert_eq!(context.eval("a.length").unwrap(), Value::from(3));
 }
 
+#[test]
+fn of_single_number() {
+    let mut context = Context::new();
+
+    let init = r#"
+        var single = Array.of(7);
+        var constructed = Array(7);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "single.length"), "1");
+    assert_eq!(forward(&mut context, "single[0]"), "7");
+    assert_eq!(forward(&mut context, "constructed.length"), "7");
+    assert_eq!(forward(&mut context, "0 in constructed"), "false");
+    assert_eq!(forward(&mut context, "Array.of.length"), "0");
+}
+
+#[test]
+fn from() {
+    let mut context = Context::new();
+
+    // Iterables
+    assert_eq!(
+        forward(&mut context, "Array.from('abc').join()"),
+        "\"a,b,c\""
+    );
+    assert_eq!(
+        forward(&mut context, "Array.from(new Set([1, 2, 2, 3])).join()"),
+        "\"1,2,3\""
+    );
+    assert_eq!(forward(&mut context, "Array.from([1, 2]).length"), "2");
+    assert_eq!(
+        forward(&mut context, "Array.isArray(Array.from([]))"),
+        "true"
+    );
+
+    // Array-like objects
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from({ length: 3, 0: 'a', 2: 'c' }).join('-')"
+        ),
+        "\"a-undefined-c\""
+    );
+    assert_eq!(forward(&mut context, "Array.from({}).length"), "0");
+    assert_eq!(forward(&mut context, "Array.from(5).length"), "0");
+
+    // Mapping
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from([1, 2, 3], (x, i) => x * 10 + i).join()"
+        ),
+        "\"10,21,32\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.from({ length: 2 }, function (_, i) { return this.base + i; }, { base: 5 }).join()"
+        ),
+        "\"5,6\""
+    );
+
+    // Errors
+    assert!(forward(&mut context, "Array.from(null)").starts_with("Uncaught \"TypeError\""));
+    assert_eq!(
+        forward(&mut context, "Array.from([], 1)"),
+        "Uncaught \"TypeError\": \"Array.from: mapFn is not callable\""
+    );
+}
+
 #[ignore]
 fn concat() {
     //TODO: array display formatter
@@ -279,6 +349,50 @@ fn find_index() {
     assert_eq!(missing, String::from("-1"));
 }
 
+#[test]
+fn find_last() {
+    let mut context = Context::new();
+
+    let code = r#"
+        function isEven(item) {
+            return item % 2 == 0;
+        }
+        var many = [1, 2, 3, 4, 5];
+        var odd = [1, 3, 5];
+        var visited = [];
+        many.findLast(function (item, index) {
+            visited.push(index);
+            return item == 2;
+        });
+        "#;
+
+    forward(&mut context, code);
+
+    assert_eq!(forward(&mut context, "many.findLast(isEven)"), "4");
+    assert_eq!(forward(&mut context, "odd.findLast(isEven)"), "undefined");
+    assert_eq!(forward(&mut context, "[].findLast(isEven)"), "undefined");
+    assert_eq!(forward(&mut context, "visited.join()"), "\"4,3,2,1\"");
+}
+
+#[test]
+fn find_last_index() {
+    let mut context = Context::new();
+
+    let code = r#"
+        function isEven(item) {
+            return item % 2 == 0;
+        }
+        var many = [1, 2, 3, 4, 5];
+        var odd = [1, 3, 5];
+        "#;
+
+    forward(&mut context, code);
+
+    assert_eq!(forward(&mut context, "many.findLastIndex(isEven)"), "3");
+    assert_eq!(forward(&mut context, "odd.findLastIndex(isEven)"), "-1");
+    assert_eq!(forward(&mut context, "[].findLastIndex(isEven)"), "-1");
+}
+
 #[test]
 fn flat() {
     let mut context = Context::new();
@@ -472,6 +586,130 @@ fn reverse() {
     assert_eq!(forward(&mut context, "arr[1]"), "1");
 }
 
+#[test]
+fn sort_default() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "[10, 2, 1].sort().join()"),
+        "\"1,10,2\""
+    );
+    assert_eq!(
+        forward(&mut context, "['b', 'c', 'a'].sort().join()"),
+        "\"a,b,c\""
+    );
+    assert_eq!(
+        forward(&mut context, "['b', undefined, 'a'].sort()[1]"),
+        "\"b\""
+    );
+    assert_eq!(
+        forward(&mut context, "['b', undefined, 'a'].sort()[2]"),
+        "undefined"
+    );
+    assert_eq!(forward(&mut context, "[].sort().length"), "0");
+
+    let init = r#"
+        var arr = [3, 1, 2];
+        var sorted = arr.sort();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "sorted === arr"), "true");
+    assert_eq!(forward(&mut context, "arr.join()"), "\"1,2,3\"");
+}
+
+#[test]
+fn sort_with_comparator() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "[10, 2, 1].sort((a, b) => a - b).join()"),
+        "\"1,2,10\""
+    );
+    assert_eq!(
+        forward(&mut context, "[1, 2, 3].sort((a, b) => b - a).join()"),
+        "\"3,2,1\""
+    );
+    // The comparator result is coerced with ToNumber, and NaN counts as equal.
+    assert_eq!(
+        forward(&mut context, "[3, 1, 2].sort(() => 'NaN').join()"),
+        "\"3,1,2\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "[2, 1].sort(() => ({ valueOf: function() { return -1; } })).join()"
+        ),
+        "\"1,2\""
+    );
+}
+
+#[test]
+fn sort_is_stable() {
+    let mut context = Context::new();
+    let init = r#"
+        var people = [
+            { name: 'a', age: 30 },
+            { name: 'b', age: 20 },
+            { name: 'c', age: 30 },
+            { name: 'd', age: 20 },
+            { name: 'e', age: 30 }
+        ];
+        people.sort((x, y) => x.age - y.age);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "people.map(p => p.name).join()"),
+        "\"b,d,a,c,e\""
+    );
+}
+
+#[test]
+fn sort_undefined_and_holes() {
+    let mut context = Context::new();
+    let init = r#"
+        var arr = [3, undefined, 1];
+        arr[5] = 2;
+        arr.sort((a, b) => a - b);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "arr.length"), "6");
+    assert_eq!(forward(&mut context, "arr[0]"), "1");
+    assert_eq!(forward(&mut context, "arr[2]"), "3");
+    assert_eq!(forward(&mut context, "3 in arr"), "true");
+    assert_eq!(forward(&mut context, "arr[3]"), "undefined");
+    assert_eq!(forward(&mut context, "4 in arr"), "false");
+    assert_eq!(forward(&mut context, "5 in arr"), "false");
+}
+
+#[test]
+fn sort_errors() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, "[2, 1].sort(1)"),
+        "Uncaught \"TypeError\": \"The comparison function must be either a function or undefined\""
+    );
+
+    let init = r#"
+        var arr = [2, 1];
+        var caught;
+        try {
+            arr.sort(() => { throw 'comparator error'; });
+        } catch (e) {
+            caught = e;
+        }
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "caught"), "\"comparator error\"");
+    assert_eq!(forward(&mut context, "arr.join()"), "\"2,1\"");
+
+    // An inconsistent comparator gives an unspecified order, but mustn't fail.
+    assert_eq!(
+        forward(
+            &mut context,
+            "[5, 1, 4, 2, 3].sort(() => Math.random() - 0.5).length"
+        ),
+        "5"
+    );
+}
+
 #[test]
 fn index_of() {
     let mut context = Context::new();
@@ -703,6 +941,82 @@ fn fill() {
     assert_eq!(forward(&mut context, "a[0].hi"), String::from("\"hi\""));
 }
 
+#[test]
+fn fill_holes_and_negative_ranges() {
+    let mut context = Context::new();
+
+    forward(&mut context, "var a = new Array(4).fill(0, -2);");
+    assert_eq!(forward(&mut context, "a.length"), "4");
+    assert_eq!(forward(&mut context, "1 in a"), "false");
+    assert_eq!(forward(&mut context, "2 in a"), "true");
+    assert_eq!(forward(&mut context, "a[3]"), "0");
+
+    forward(&mut context, "a = [1, 2, 3, 4, 5];");
+    assert_eq!(
+        forward(&mut context, "a.fill(0, -4, -1).join()"),
+        String::from("\"1,0,0,0,5\"")
+    );
+}
+
+#[test]
+fn copy_within() {
+    let mut context = Context::new();
+
+    forward(&mut context, "var a = [1, 2, 3, 4, 5];");
+    assert_eq!(
+        forward(&mut context, "a.copyWithin(0, 3).join()"),
+        String::from("\"4,5,3,4,5\"")
+    );
+    // make sure the array is modified in place
+    assert_eq!(
+        forward(&mut context, "a.join()"),
+        String::from("\"4,5,3,4,5\"")
+    );
+
+    forward(&mut context, "a = [1, 2, 3, 4, 5];");
+    assert_eq!(
+        forward(&mut context, "a.copyWithin(1, 0, 3).join()"),
+        String::from("\"1,1,2,3,5\"")
+    );
+
+    forward(&mut context, "a = [1, 2, 3, 4, 5];");
+    assert_eq!(
+        forward(&mut context, "a.copyWithin(-2, -4, -3).join()"),
+        String::from("\"1,2,3,2,5\"")
+    );
+
+    forward(&mut context, "a = [1, 2, 3, 4, 5];");
+    assert_eq!(
+        forward(&mut context, "a.copyWithin(2).join()"),
+        String::from("\"1,2,1,2,3\"")
+    );
+    assert_eq!(forward(&mut context, "a.length"), "5");
+
+    forward(&mut context, "a = [1, 2, 3]; delete a[1];");
+    forward(&mut context, "a.copyWithin(0, 1);");
+    assert_eq!(forward(&mut context, "0 in a"), "false");
+    assert_eq!(forward(&mut context, "a[1]"), "3");
+}
+
+#[test]
+fn at() {
+    let mut context = Context::new();
+    let init = r#"
+        var array = [1, 2, 3];
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "array.at(0)"), "1");
+    assert_eq!(forward(&mut context, "array.at(-1)"), "3");
+    assert_eq!(forward(&mut context, "array.at(-3)"), "1");
+    assert_eq!(forward(&mut context, "array.at(1.7)"), "2");
+    assert_eq!(forward(&mut context, "array.at()"), "1");
+    assert_eq!(forward(&mut context, "array.at(3)"), "undefined");
+    assert_eq!(forward(&mut context, "array.at(-4)"), "undefined");
+    assert_eq!(forward(&mut context, "array.at(Infinity)"), "undefined");
+    assert_eq!(forward(&mut context, "[].at(0)"), "undefined");
+}
+
 #[test]
 fn includes_value() {
     let mut context = Context::new();
@@ -741,6 +1055,38 @@ fn includes_value() {
     assert_eq!(second_in_many, String::from("false"));
 }
 
+#[test]
+fn includes_value_same_value_zero() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "[NaN].includes(NaN)"), "true");
+    assert_eq!(forward(&mut context, "[NaN].indexOf(NaN)"), "-1");
+    assert_eq!(forward(&mut context, "[-0].includes(0)"), "true");
+    assert_eq!(forward(&mut context, "[0].includes(-0)"), "true");
+    assert_eq!(forward(&mut context, "[1].includes('1')"), "false");
+}
+
+#[test]
+fn includes_value_from_index() {
+    let mut context = Context::new();
+    forward(&mut context, "var array = ['a', 'b', 'c'];");
+
+    assert_eq!(forward(&mut context, "array.includes('a', 1)"), "false");
+    assert_eq!(forward(&mut context, "array.includes('c', 2)"), "true");
+    assert_eq!(forward(&mut context, "array.includes('c', 3)"), "false");
+    assert_eq!(forward(&mut context, "array.includes('c', -1)"), "true");
+    assert_eq!(forward(&mut context, "array.includes('b', -1)"), "false");
+    assert_eq!(forward(&mut context, "array.includes('a', -100)"), "true");
+    assert_eq!(
+        forward(&mut context, "array.includes('a', -Infinity)"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "array.includes('a', Infinity)"),
+        "false"
+    );
+}
+
 #[test]
 fn map() {
     let mut context = Context::new();
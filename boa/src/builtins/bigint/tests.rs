@@ -154,6 +154,24 @@ fn add() {
     assert_eq!(forward(&mut context, "10000n + 1000n"), "11000n");
 }
 
+#[test]
+fn add_mixed_types() {
+    let mut context = Context::new();
+
+    assert_throws(&mut context, "1n + 1", "TypeError");
+    assert_throws(&mut context, "1 + 1n", "TypeError");
+    assert_throws(&mut context, "2n * 1.5", "TypeError");
+    assert_eq!(forward(&mut context, "1n + '1'"), "\"11\"");
+}
+
+#[test]
+fn type_of() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "typeof 1n"), "\"bigint\"");
+    assert_eq!(forward(&mut context, "typeof BigInt(1)"), "\"bigint\"");
+}
+
 #[test]
 fn sub() {
     let mut context = Context::new();
@@ -43,6 +43,43 @@ fn ignore_ambiguity<T>(result: LocalResult<T>) -> Option<T> {
     }
 }
 
+/// Parses a string in the [date time string format][spec], a simplification of ISO 8601, into a
+/// UTC date time.
+///
+/// Date-only forms are interpreted as UTC, while date-time forms without an offset are
+/// interpreted as local time.
+///
+/// [spec]: https://tc39.es/ecma262/#sec-date-time-string-format
+fn parse_date_time_string(string: &str) -> Option<NaiveDateTime> {
+    if let Ok(date_time) = DateTime::parse_from_rfc3339(string) {
+        return Some(date_time.naive_utc());
+    }
+
+    // Date-only forms: `YYYY`, `YYYY-MM` and `YYYY-MM-DD`.
+    let date = match string.len() {
+        4 => NaiveDate::parse_from_str(&format!("{}-01-01", string), "%Y-%m-%d").ok(),
+        7 => NaiveDate::parse_from_str(&format!("{}-01", string), "%Y-%m-%d").ok(),
+        10 => NaiveDate::parse_from_str(string, "%Y-%m-%d").ok(),
+        _ => None,
+    };
+    if let Some(date) = date {
+        return Some(date.and_hms(0, 0, 0));
+    }
+
+    // Date-time forms, where the seconds and the offset are optional.
+    if let Some(utc) = string.strip_suffix('Z') {
+        return NaiveDateTime::parse_from_str(utc, "%Y-%m-%dT%H:%M").ok();
+    }
+    if let Ok(date_time) = DateTime::parse_from_str(string, "%Y-%m-%dT%H:%M%:z") {
+        return Some(date_time.naive_utc());
+    }
+    ["%Y-%m-%dT%H:%M", "%Y-%m-%dT%H:%M:%S%.f"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(string, format).ok())
+        .and_then(|local| ignore_ambiguity(Local.from_local_datetime(&local)))
+        .map(|local| local.naive_utc())
+}
+
 macro_rules! getter_method {
     ($name:ident) => {{
         fn get_value(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
@@ -444,10 +481,7 @@ impl Date {
         let tv = match this_time_value(value, context) {
             Ok(dt) => dt.0,
             _ => match value.to_primitive(context, PreferredType::Default)? {
-                Value::String(ref str) => match chrono::DateTime::parse_from_rfc3339(&str) {
-                    Ok(dt) => Some(dt.naive_utc()),
-                    _ => None,
-                },
+                Value::String(ref str) => parse_date_time_string(str),
                 tv => {
                     let tv = tv.to_number(context)?;
                     let secs = (tv / 1_000f64) as i64;
@@ -1337,9 +1371,9 @@ impl Date {
             return Ok(Value::number(f64::NAN));
         }
 
-        match DateTime::parse_from_rfc3339(&args[0].to_string(context)?) {
-            Ok(v) => Ok(Value::number(v.naive_utc().timestamp_millis() as f64)),
-            _ => Ok(Value::number(f64::NAN)),
+        match parse_date_time_string(&args[0].to_string(context)?) {
+            Some(v) => Ok(Value::number(v.timestamp_millis() as f64)),
+            None => Ok(Value::number(f64::NAN)),
         }
     }
 
@@ -121,6 +121,70 @@ fn date_ctor_call_string_invalid() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+#[test]
+fn date_ctor_call_string_utc_components() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    forward(&mut context, "let d = new Date('2020-01-02T03:04:05Z')");
+    let components = forward(
+        &mut context,
+        "[d.getUTCFullYear(), d.getUTCMonth(), d.getUTCDate(), d.getUTCHours(), d.getUTCMinutes(), d.getUTCSeconds()].join()",
+    );
+    assert_eq!("\"2020,0,2,3,4,5\"", components);
+    assert_eq!("1577934245000", forward(&mut context, "d.getTime()"));
+    Ok(())
+}
+
+#[test]
+fn date_ctor_call_string_date_only() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    // Date-only forms are interpreted as UTC.
+    let date_time = forward_dt_utc(&mut context, "new Date('2020-01-02')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2020, 01, 02).and_hms(0, 0, 0)),
+        date_time
+    );
+
+    let date_time = forward_dt_utc(&mut context, "new Date('2020-03')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2020, 03, 01).and_hms(0, 0, 0)),
+        date_time
+    );
+    Ok(())
+}
+
+#[test]
+fn date_ctor_call_string_without_offset() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    // Date-time forms without an offset are interpreted as local time.
+    let date_time = forward_dt_local(&mut context, "new Date('2020-01-02T03:04')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2020, 01, 02).and_hms(3, 4, 0)),
+        date_time
+    );
+
+    let date_time = forward_dt_utc(&mut context, "new Date('2020-01-02T03:04+01:00')");
+    assert_eq!(
+        Some(NaiveDate::from_ymd(2020, 01, 02).and_hms(2, 4, 0)),
+        date_time
+    );
+    Ok(())
+}
+
+#[test]
+fn date_ctor_call_string_invalid_time_value() -> Result<(), Box<dyn std::error::Error>> {
+    let mut context = Context::new();
+
+    let time = forward_val(&mut context, "new Date('2020-13-45').getTime()");
+    assert_eq!(Ok(Value::Rational(f64::NAN)), time);
+
+    let time = forward_val(&mut context, "Date.parse('nope')");
+    assert_eq!(Ok(Value::Rational(f64::NAN)), time);
+    Ok(())
+}
+
 #[test]
 fn date_ctor_call_number() -> Result<(), Box<dyn std::error::Error>> {
     let mut context = Context::new();
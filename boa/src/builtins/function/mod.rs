@@ -18,11 +18,15 @@ use crate::{
     gc::{empty_trace, Finalize, Trace},
     object::{ConstructorBuilder, FunctionBuilder, GcObject, Object, ObjectData},
     property::{Attribute, DataDescriptor},
-    syntax::ast::node::{FormalParameter, RcStatementList},
+    syntax::ast::node::{Declaration, FormalParameter, RcStatementList},
     BoaProfiler, Context, Result, Value,
 };
 use bitflags::bitflags;
-use std::fmt::{self, Debug};
+use gc::{Gc, GcCell};
+use std::{
+    fmt::{self, Debug},
+    rc::Rc,
+};
 
 #[cfg(test)]
 mod tests;
@@ -49,12 +53,56 @@ impl Debug for BuiltInFunction {
     }
 }
 
+/// _fn(this, arguments, context) -> ResultValue_ - The signature of a built-in closure
+pub type ClosureFunction = dyn Fn(&Value, &[Value], &mut Context) -> Result<Value>;
+
+/// A built-in function backed by a Rust closure, which can capture state.
+///
+/// The captured state is not traced by the garbage collector, so the closure must not capture
+/// garbage collected values such as `Value` or `GcObject`.
+#[derive(Clone, Finalize)]
+pub struct BuiltInClosure(pub(crate) Rc<ClosureFunction>);
+
+unsafe impl Trace for BuiltInClosure {
+    empty_trace!();
+}
+
+impl Debug for BuiltInClosure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[native closure]")
+    }
+}
+
+/// _fn(this, arguments, captures, context) -> ResultValue_ - The signature of a built-in function
+/// with captured values
+pub type CapturingFunction = fn(&Value, &[Value], &Captures, &mut Context) -> Result<Value>;
+
+/// The values captured by a built-in capturing function.
+///
+/// Unlike the state of a [`BuiltInClosure`], these values are traced by the garbage collector.
+/// The same captures can be shared by several functions.
+pub type Captures = Gc<GcCell<Vec<Value>>>;
+
+#[derive(Clone, Copy, Finalize)]
+pub struct BuiltInCapturingFunction(pub(crate) CapturingFunction);
+
+unsafe impl Trace for BuiltInCapturingFunction {
+    empty_trace!();
+}
+
+impl Debug for BuiltInCapturingFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[native]")
+    }
+}
+
 bitflags! {
     #[derive(Finalize, Default)]
     pub struct FunctionFlags: u8 {
         const CALLABLE = 0b0000_0001;
         const CONSTRUCTABLE = 0b0000_0010;
         const LEXICAL_THIS_MODE = 0b0000_0100;
+        const GENERATOR = 0b0000_1000;
     }
 }
 
@@ -86,6 +134,11 @@ impl FunctionFlags {
     pub(crate) fn is_lexical_this_mode(&self) -> bool {
         self.contains(Self::LEXICAL_THIS_MODE)
     }
+
+    #[inline]
+    pub(crate) fn is_generator(&self) -> bool {
+        self.contains(Self::GENERATOR)
+    }
 }
 
 unsafe impl Trace for FunctionFlags {
@@ -100,6 +153,12 @@ unsafe impl Trace for FunctionFlags {
 #[derive(Debug, Clone, Finalize, Trace)]
 pub enum Function {
     BuiltIn(BuiltInFunction, FunctionFlags),
+    Closure(BuiltInClosure, FunctionFlags),
+    Capturing {
+        function: BuiltInCapturingFunction,
+        captures: Captures,
+        flags: FunctionFlags,
+    },
     Ordinary {
         flags: FunctionFlags,
         body: RcStatementList,
@@ -117,22 +176,13 @@ impl Function {
         args_list: &[Value],
         context: &mut Context,
         local_env: &Environment,
-    ) {
+    ) -> Result<()> {
         // Create array of values
         let array = Array::new_array(context);
         Array::add_to_array_object(&array, &args_list.get(index..).unwrap_or_default(), context)
             .unwrap();
 
-        // Create binding
-        local_env
-            // Function parameters can share names in JavaScript...
-            .create_mutable_binding(param.name().to_owned(), false, true, context)
-            .expect("Failed to create binding for rest param");
-
-        // Set Binding to value
-        local_env
-            .initialize_binding(param.name(), array, context)
-            .expect("Failed to initialize rest param");
+        self.add_arguments_to_environment(param, array, local_env, context)
     }
 
     // Adds an argument to the environment
@@ -142,35 +192,79 @@ impl Function {
         value: Value,
         local_env: &Environment,
         context: &mut Context,
-    ) {
-        // Create binding
-        local_env
-            .create_mutable_binding(param.name().to_owned(), false, true, context)
-            .expect("Failed to create binding");
-
-        // Set Binding to value
-        local_env
-            .initialize_binding(param.name(), value, context)
-            .expect("Failed to intialize binding");
+    ) -> Result<()> {
+        let bindings = match param.declaration() {
+            Declaration::Identifier { ident, .. } => vec![(ident.as_ref().into(), value)],
+            Declaration::Pattern(pattern) => pattern.run(Some(value), context)?,
+        };
+
+        for (name, value) in bindings {
+            // Create binding
+            local_env
+                // Function parameters can share names in JavaScript...
+                .create_mutable_binding(name.to_string(), false, true, context)
+                .expect("Failed to create binding");
+
+            // Set Binding to value
+            local_env
+                .initialize_binding(&name, value, context)
+                .expect("Failed to intialize binding");
+        }
+
+        Ok(())
     }
 
     /// Returns true if the function object is callable.
     pub fn is_callable(&self) -> bool {
         match self {
-            Self::BuiltIn(_, flags) => flags.is_callable(),
-            Self::Ordinary { flags, .. } => flags.is_callable(),
+            Self::BuiltIn(_, flags) | Self::Closure(_, flags) => flags.is_callable(),
+            Self::Capturing { flags, .. } | Self::Ordinary { flags, .. } => flags.is_callable(),
         }
     }
 
     /// Returns true if the function object is constructable.
     pub fn is_constructable(&self) -> bool {
         match self {
-            Self::BuiltIn(_, flags) => flags.is_constructable(),
-            Self::Ordinary { flags, .. } => flags.is_constructable(),
+            Self::BuiltIn(_, flags) | Self::Closure(_, flags) => flags.is_constructable(),
+            Self::Capturing { flags, .. } | Self::Ordinary { flags, .. } => {
+                flags.is_constructable()
+            }
         }
     }
 }
 
+/// Creates a callable built-in function object with access to the given captures.
+///
+/// The captures can be shared with other functions, to give them access to the same values.
+pub(crate) fn make_capturing_fn<N>(
+    function: CapturingFunction,
+    captures: Captures,
+    name: N,
+    length: usize,
+    context: &Context,
+) -> GcObject
+where
+    N: AsRef<str>,
+{
+    let mut function = Object::function(
+        Function::Capturing {
+            function: BuiltInCapturingFunction(function),
+            captures,
+            flags: FunctionFlags::CALLABLE,
+        },
+        context
+            .standard_objects()
+            .function_object()
+            .prototype()
+            .into(),
+    );
+    let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+    function.insert_property("length", length, attribute);
+    function.insert_property("name", name.as_ref(), attribute);
+
+    GcObject::new(function)
+}
+
 /// Arguments.
 ///
 /// <https://tc39.es/ecma262/#sec-createunmappedargumentsobject>
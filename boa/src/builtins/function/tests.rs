@@ -212,3 +212,162 @@ fn function_prototype_apply_on_object() {
         .unwrap();
     assert!(boolean);
 }
+
+#[test]
+fn object_pattern_parameter() {
+    let mut context = Context::new();
+    let func = r#"
+        function f({ x, y = 2 }) {
+            return x + " " + y;
+        }
+        f({ x: 1 }) + ", " + f({ x: 1, y: 3 }) + ", " + f({});
+        "#;
+    assert_eq!(forward(&mut context, func), r#""1 2, 1 3, undefined 2""#);
+}
+
+#[test]
+fn array_pattern_parameter() {
+    let mut context = Context::new();
+    let func = r#"
+        function f([a, , b = 10], ...[c, d]) {
+            return [a, b, c, d];
+        }
+        f([1, 2]);
+        "#;
+    assert_eq!(
+        forward(&mut context, func),
+        "[ 1, 10, undefined, undefined ]"
+    );
+}
+
+#[test]
+fn pattern_parameter_with_default() {
+    let mut context = Context::new();
+    let func = r#"
+        function f({ x = 1 } = {}, [y] = [2]) {
+            return x + y;
+        }
+        f();
+        "#;
+    assert_eq!(forward(&mut context, func), "3");
+}
+
+#[test]
+fn pattern_parameter_default_is_lazy() {
+    let mut context = Context::new();
+    let func = r#"
+        let calls = 0;
+        function def() {
+            calls++;
+            return 0;
+        }
+        function f({ x = def() }, [y = def()]) {
+            return x + y;
+        }
+        f({ x: 1 }, [2]) + " " + calls;
+        "#;
+    assert_eq!(forward(&mut context, func), r#""3 0""#);
+}
+
+#[test]
+fn pattern_parameter_missing_argument_throws() {
+    let mut context = Context::new();
+    let func = r#"
+        function f({ x }) {
+            return x;
+        }
+        try {
+            f();
+        } catch (e) {
+            e instanceof TypeError;
+        }
+        "#;
+    assert_eq!(forward(&mut context, func), "true");
+}
+
+#[test]
+fn default_parameter_values() {
+    let mut context = Context::new();
+    let func = r#"
+        function f(a, b = a + 1) {
+            return b;
+        }
+        "#;
+    forward(&mut context, func);
+    assert_eq!(forward(&mut context, "f(1) === 2"), "true");
+    assert_eq!(forward(&mut context, "f(1, undefined) === 2"), "true");
+    assert_eq!(forward(&mut context, "f(1, null)"), "null");
+    assert_eq!(forward(&mut context, "f(1, 5)"), "5");
+}
+
+#[test]
+fn default_parameter_values_left_to_right() {
+    let mut context = Context::new();
+    let func = r#"
+        let order = "";
+        function log(name, value) {
+            order += name;
+            return value;
+        }
+        function f(a = log("a", 1), b = log("b", a + 1), c = log("c", b + 1)) {
+            return a + b + c;
+        }
+        f() + " " + order;
+        "#;
+    assert_eq!(forward(&mut context, func), r#""6 abc""#);
+}
+
+#[test]
+fn default_parameter_value_throws() {
+    let mut context = Context::new();
+    let func = r#"
+        function bad() {
+            throw new RangeError("bad");
+        }
+        function f(a = bad()) {
+            return a;
+        }
+        try {
+            f();
+        } catch (e) {
+            e instanceof RangeError;
+        }
+        "#;
+    assert_eq!(forward(&mut context, func), "true");
+}
+
+#[test]
+fn global_closure() {
+    use crate::Value;
+    use std::{cell::Cell, rc::Rc};
+
+    let mut context = Context::new();
+
+    let calls = Rc::new(Cell::new(0));
+    let counter = calls.clone();
+    context
+        .register_global_closure("sum", 2, move |_, args, context| {
+            counter.set(counter.get() + 1);
+            let mut sum = 0.0;
+            for arg in args {
+                sum += arg.to_number(context)?;
+            }
+            Ok(Value::from(sum))
+        })
+        .unwrap();
+    context
+        .register_global_closure("getThis", 0, |this, _, _| Ok(this.clone()))
+        .unwrap();
+
+    assert_eq!(forward(&mut context, "sum(1, 2, 3)"), "6");
+    assert_eq!(forward(&mut context, "sum()"), "0");
+    assert_eq!(forward(&mut context, "sum.length"), "2");
+    assert_eq!(forward(&mut context, "sum.name"), "\"sum\"");
+    assert_eq!(calls.get(), 2);
+
+    assert_eq!(
+        forward(&mut context, "var o = { v: 1, f: getThis }; o.f() === o"),
+        "true"
+    );
+    assert!(forward(&mut context, "new sum()").starts_with("Uncaught \"TypeError\""));
+}
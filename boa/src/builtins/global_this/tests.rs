@@ -8,3 +8,45 @@ fn global_this_exists_on_global_object_and_evaluates_to_an_object() {
 
     assert_eq!(&exec(scenario), "\"object\"");
 }
+
+#[test]
+fn global_this_is_its_own_property() {
+    let scenario = r#"
+        globalThis === globalThis.globalThis;
+        "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn global_this_properties_are_global_bindings() {
+    let scenario = r#"
+        globalThis.x = 1;
+        x;
+        "#;
+
+    assert_eq!(&exec(scenario), "1");
+
+    let scenario = r#"
+        var y = 2;
+        globalThis.y;
+        "#;
+
+    assert_eq!(&exec(scenario), "2");
+}
+
+#[test]
+fn global_this_is_reachable_from_any_scope() {
+    let scenario = r#"
+        function f() {
+            let globalThisInBlock;
+            {
+                globalThisInBlock = (() => globalThis)();
+            }
+            return globalThisInBlock;
+        }
+        f() === globalThis;
+        "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
@@ -3,6 +3,7 @@ use crate::{
     builtins::ArrayIterator,
     builtins::ForInIterator,
     builtins::MapIterator,
+    builtins::RegExpStringIterator,
     builtins::SetIterator,
     object::{GcObject, ObjectInitializer},
     property::{Attribute, DataDescriptor},
@@ -10,6 +11,9 @@ use crate::{
     BoaProfiler, Context, Result, Value,
 };
 
+#[cfg(test)]
+mod tests;
+
 #[derive(Debug, Default)]
 pub struct IteratorPrototypes {
     iterator_prototype: GcObject,
@@ -17,6 +21,7 @@ pub struct IteratorPrototypes {
     set_iterator: GcObject,
     string_iterator: GcObject,
     map_iterator: GcObject,
+    regexp_string_iterator: GcObject,
     for_in_iterator: GcObject,
 }
 
@@ -34,6 +39,10 @@ impl IteratorPrototypes {
                 iterator_prototype.clone().into(),
             ),
             map_iterator: MapIterator::create_prototype(context, iterator_prototype.clone().into()),
+            regexp_string_iterator: RegExpStringIterator::create_prototype(
+                context,
+                iterator_prototype.clone().into(),
+            ),
             for_in_iterator: ForInIterator::create_prototype(
                 context,
                 iterator_prototype.clone().into(),
@@ -67,6 +76,11 @@ impl IteratorPrototypes {
         self.map_iterator.clone()
     }
 
+    #[inline]
+    pub fn regexp_string_iterator(&self) -> GcObject {
+        self.regexp_string_iterator.clone()
+    }
+
     #[inline]
     pub fn for_in_iterator(&self) -> GcObject {
         self.for_in_iterator.clone()
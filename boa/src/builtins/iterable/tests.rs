@@ -0,0 +1,98 @@
+use crate::{builtins::iterable::get_iterator, forward, forward_val, Context, Value};
+
+/// Collects the values of the given iterable, through the iterator protocol.
+fn collect(context: &mut Context, iterable: &str) -> Vec<Value> {
+    let iterable = forward_val(context, iterable).unwrap();
+    let iterator_record = get_iterator(context, iterable).unwrap();
+
+    let mut values = Vec::new();
+    loop {
+        let next = iterator_record.next(context).unwrap();
+        if next.is_done() {
+            break;
+        }
+        values.push(next.value());
+    }
+    values
+}
+
+#[test]
+fn map_entries() {
+    let mut context = Context::new();
+
+    let entries = collect(&mut context, "new Map([['a', 1], ['b', 2]])");
+    assert_eq!(entries.len(), 2);
+
+    let entries = entries
+        .iter()
+        .map(|entry| {
+            assert!(entry.is_object());
+            (
+                entry.get_field(0, &mut context).unwrap(),
+                entry.get_field(1, &mut context).unwrap(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        entries,
+        vec![
+            (Value::from("a"), Value::from(1)),
+            (Value::from("b"), Value::from(2))
+        ]
+    );
+}
+
+#[test]
+fn set_values() {
+    let mut context = Context::new();
+
+    let values = collect(&mut context, "new Set(['a', 'b', 'a'])");
+    assert_eq!(values, vec![Value::from("a"), Value::from("b")]);
+}
+
+#[test]
+fn array_values() {
+    let mut context = Context::new();
+
+    let values = collect(&mut context, "['a', 'b']");
+    assert_eq!(values, vec![Value::from("a"), Value::from("b")]);
+}
+
+#[test]
+fn collection_iterators_are_iterable() {
+    let mut context = Context::new();
+
+    let values = collect(&mut context, "new Map([['a', 1]]).keys()");
+    assert_eq!(values, vec![Value::from("a")]);
+    let values = collect(&mut context, "new Set(['a']).values()");
+    assert_eq!(values, vec![Value::from("a")]);
+    let values = collect(&mut context, "['a'].values()");
+    assert_eq!(values, vec![Value::from("a")]);
+}
+
+#[test]
+fn symbol_iterator_methods() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "Map.prototype[Symbol.iterator] === Map.prototype.entries"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Set.prototype[Symbol.iterator] === Set.prototype.values"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Array.prototype[Symbol.iterator] === Array.prototype.values"
+        ),
+        "true"
+    );
+}
@@ -13,14 +13,14 @@
 //! [json]: https://www.json.org/json-en.html
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON
 
+use crate::symbol::WellKnownSymbols;
 use crate::{
     builtins::BuiltIn,
-    object::ObjectInitializer,
-    property::{Attribute, DataDescriptor, PropertyKey},
-    value::IntegerOrInfinity,
+    object::{ObjectInitializer, RecursionLimiter},
+    property::{Attribute, PropertyKey},
+    value::{IntegerOrInfinity, RcString},
     BoaProfiler, Context, Result, Value,
 };
-use crate::{object::Object, symbol::WellKnownSymbols};
 use serde::Serialize;
 use serde_json::{self, ser::PrettyFormatter, Serializer, Value as JSONValue};
 
@@ -79,9 +79,9 @@ impl Json {
                 let j = Value::from_json(json, context);
                 match args.get(1) {
                     Some(reviver) if reviver.is_function() => {
-                        let mut holder = Value::object(Object::default());
+                        let holder = Value::new_object(context);
                         holder.set_field("", j, context)?;
-                        Self::walk(reviver, context, &mut holder, &PropertyKey::from(""))
+                        Self::walk(reviver, context, &holder, &PropertyKey::from(""))
                     }
                     _ => Ok(j),
                 }
@@ -90,37 +90,53 @@ impl Json {
         }
     }
 
-    /// This is a translation of the [Polyfill implementation][polyfill]
+    /// `InternalizeJSONProperty ( holder, name, reviver )`
     ///
     /// This function recursively walks the structure, passing each key-value pair to the reviver function
-    /// for possible transformation.
+    /// for possible transformation. Children are visited before their parents, and a property is deleted
+    /// when the reviver returns `undefined` for it.
     ///
-    /// [polyfill]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/JSON/parse
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-internalizejsonproperty
     fn walk(
         reviver: &Value,
         context: &mut Context,
-        holder: &mut Value,
+        holder: &Value,
         key: &PropertyKey,
     ) -> Result<Value> {
+        // 1. Let val be ? Get(holder, name).
         let value = holder.get_field(key.clone(), context)?;
 
-        if let Value::Object(ref object) = value {
-            let keys: Vec<_> = object.borrow().keys().collect();
+        // 2. If Type(val) is Object, then
+        if let Some(object) = value.as_object() {
+            let keys: Vec<PropertyKey> = if object.is_array() {
+                let len = value.get_field("length", context)?.to_length(context)?;
+                (0..len).map(PropertyKey::from).collect()
+            } else {
+                object
+                    .borrow()
+                    .iter()
+                    .filter(|(key, property)| {
+                        !matches!(key, PropertyKey::Symbol(_)) && property.enumerable()
+                    })
+                    .map(|(key, _)| key)
+                    .collect()
+            };
 
             for key in keys {
-                let v = Self::walk(reviver, context, &mut value.clone(), &key);
-                match v {
-                    Ok(v) if !v.is_undefined() => {
-                        value.set_field(key, v, context)?;
-                    }
-                    Ok(_) => {
-                        value.remove_property(key);
-                    }
-                    Err(_v) => {}
+                let new_element = Self::walk(reviver, context, &value, &key)?;
+                if new_element.is_undefined() {
+                    value.remove_property(key);
+                } else {
+                    value.set_field(key, new_element, context)?;
                 }
             }
         }
-        context.call(reviver, holder, &[key.into(), value])
+
+        // 3. Return ? Call(reviver, holder, « name, val »).
+        context.call(reviver, holder, &[Value::from(key.to_string()), value])
     }
 
     /// `JSON.stringify( value[, replacer[, space]] )`
@@ -176,84 +192,188 @@ impl Json {
 
         let gap = &gap.to_string(context)?;
 
-        let replacer = match args.get(1) {
-            Some(replacer) if replacer.is_object() => replacer,
-            _ => {
-                if let Some(value) = object.to_json(context)? {
-                    return Ok(Value::from(json_to_pretty_string(&value, gap)));
+        let mut state = StateRecord {
+            replacer_function: None,
+            property_list: None,
+        };
+        if let Some(replacer) = args.get(1).and_then(Value::as_object) {
+            if replacer.is_callable() {
+                state.replacer_function = Some(Value::from(replacer));
+            } else if replacer.is_array() {
+                let replacer = Value::from(replacer);
+                let len = replacer.get_field("length", context)?.to_length(context)?;
+                let mut property_list = Vec::with_capacity(len);
+                for index in 0..len {
+                    let v = replacer.get_field(index, context)?;
+                    let item = match v {
+                        Value::String(ref string) => Some(string.clone()),
+                        Value::Integer(_) | Value::Rational(_) => Some(v.to_string(context)?),
+                        Value::Object(ref object)
+                            if object.borrow().as_string().is_some()
+                                || object.borrow().as_number().is_some() =>
+                        {
+                            Some(v.to_string(context)?)
+                        }
+                        _ => None,
+                    };
+                    if let Some(item) = item {
+                        if !property_list.contains(&item) {
+                            property_list.push(item);
+                        }
+                    }
+                }
+                state.property_list = Some(property_list);
+            }
+        }
+
+        let wrapper = Value::new_object(context);
+        wrapper.set_field("", object.clone(), context)?;
+
+        match Self::serialize_json_property(&state, PropertyKey::from(""), &wrapper, context)? {
+            Some(value) => Ok(Value::from(json_to_pretty_string(&value, gap))),
+            None => Ok(Value::undefined()),
+        }
+    }
+
+    /// `SerializeJSONProperty ( state, key, holder )`
+    ///
+    /// Applies `toJSON` and the replacer function to the property, then converts the result.
+    /// Returns `None` if the value can't be represented in JSON.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonproperty
+    fn serialize_json_property(
+        state: &StateRecord,
+        key: PropertyKey,
+        holder: &Value,
+        context: &mut Context,
+    ) -> Result<Option<JSONValue>> {
+        // 1. Let value be ? Get(holder, key).
+        let mut value = holder.get_field(key.clone(), context)?;
+        let key = Value::from(key.to_string());
+
+        // 2. If Type(value) is Object or BigInt, then
+        if value.is_object() || value.is_bigint() {
+            // a. Let toJSON be ? GetV(value, "toJSON").
+            let to_json = value.get_field("toJSON", context)?;
+            // b. If IsCallable(toJSON) is true, then
+            if to_json.is_function() {
+                // i. Set value to ? Call(toJSON, value, « key »).
+                value = context.call(&to_json, &value, &[key.clone()])?;
+            }
+        }
+
+        // 3. If state.[[ReplacerFunction]] is not undefined, then
+        if let Some(replacer) = &state.replacer_function {
+            // a. Set value to ? Call(state.[[ReplacerFunction]], holder, « key, value »).
+            value = context.call(replacer, holder, &[key, value])?;
+        }
+
+        // 4. If Type(value) is Object, then unwrap primitive wrapper objects.
+        if let Some(object) = value.as_object() {
+            let (is_number, is_string) = {
+                let object = object.borrow();
+                (object.as_number().is_some(), object.as_string().is_some())
+            };
+            if is_number {
+                value = Value::from(value.to_number(context)?);
+            } else if is_string {
+                value = Value::from(value.to_string(context)?);
+            } else {
+                let object = object.borrow();
+                if let Some(boolean) = object.as_boolean() {
+                    value = Value::from(boolean);
+                } else if let Some(bigint) = object.as_bigint() {
+                    value = Value::from(bigint.clone());
+                }
+            }
+        }
+
+        match value {
+            // 10. If Type(value) is Object and IsCallable(value) is false, then
+            Value::Object(ref object) if !object.is_callable() => {
+                let rec_limiter = RecursionLimiter::new(object);
+                if rec_limiter.live {
+                    return Err(context.construct_type_error("cyclic object value"));
+                }
+                if object.is_array() {
+                    Self::serialize_json_array(state, &value, context).map(Some)
                 } else {
-                    return Ok(Value::undefined());
+                    Self::serialize_json_object(state, &value, context).map(Some)
                 }
             }
-        };
+            Value::Object(_) => Ok(None),
+            // 5-9. Primitives are converted directly, and non-serializable values are skipped.
+            _ => value.to_json(context),
+        }
+    }
 
-        let replacer_as_object = replacer
-            .as_object()
-            .expect("JSON.stringify replacer was an object");
-        if replacer_as_object.is_callable() {
-            object
+    /// `SerializeJSONObject ( state, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonobject
+    fn serialize_json_object(
+        state: &StateRecord,
+        value: &Value,
+        context: &mut Context,
+    ) -> Result<JSONValue> {
+        let keys: Vec<PropertyKey> = match state.property_list {
+            Some(ref property_list) => property_list.iter().cloned().map(Into::into).collect(),
+            None => value
                 .as_object()
-                .map(|obj| {
-                    let object_to_return = Value::object(Object::default());
-                    for key in obj.borrow().keys() {
-                        let val = obj.get(&key, obj.clone().into(), context)?;
-                        let this_arg = object.clone();
-                        object_to_return.set_property(
-                            key.to_owned(),
-                            DataDescriptor::new(
-                                context.call(
-                                    replacer,
-                                    &this_arg,
-                                    &[Value::from(key.clone()), val.clone()],
-                                )?,
-                                Attribute::all(),
-                            ),
-                        );
-                    }
-                    if let Some(value) = object_to_return.to_json(context)? {
-                        Ok(Value::from(json_to_pretty_string(&value, gap)))
-                    } else {
-                        Ok(Value::undefined())
-                    }
+                .expect("SerializeJSONObject called with a non-object")
+                .borrow()
+                .iter()
+                .filter(|(key, property)| {
+                    !matches!(key, PropertyKey::Symbol(_)) && property.enumerable()
                 })
-                .ok_or_else(Value::undefined)?
-        } else if replacer_as_object.is_array() {
-            let mut obj_to_return = serde_json::Map::new();
-            let replacer_as_object = replacer_as_object.borrow();
-            let fields = replacer_as_object.keys().filter_map(|key| {
-                if key == "length" {
-                    None
-                } else {
-                    Some(
-                        replacer
-                            .get_property(key)
-                            .as_ref()
-                            .and_then(|p| p.as_data_descriptor())
-                            .map(|d| d.value())
-                            .unwrap_or_else(Value::undefined),
-                    )
-                }
-            });
-            for field in fields {
-                let v = object.get_field(field.to_string(context)?, context)?;
-                if !v.is_undefined() {
-                    if let Some(value) = v.to_json(context)? {
-                        obj_to_return.insert(field.to_string(context)?.to_string(), value);
-                    }
-                }
+                .map(|(key, _)| key)
+                .collect(),
+        };
+
+        let mut object = serde_json::Map::new();
+        for key in keys {
+            if let Some(json) = Self::serialize_json_property(state, key.clone(), value, context)? {
+                object.insert(key.to_string(), json);
             }
-            Ok(Value::from(json_to_pretty_string(
-                &JSONValue::Object(obj_to_return),
-                gap,
-            )))
-        } else if let Some(value) = object.to_json(context)? {
-            Ok(Value::from(json_to_pretty_string(&value, gap)))
-        } else {
-            Ok(Value::undefined())
         }
+        Ok(JSONValue::Object(object))
+    }
+
+    /// `SerializeJSONArray ( state, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-serializejsonarray
+    fn serialize_json_array(
+        state: &StateRecord,
+        value: &Value,
+        context: &mut Context,
+    ) -> Result<JSONValue> {
+        let len = value.get_field("length", context)?.to_length(context)?;
+        let mut array = Vec::with_capacity(len);
+        for index in 0..len {
+            let json = Self::serialize_json_property(state, index.into(), value, context)?;
+            array.push(json.unwrap_or(JSONValue::Null));
+        }
+        Ok(JSONValue::Array(array))
     }
 }
 
+/// The `JSON.stringify` state that is shared by all the properties being serialized.
+///
+/// The indentation is handled by the serializer when the result is printed.
+#[derive(Debug)]
+struct StateRecord {
+    replacer_function: Option<Value>,
+    property_list: Option<Vec<RcString>>,
+}
+
 fn json_to_pretty_string(json: &JSONValue, gap: &str) -> String {
     if gap.is_empty() {
         return json.to_string();
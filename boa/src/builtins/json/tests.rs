@@ -94,6 +94,112 @@ fn json_stringify_replacer_function() {
     assert_eq!(actual, expected);
 }
 
+#[test]
+fn json_stringify_replacer_function_nested() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify({ a: 1, b: { c: 2 } }, (key, value) => {
+            if (typeof value === 'number') {
+                return value * 10;
+            }
+            return value;
+        })"#,
+    );
+    let expected = forward(&mut context, r#"'{"a":10,"b":{"c":20}}'"#);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_stringify_replacer_function_called_with_holder() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"
+        var calls = [];
+        JSON.stringify({ a: [1] }, function (key, value) {
+            calls.push(key + ':' + Array.isArray(this));
+            return value;
+        });
+        calls.join(',')
+        "#,
+    );
+    let expected = forward(&mut context, r#"':false,a:false,0:true'"#);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_stringify_replacer_array_nested() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify({ a: { a: 1, b: 2, c: 3 }, b: [{ a: 4, d: 5 }], c: 6 }, ['a', 'b'])"#,
+    );
+    let expected = forward(&mut context, r#"'{"a":{"a":1,"b":2},"b":[{"a":4}]}'"#);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_stringify_to_json() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify({ a: { toJSON: function (key) { return key + '!'; } } })"#,
+    );
+    let expected = forward(&mut context, r#"'{"a":"a!"}'"#);
+    assert_eq!(actual, expected);
+
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify(
+            { a: { toJSON: function () { return 1; } } },
+            (key, value) => typeof value === 'number' ? value + 1 : value
+        )"#,
+    );
+    let expected = forward(&mut context, r#"'{"a":2}'"#);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_stringify_primitive_wrappers() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify([new Number(1), new String('s'), new Boolean(false)])"#,
+    );
+    let expected = forward(&mut context, r#"'[1,"s",false]'"#);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_stringify_pretty_print_nested() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"JSON.stringify({ a: [1, {}] }, null, '--')"#,
+    );
+    let expected = forward(&mut context, r#"'{\n--"a": [\n----1,\n----{}\n--]\n}'"#);
+    assert_eq!(actual, expected);
+
+    let actual = forward(&mut context, r#"JSON.stringify([[]], null, 2)"#);
+    let expected = forward(&mut context, r#"'[\n  []\n]'"#);
+    assert_eq!(actual, expected);
+}
+
+#[test]
+fn json_stringify_cyclic_with_replacer() {
+    let mut context = Context::new();
+    let actual = forward(
+        &mut context,
+        r#"
+        var a = {};
+        a.b = { a: a };
+        JSON.stringify(a, ['a', 'b'])
+        "#,
+    );
+    assert_eq!(actual, r#"Uncaught "TypeError": "cyclic object value""#);
+}
+
 #[test]
 fn json_stringify_arrays() {
     let mut context = Context::new();
@@ -400,6 +506,77 @@ fn json_parse_object_with_reviver() {
     assert_eq!(result, r#""{"firstname":"boa","lastname":"interpreter"}""#);
 }
 
+#[test]
+fn json_parse_reviver_doubles_numbers() {
+    let mut context = Context::new();
+    let result = forward(
+        &mut context,
+        r#"
+        var parsed = JSON.parse('{"a":1,"b":{"c":[2,3]},"d":"e"}', function (key, value) {
+            return typeof value === 'number' ? value * 2 : value;
+        });
+        JSON.stringify(parsed)
+        "#,
+    );
+    let expected = forward(&mut context, r#"'{"a":2,"b":{"c":[4,6]},"d":"e"}'"#);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn json_parse_reviver_removes_undefined() {
+    let mut context = Context::new();
+    let result = forward(
+        &mut context,
+        r#"
+        var parsed = JSON.parse('{"keep":1,"drop":2}', function (key, value) {
+            return key === 'drop' ? undefined : value;
+        });
+        parsed.hasOwnProperty('keep') + ':' + ('drop' in parsed)
+        "#,
+    );
+    assert_eq!(result, r#""true:false""#);
+}
+
+#[test]
+fn json_parse_reviver_visits_children_first() {
+    let mut context = Context::new();
+    let result = forward(
+        &mut context,
+        r#"
+        var keys = [];
+        JSON.parse('{"a":[true,{"b":null}]}', function (key, value) {
+            keys.push(typeof key + ' ' + key);
+            return value;
+        });
+        keys.join(',')
+        "#,
+    );
+    assert_eq!(result, r#""string 0,string b,string 1,string a,string ""#);
+}
+
+#[test]
+fn json_parse_reviver_propagates_errors() {
+    let mut context = Context::new();
+    let result = forward(
+        &mut context,
+        r#"
+        var caught;
+        try {
+            JSON.parse('{"a":1}', function (key, value) {
+                if (key === 'a') {
+                    throw 'reviver error';
+                }
+                return value;
+            });
+        } catch (e) {
+            caught = e;
+        }
+        caught
+        "#,
+    );
+    assert_eq!(result, r#""reviver error""#);
+}
+
 #[test]
 fn json_parse_sets_prototypes() {
     let mut context = Context::new();
@@ -399,6 +399,33 @@ fn hypot() {
     assert_eq!(g.to_number(&mut context).unwrap(), 12f64);
 }
 
+#[test]
+fn hypot_non_finite_and_overflow() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "Math.hypot(3, 4) === 5"), "true");
+    assert_eq!(
+        forward(&mut context, "Math.hypot(NaN, Infinity)"),
+        "Infinity"
+    );
+    assert_eq!(
+        forward(&mut context, "Math.hypot(-Infinity, NaN)"),
+        "Infinity"
+    );
+    assert_eq!(forward(&mut context, "Math.hypot(1, NaN)"), "NaN");
+    assert_eq!(
+        forward(&mut context, "isFinite(Math.hypot(1e200, 1e200))"),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "Math.hypot({ valueOf: function() { return 3; } }, '4')"
+        ),
+        "5"
+    );
+}
+
 #[test]
 #[allow(clippy::many_single_char_names)]
 fn imul() {
@@ -427,6 +454,7 @@ fn imul() {
     assert_eq!(d.to_number(&mut context).unwrap(), -10f64);
     assert_eq!(e.to_number(&mut context).unwrap(), 0f64);
     assert_eq!(f.to_number(&mut context).unwrap(), 0f64);
+    assert_eq!(forward(&mut context, "Math.imul.length"), "2");
 }
 
 #[test]
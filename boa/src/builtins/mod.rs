@@ -17,12 +17,16 @@ pub mod math;
 pub mod nan;
 pub mod number;
 pub mod object;
+pub mod promise;
+pub mod proxy;
 pub mod reflect;
 pub mod regexp;
 pub mod set;
 pub mod string;
 pub mod symbol;
 pub mod undefined;
+pub mod weak_map;
+pub mod weak_set;
 
 pub(crate) use self::{
     array::{array_iterator::ArrayIterator, Array},
@@ -41,13 +45,17 @@ pub(crate) use self::{
     number::Number,
     object::for_in_iterator::ForInIterator,
     object::Object as BuiltInObjectObject,
+    promise::Promise,
+    proxy::Proxy,
     reflect::Reflect,
-    regexp::RegExp,
+    regexp::{regexp_string_iterator::RegExpStringIterator, RegExp},
     set::set_iterator::SetIterator,
     set::Set,
     string::String,
     symbol::Symbol,
     undefined::Undefined,
+    weak_map::WeakMap,
+    weak_set::WeakSet,
 };
 use crate::{
     property::{Attribute, DataDescriptor},
@@ -81,6 +89,8 @@ pub fn init(context: &mut Context) {
         Date::init,
         Map::init,
         Number::init,
+        Promise::init,
+        Proxy::init,
         Set::init,
         String::init,
         RegExp::init,
@@ -93,6 +103,8 @@ pub fn init(context: &mut Context) {
         EvalError::init,
         UriError::init,
         Reflect::init,
+        WeakMap::init,
+        WeakSet::init,
         #[cfg(feature = "console")]
         console::Console::init,
     ];
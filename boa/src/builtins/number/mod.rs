@@ -17,7 +17,7 @@ use super::function::make_builtin_fn;
 use super::string::is_trimmable_whitespace;
 use crate::{
     builtins::BuiltIn,
-    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    object::{ConstructorBuilder, FunctionBuilder, ObjectData, PROTOTYPE},
     property::Attribute,
     value::{AbstractRelation, IntegerOrInfinity, Value},
     BoaProfiler, Context, Result,
@@ -53,6 +53,23 @@ impl BuiltIn for Number {
     fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
         let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
 
+        // `Number.parseInt` and `Number.parseFloat` are the same function objects as the global
+        // `parseInt` and `parseFloat`.
+        let parse_int = FunctionBuilder::new(context, Self::parse_int)
+            .name("parseInt")
+            .length(PARSE_INT_MAX_ARG_COUNT)
+            .callable(true)
+            .constructable(false)
+            .build();
+        let parse_float = FunctionBuilder::new(context, Self::parse_float)
+            .name("parseFloat")
+            .length(PARSE_FLOAT_MAX_ARG_COUNT)
+            .callable(true)
+            .constructable(false)
+            .build();
+        let function_attribute =
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+
         let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
         let number_object = ConstructorBuilder::with_standard_object(
             context,
@@ -79,23 +96,13 @@ impl BuiltIn for Number {
         .static_method(Self::number_is_nan, "isNaN", 1)
         .static_method(Self::is_safe_integer, "isSafeInteger", 1)
         .static_method(Self::number_is_integer, "isInteger", 1)
+        .static_property("parseInt", parse_int.clone(), function_attribute)
+        .static_property("parseFloat", parse_float.clone(), function_attribute)
         .build();
 
-        let global = context.global_object();
-        make_builtin_fn(
-            Self::parse_int,
-            "parseInt",
-            &global,
-            PARSE_INT_MAX_ARG_COUNT,
-            context,
-        );
-        make_builtin_fn(
-            Self::parse_float,
-            "parseFloat",
-            &global,
-            PARSE_FLOAT_MAX_ARG_COUNT,
-            context,
-        );
+        let mut global = context.global_object();
+        global.insert_property("parseInt", parse_int, function_attribute);
+        global.insert_property("parseFloat", parse_float, function_attribute);
         make_builtin_fn(Self::global_is_finite, "isFinite", &global, 1, context);
         make_builtin_fn(Self::global_is_nan, "isNaN", &global, 1, context);
 
@@ -208,15 +215,33 @@ impl Number {
         Err(context.construct_type_error("'this' is not a number"))
     }
 
-    /// Helper function that formats a float as a ES6-style exponential number string.
-    fn num_to_exponential(n: f64) -> String {
-        match n.abs() {
-            x if x > 1.0 => format!("{:e}", n).replace("e", "e+"),
-            x if x == 0.0 => format!("{:e}", n).replace("e", "e+"),
-            _ => format!("{:e}", n),
+    /// The number of fractional digits needed to write any finite `f64` exactly in decimal.
+    const EXACT_FRACTION_DIGITS: usize = 1100;
+
+    /// Checks the digit count argument of `toFixed` and `toExponential`.
+    ///
+    /// Returns `None` if the argument is not an integer between `0` and `100`.
+    fn fraction_digits(digits: IntegerOrInfinity) -> Option<usize> {
+        match digits {
+            IntegerOrInfinity::Integer(x) if (0..=100).contains(&x) => Some(x as usize),
+            _ => None,
         }
     }
 
+    /// Helper function that formats the significant `digits` of a number, whose most significant
+    /// digit is multiplied by `10^exponent`, as an ES6-style exponential number string.
+    fn num_to_exponential(mut digits: String, exponent: i32) -> String {
+        if digits.len() > 1 {
+            digits.insert(1, '.');
+        }
+        digits.push('e');
+        if exponent >= 0 {
+            digits.push('+');
+        }
+        digits.push_str(&exponent.to_string());
+        digits
+    }
+
     /// `Number.prototype.toExponential( [fractionDigits] )`
     ///
     /// The `toExponential()` method returns a string representing the Number object in exponential notation.
@@ -230,12 +255,70 @@ impl Number {
     #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_exponential(
         this: &Value,
-        _: &[Value],
+        args: &[Value],
         context: &mut Context,
     ) -> Result<Value> {
-        let this_num = Self::this_number_value(this, context)?;
-        let this_str_num = Self::num_to_exponential(this_num);
-        Ok(Value::from(this_str_num))
+        // 1. Let x be ? thisNumberValue(this value).
+        let mut this_num = Self::this_number_value(this, context)?;
+
+        // 2. Let f be ? ToIntegerOrInfinity(fractionDigits).
+        let fraction_digits = args.get(0).cloned().unwrap_or_default();
+        let digits = fraction_digits.to_integer_or_infinity(context)?;
+
+        // 4. If x is not finite, return ! Number::toString(x).
+        if !this_num.is_finite() {
+            return Ok(Value::from(Self::to_native_string(this_num)));
+        }
+
+        // 5. If f < 0 or f > 100, throw a RangeError exception.
+        let fraction_digits_count = match Self::fraction_digits(digits) {
+            Some(digits) => digits,
+            None => {
+                return context
+                    .throw_range_error("toExponential() argument must be between 0 and 100")
+            }
+        };
+
+        // 8. If x < 0, then set s to "-" and x to -x.
+        let prefix = if this_num < 0.0 {
+            this_num = -this_num;
+            "-"
+        } else {
+            ""
+        };
+
+        let (digits, exponent) = if this_num == 0.0 {
+            // 9. If x = 0, let m be f + 1 zeros, and e be 0.
+            ("0".repeat(fraction_digits_count + 1), 0)
+        } else if fraction_digits.is_undefined() {
+            // 10.c. If fractionDigits is undefined, use as few digits as necessary to represent x.
+            let shortest = format!("{:e}", this_num);
+            let (mantissa, exponent) = shortest
+                .split_once('e')
+                .expect("exponential formatting always has an exponent");
+            (
+                mantissa.replace('.', ""),
+                exponent.parse().expect("the exponent is an integer"),
+            )
+        } else {
+            // 10.a. Otherwise, round the exact value of x to f + 1 significant digits, choosing the
+            // larger one on ties.
+            let mut digits = format!("{:.*}", Self::EXACT_FRACTION_DIGITS, this_num);
+            let mut exponent = Self::flt_str_to_exp(&digits);
+            if exponent < 0 {
+                digits = digits.split_off((1 - exponent) as usize);
+            } else if let Some(n) = digits.find('.') {
+                digits.remove(n);
+            }
+            if Self::round_to_precision(&mut digits, fraction_digits_count + 1) {
+                exponent += 1;
+            }
+            (digits, exponent)
+        };
+
+        Ok(Value::from(
+            prefix.to_owned() + &Self::num_to_exponential(digits, exponent),
+        ))
     }
 
     /// `Number.prototype.toFixed( [digits] )`
@@ -250,16 +333,68 @@ impl Number {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/toFixed
     #[allow(clippy::wrong_self_convention)]
     pub(crate) fn to_fixed(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let this_num = Self::this_number_value(this, context)?;
-        let precision = match args.get(0) {
-            Some(n) => match n.to_integer(context)? as i32 {
-                x if x > 0 => n.to_integer(context)? as usize,
-                _ => 0,
-            },
-            None => 0,
+        // 1. Let x be ? thisNumberValue(this value).
+        let mut this_num = Self::this_number_value(this, context)?;
+
+        // 2. Let f be ? ToIntegerOrInfinity(fractionDigits).
+        let digits = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_integer_or_infinity(context)?;
+
+        // 4-5. If f is not finite, or f < 0 or f > 100, throw a RangeError exception.
+        let digits = match Self::fraction_digits(digits) {
+            Some(digits) => digits,
+            None => {
+                return context
+                    .throw_range_error("toFixed() digits argument must be between 0 and 100")
+            }
         };
-        let this_fixed_num = format!("{:.*}", precision, this_num);
-        Ok(Value::from(this_fixed_num))
+
+        // 6. If x is not finite, return ! Number::toString(x).
+        if !this_num.is_finite() {
+            return Ok(Value::from(Self::to_native_string(this_num)));
+        }
+
+        // 7. Set x to ℝ(x), which has no negative zero.
+        if this_num == 0.0 {
+            this_num = 0.0;
+        }
+
+        // 8. If x < 0, then set s to "-" and x to -x.
+        let prefix = if this_num < 0.0 {
+            this_num = -this_num;
+            "-"
+        } else {
+            ""
+        };
+
+        // 9. If x ≥ 10^21, let m be ! ToString(𝔽(x)).
+        if this_num >= 1e21 {
+            return Ok(Value::from(
+                prefix.to_owned() + &Self::to_native_string(this_num),
+            ));
+        }
+
+        // 10. Otherwise, round the exact value of x to f fractional digits, choosing the larger
+        // one on ties.
+        let exact = format!("{:.*}", Self::EXACT_FRACTION_DIGITS, this_num);
+        let point = exact
+            .find('.')
+            .expect("exact formatting always has a point");
+        let mut m = exact[..point].to_owned();
+        m.push_str(&exact[point + 1..=point + 1 + digits]);
+        let precision = point + digits;
+        if Self::round_to_precision(&mut m, precision) {
+            m.push('0');
+        }
+
+        if digits != 0 {
+            m.insert(m.len() - digits, '.');
+        }
+
+        Ok(Value::from(prefix.to_owned() + &m))
     }
 
     /// `Number.prototype.toLocaleString( [locales [, options]] )`
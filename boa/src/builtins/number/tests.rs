@@ -75,6 +75,47 @@ fn to_exponential() {
     assert_eq!(noop_exp, "\"1.23e+2\"");
 }
 
+#[test]
+fn to_exponential_digits() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "(1).toExponential()"), "\"1e+0\"");
+    assert_eq!(
+        forward(&mut context, "(0.00015).toExponential()"),
+        "\"1.5e-4\""
+    );
+    assert_eq!(
+        forward(&mut context, "(-1234).toExponential(2)"),
+        "\"-1.23e+3\""
+    );
+    assert_eq!(forward(&mut context, "(0).toExponential(2)"), "\"0.00e+0\"");
+    assert_eq!(
+        forward(&mut context, "(1.25).toExponential(1)"),
+        "\"1.3e+0\""
+    );
+    assert_eq!(
+        forward(&mut context, "(99.99).toExponential(1)"),
+        "\"1.0e+2\""
+    );
+    assert_eq!(
+        forward(&mut context, "(123456).toExponential(0)"),
+        "\"1e+5\""
+    );
+    assert_eq!(
+        forward(&mut context, "(1/0).toExponential(1000)"),
+        "\"Infinity\""
+    );
+
+    let expected =
+        "Uncaught \"RangeError\": \"toExponential() argument must be between 0 and 100\"";
+    assert_eq!(forward(&mut context, "(1).toExponential(-1)"), expected);
+    assert_eq!(forward(&mut context, "(1).toExponential(101)"), expected);
+    assert_eq!(
+        forward(&mut context, "(1).toExponential(100).length"),
+        "105"
+    );
+}
+
 #[test]
 fn to_fixed() {
     let mut context = Context::new();
@@ -100,6 +141,37 @@ fn to_fixed() {
     assert_eq!(nan_fixed, "\"NaN\"");
 }
 
+#[test]
+fn to_fixed_rounding() {
+    let mut context = Context::new();
+
+    // 1.005 is actually stored as 1.00499999999999989...
+    assert_eq!(forward(&mut context, "(1.005).toFixed(2)"), "\"1.00\"");
+    assert_eq!(forward(&mut context, "(1.45).toFixed(1)"), "\"1.4\"");
+    // Exact ties are rounded to the larger number.
+    assert_eq!(forward(&mut context, "(0.5).toFixed(0)"), "\"1\"");
+    assert_eq!(forward(&mut context, "(2.5).toFixed(0)"), "\"3\"");
+    assert_eq!(forward(&mut context, "(1.25).toFixed(1)"), "\"1.3\"");
+    assert_eq!(forward(&mut context, "(-1.25).toFixed(1)"), "\"-1.3\"");
+    assert_eq!(forward(&mut context, "(9.96).toFixed(1)"), "\"10.0\"");
+    assert_eq!(
+        forward(&mut context, "(0.000001).toFixed(7)"),
+        "\"0.0000010\""
+    );
+    assert_eq!(forward(&mut context, "(-0.0001).toFixed(2)"), "\"-0.00\"");
+    assert_eq!(forward(&mut context, "(-0).toFixed(2)"), "\"0.00\"");
+    assert_eq!(forward(&mut context, "(1e21).toFixed(2)"), "\"1e+21\"");
+    assert_eq!(forward(&mut context, "(-1e21).toFixed(2)"), "\"-1e+21\"");
+    assert_eq!(forward(&mut context, "(1/0).toFixed(2)"), "\"Infinity\"");
+
+    let expected =
+        "Uncaught \"RangeError\": \"toFixed() digits argument must be between 0 and 100\"";
+    assert_eq!(forward(&mut context, "(1).toFixed(-1)"), expected);
+    assert_eq!(forward(&mut context, "(1).toFixed(101)"), expected);
+    assert_eq!(forward(&mut context, "(1).toFixed(1/0)"), expected);
+    assert_eq!(forward(&mut context, "(1).toFixed(100).length"), "102");
+}
+
 #[test]
 fn to_locale_string() {
     let mut context = Context::new();
@@ -528,6 +600,53 @@ fn number_constants() {
         .is_null_or_undefined());
 }
 
+#[test]
+fn number_constants_values() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        &forward(&mut context, "Number.MAX_SAFE_INTEGER"),
+        "9007199254740991"
+    );
+    assert_eq!(
+        &forward(&mut context, "Number.MIN_SAFE_INTEGER"),
+        "-9007199254740991"
+    );
+    assert_eq!(
+        &forward(&mut context, "Number.EPSILON === Math.pow(2, -52)"),
+        "true"
+    );
+    assert_eq!(
+        &forward(&mut context, "Number.isSafeInteger(2 ** 53)"),
+        "false"
+    );
+    assert_eq!(
+        &forward(
+            &mut context,
+            "Number.isSafeInteger(Number.MAX_SAFE_INTEGER)"
+        ),
+        "true"
+    );
+}
+
+#[test]
+fn number_parse_int_parse_float() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        &forward(&mut context, "Number.parseInt === parseInt"),
+        "true"
+    );
+    assert_eq!(
+        &forward(&mut context, "Number.parseFloat === parseFloat"),
+        "true"
+    );
+    assert_eq!(&forward(&mut context, "Number.parseInt('42px')"), "42");
+    assert_eq!(&forward(&mut context, "Number.parseInt('ff', 16)"), "255");
+    assert_eq!(&forward(&mut context, "Number.parseFloat('3.5e1')"), "35");
+    assert_eq!(&forward(&mut context, "Number.parseFloat('abc')"), "NaN");
+}
+
 #[test]
 fn parse_int_simple() {
     let mut context = Context::new();
@@ -570,6 +689,19 @@ fn parse_int_inferred_hex() {
     assert_eq!(&forward(&mut context, "parseInt(\"0xA\")"), "10");
 }
 
+#[test]
+fn parse_int_inferred_hex_prefix() {
+    let mut context = Context::new();
+
+    assert_eq!(&forward(&mut context, "parseInt(\"0x1F\")"), "31");
+    assert_eq!(&forward(&mut context, "parseInt(\"0X1f\")"), "31");
+    assert_eq!(&forward(&mut context, "parseInt(\"-0x1F\")"), "-31");
+    assert_eq!(&forward(&mut context, "parseInt(\"0x1F\", 16)"), "31");
+    assert_eq!(&forward(&mut context, "parseInt(\"0x1F\", 10)"), "0");
+    assert_eq!(&forward(&mut context, "parseInt(\"0x\")"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"0xg\")"), "NaN");
+}
+
 /// This test demonstrates that this version of parseInt treats strings starting with 0 to be parsed with
 /// a radix 10 if no radix is specified. Some alternative implementations default to a radix of 8.
 #[test]
@@ -624,6 +756,33 @@ fn parse_int_malformed_str() {
     assert_eq!(&forward(&mut context, "parseInt(\"hello\")"), "NaN");
 }
 
+#[test]
+fn parse_int_explicit_radix() {
+    let mut context = Context::new();
+
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 2)"), "2");
+    assert_eq!(&forward(&mut context, "parseInt(\"12\", 2)"), "1");
+    assert_eq!(&forward(&mut context, "parseInt(\"z\", 36)"), "35");
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 2.9)"), "2");
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 1)"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"10\", 37)"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"2\", 2)"), "NaN");
+}
+
+#[test]
+fn parse_int_whitespace_and_sign() {
+    let mut context = Context::new();
+
+    assert_eq!(&forward(&mut context, "parseInt(\"   42abc\")"), "42");
+    assert_eq!(&forward(&mut context, "parseInt(\"\\n\\t 42 \\n\")"), "42");
+    assert_eq!(&forward(&mut context, "parseInt(\"+42\")"), "42");
+    assert_eq!(&forward(&mut context, "parseInt(\" -42\")"), "-42");
+    assert_eq!(&forward(&mut context, "1 / parseInt(\"-0\")"), "-Infinity");
+    assert_eq!(&forward(&mut context, "parseInt(\"- 42\")"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"xyz\")"), "NaN");
+    assert_eq!(&forward(&mut context, "parseInt(\"\")"), "NaN");
+}
+
 #[test]
 fn parse_int_undefined() {
     let mut context = Context::new();
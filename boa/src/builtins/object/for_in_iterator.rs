@@ -84,6 +84,9 @@ impl ForInIterator {
                         }
                         iterator.object_was_visited = true;
                     }
+                    // The keys are a snapshot taken when the object was first visited, so keys
+                    // deleted since then are skipped, and keys already seen on an object closer
+                    // in the prototype chain (enumerable or not) shadow the inherited ones.
                     while let Some(r) = iterator.remaining_keys.pop_front() {
                         if !iterator.visited_keys.contains(&r) {
                             if let Some(desc) =
@@ -115,7 +118,7 @@ impl ForInIterator {
                 context.throw_type_error("`this` is not a ForInIterator")
             }
         } else {
-            context.throw_type_error("`this` is not an ForInIterator")
+            context.throw_type_error("`this` is not a ForInIterator")
         }
     }
 
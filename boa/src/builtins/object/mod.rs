@@ -63,6 +63,7 @@ impl BuiltIn for Object {
         .static_method(Self::define_properties, "defineProperties", 2)
         .static_method(Self::assign, "assign", 2)
         .static_method(Self::is, "is", 2)
+        .static_method(Self::has_own, "hasOwn", 2)
         .static_method(
             Self::get_own_property_descriptor,
             "getOwnPropertyDescriptor",
@@ -194,15 +195,9 @@ impl Object {
             .to_object(context)?;
         let descriptors = context.construct_object();
 
-        for key in object.borrow().keys() {
-            let descriptor = {
-                let desc = object
-                    .get_own_property(&key)
-                    .expect("Expected property to be on object.");
-                Self::from_property_descriptor(desc, context)
-            };
-
-            if !descriptor.is_undefined() {
+        for key in object.own_property_keys() {
+            if let Some(desc) = object.get_own_property(&key) {
+                let descriptor = Self::from_property_descriptor(desc, context);
                 descriptors.borrow_mut().insert(
                     key,
                     PropertyDescriptor::from(DataDescriptor::new(descriptor, Attribute::all())),
@@ -221,27 +216,27 @@ impl Object {
     fn from_property_descriptor(desc: PropertyDescriptor, context: &mut Context) -> Value {
         let mut descriptor = ObjectInitializer::new(context);
 
-        if let PropertyDescriptor::Data(data_desc) = &desc {
-            descriptor.property("value", data_desc.value(), Attribute::all());
-        }
-
-        if let PropertyDescriptor::Accessor(accessor_desc) = &desc {
-            if let Some(setter) = accessor_desc.setter() {
-                descriptor.property("set", Value::Object(setter.to_owned()), Attribute::all());
+        match &desc {
+            PropertyDescriptor::Data(data_desc) => {
+                descriptor
+                    .property("value", data_desc.value(), Attribute::all())
+                    .property(
+                        "writable",
+                        Value::from(data_desc.writable()),
+                        Attribute::all(),
+                    );
             }
-            if let Some(getter) = accessor_desc.getter() {
-                descriptor.property("get", Value::Object(getter.to_owned()), Attribute::all());
+            // Accessor descriptors have no `writable` field, but always have both `get` and `set`.
+            PropertyDescriptor::Accessor(accessor_desc) => {
+                let getter = accessor_desc.getter().cloned().map(Value::from);
+                let setter = accessor_desc.setter().cloned().map(Value::from);
+                descriptor
+                    .property("get", getter.unwrap_or_default(), Attribute::all())
+                    .property("set", setter.unwrap_or_default(), Attribute::all());
             }
         }
 
-        let writable = if let PropertyDescriptor::Data(data_desc) = &desc {
-            data_desc.writable()
-        } else {
-            false
-        };
-
         descriptor
-            .property("writable", Value::from(writable), Attribute::all())
             .property(
                 "enumerable",
                 Value::from(desc.enumerable()),
@@ -466,6 +461,34 @@ impl Object {
         Ok(object.has_own_property(key).into())
     }
 
+    /// `Object.hasOwn( object, property )`
+    ///
+    /// The method returns a boolean indicating whether the object has the specified property
+    /// as its own property. It is a safer alternative to `Object.prototype.hasOwnProperty`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-accessible-object-hasownproperty/#sec-object.hasown
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object/hasOwn
+    pub fn has_own(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let obj be ? ToObject(O).
+        let object = args
+            .get(0)
+            .unwrap_or(&Value::undefined())
+            .to_object(context)?;
+
+        // 2. Let key be ? ToPropertyKey(P).
+        let key = args
+            .get(1)
+            .unwrap_or(&Value::undefined())
+            .to_property_key(context)?;
+
+        // 3. Return ? HasOwnProperty(obj, key).
+        Ok(object.has_own_property(key).into())
+    }
+
     /// `Object.prototype.propertyIsEnumerable( property )`
     ///
     /// This method returns a Boolean indicating whether the specified property is
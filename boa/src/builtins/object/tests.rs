@@ -119,6 +119,46 @@ fn object_has_own_property() {
     );
 }
 
+#[test]
+fn object_has_own() {
+    let mut context = Context::new();
+    let init = r#"
+        let x = { someProp: 1, undefinedProp: undefined };
+        Object.defineProperty(x, 'hidden', { value: 2, enumerable: false });
+        let sym = Symbol('sym');
+        x[sym] = 3;
+        let arr = ['a', 'b'];
+    "#;
+
+    eprintln!("{}", forward(&mut context, init));
+    assert_eq!(
+        forward(&mut context, "Object.hasOwn(x, 'someProp')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.hasOwn(x, 'undefinedProp')"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Object.hasOwn(x, 'hidden')"), "true");
+    assert_eq!(forward(&mut context, "Object.hasOwn(x, sym)"), "true");
+    assert_eq!(
+        forward(&mut context, "Object.hasOwn(x, 'toString')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "Object.hasOwn(x, 'missing')"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "Object.hasOwn(arr, 1)"), "true");
+    assert_eq!(forward(&mut context, "Object.hasOwn(arr, '0')"), "true");
+    assert_eq!(forward(&mut context, "Object.hasOwn(arr, 2)"), "false");
+    assert_eq!(
+        forward(&mut context, "Object.hasOwn(arr, 'length')"),
+        "true"
+    );
+    assert!(forward(&mut context, "Object.hasOwn(null, 'a')").starts_with("Uncaught \"TypeError\""));
+}
+
 #[test]
 fn object_property_is_enumerable() {
     let mut context = Context::new();
@@ -260,6 +300,50 @@ fn get_own_property_descriptors() {
     assert_eq!(forward(&mut context, "result.b.value"), "2");
 }
 
+#[test]
+fn object_get_own_property_descriptors_accessor() {
+    let mut context = Context::new();
+    let init = r#"
+        let obj = {
+            data: 1,
+            get both() { return 2; },
+            set both(value) {},
+            get onlyGet() { return 3; },
+        };
+        let result = Object.getOwnPropertyDescriptors(obj);
+        let fields = (desc) => Reflect.ownKeys(desc).sort().join();
+    "#;
+    forward(&mut context, init);
+
+    assert_eq!(
+        forward(&mut context, "fields(result.data)"),
+        r#""configurable,enumerable,value,writable""#
+    );
+    assert_eq!(forward(&mut context, "result.data.value"), "1");
+
+    assert_eq!(
+        forward(&mut context, "fields(result.both)"),
+        r#""configurable,enumerable,get,set""#
+    );
+    assert_eq!(
+        forward(&mut context, "typeof result.both.get"),
+        r#""function""#
+    );
+    assert_eq!(
+        forward(&mut context, "typeof result.both.set"),
+        r#""function""#
+    );
+    assert_eq!(forward(&mut context, "result.both.get()"), "2");
+    assert_eq!(forward(&mut context, "result.both.enumerable"), "true");
+    assert_eq!(forward(&mut context, "result.both.configurable"), "true");
+
+    assert_eq!(
+        forward(&mut context, "fields(result.onlyGet)"),
+        r#""configurable,enumerable,get,set""#
+    );
+    assert_eq!(forward(&mut context, "result.onlyGet.set"), "undefined");
+}
+
 #[test]
 fn object_define_properties() {
     let mut context = Context::new();
@@ -0,0 +1,682 @@
+//! This module implements the global `Promise` object.
+//!
+//! The `Promise` object represents the eventual completion (or failure) of an asynchronous
+//! operation, and its resulting value.
+//!
+//! Reactions to the settlement of a promise are run as jobs, once the script that triggered
+//! them has completed. The global `queueMicrotask` function, which enqueues a callback in the
+//! same job queue, is also implemented here.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-promise-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise
+
+use crate::{
+    builtins::{
+        function::{make_builtin_fn, make_capturing_fn, Captures},
+        BuiltIn,
+    },
+    gc::{empty_trace, Finalize, Trace},
+    job::Job,
+    object::{ConstructorBuilder, FunctionBuilder, GcObject, ObjectData, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, Result, Value,
+};
+use gc::{Gc, GcCell};
+
+#[cfg(test)]
+mod tests;
+
+/// The state of a promise.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#table-internal-slots-of-promise-instances
+#[derive(Debug, Clone, Trace, Finalize)]
+pub enum PromiseState {
+    Pending,
+    Fulfilled(Value),
+    Rejected(Value),
+}
+
+/// Whether a reaction handles the fulfillment or the rejection of a promise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Finalize)]
+pub enum ReactionType {
+    Fulfill,
+    Reject,
+}
+
+unsafe impl Trace for ReactionType {
+    empty_trace!();
+}
+
+/// A promise, together with the functions that resolve or reject it.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisecapability-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct PromiseCapability {
+    promise: Value,
+    resolve: Value,
+    reject: Value,
+}
+
+/// A reaction to the fulfillment or the rejection of a promise.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-promisereaction-records
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct ReactionRecord {
+    capability: PromiseCapability,
+    reaction_type: ReactionType,
+    handler: Option<Value>,
+}
+
+/// The internal representation of a `Promise` object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Promise {
+    state: PromiseState,
+    fulfill_reactions: Vec<ReactionRecord>,
+    reject_reactions: Vec<ReactionRecord>,
+}
+
+impl BuiltIn for Promise {
+    const NAME: &'static str = "Promise";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let species = WellKnownSymbols::species();
+
+        let species_getter = FunctionBuilder::new(context, Self::species_getter)
+            .callable(true)
+            .constructable(false)
+            .name("get [Symbol.species]")
+            .build();
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+
+        let promise_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().promise_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .static_accessor(species, Some(species_getter), None, Attribute::CONFIGURABLE)
+        .static_method(Self::reject, "reject", 1)
+        .static_method(Self::resolve, "resolve", 1)
+        .method(Self::catch, "catch", 1)
+        .method(Self::then, "then", 2)
+        .property(
+            to_string_tag,
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        let global = context.global_object();
+        make_builtin_fn(Self::queue_microtask, "queueMicrotask", &global, 1, context);
+
+        (Self::NAME, promise_object.into(), Self::attribute())
+    }
+}
+
+impl Promise {
+    /// The amount of arguments this function object takes.
+    pub(crate) const LENGTH: usize = 1;
+
+    /// Creates the internal representation of a new pending promise.
+    fn new() -> Self {
+        Self {
+            state: PromiseState::Pending,
+            fulfill_reactions: Vec::new(),
+            reject_reactions: Vec::new(),
+        }
+    }
+
+    /// Returns the state of the promise.
+    #[inline]
+    pub fn state(&self) -> &PromiseState {
+        &self.state
+    }
+
+    /// `Promise ( executor )`
+    ///
+    /// Creates a new promise, and calls the executor with the functions that resolve or reject it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-executor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/Promise
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin Promise constructor without new is forbidden",
+            );
+        }
+
+        // 2.
+        let executor = match args.get(0) {
+            Some(Value::Object(ref executor)) if executor.is_callable() => executor.clone(),
+            _ => return context.throw_type_error("Promise executor is not a function"),
+        };
+
+        // 3.
+        let promise_prototype = context.standard_objects().promise_object().prototype();
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or(promise_prototype);
+
+        let promise = Self::create(prototype, context);
+
+        // 8.
+        let (resolve, reject) = Self::create_resolving_functions(&promise, context);
+
+        // 9.
+        let completion = executor.call(
+            &Value::undefined(),
+            &[resolve.into(), reject.clone().into()],
+            context,
+        );
+
+        // 10.
+        if let Err(error) = completion {
+            reject.call(&Value::undefined(), &[error], context)?;
+        }
+
+        // 11.
+        Ok(promise.into())
+    }
+
+    /// Creates a new pending promise object with the given prototype.
+    fn create(prototype: GcObject, context: &Context) -> GcObject {
+        let mut promise = context.construct_object();
+        promise.set_prototype_instance(prototype.into());
+        promise.borrow_mut().data = ObjectData::Promise(Self::new());
+        promise
+    }
+
+    /// `CreateResolvingFunctions ( promise )`
+    ///
+    /// Creates the `resolve` and `reject` functions of a promise. Only the first call to either
+    /// of them has an effect.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createresolvingfunctions
+    fn create_resolving_functions(promise: &GcObject, context: &Context) -> (GcObject, GcObject) {
+        // The captures are shared, so that they also share the `[[AlreadyResolved]]` flag.
+        let captures = Gc::new(GcCell::new(vec![promise.clone().into(), false.into()]));
+
+        let resolve = make_capturing_fn(Self::resolve_function, captures.clone(), "", 1, context);
+        let reject = make_capturing_fn(Self::reject_function, captures, "", 1, context);
+
+        (resolve, reject)
+    }
+
+    /// Marks the promise of the resolving functions as resolved, and returns it if it was not
+    /// resolved yet.
+    fn take_unresolved_promise(captures: &Captures) -> Option<GcObject> {
+        let mut captures = captures.borrow_mut();
+        if captures[1].to_boolean() {
+            return None;
+        }
+        captures[1] = true.into();
+
+        Some(
+            captures[0]
+                .as_object()
+                .expect("resolving functions must capture their promise"),
+        )
+    }
+
+    /// Promise Resolve Functions
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-resolve-functions
+    fn resolve_function(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 3-6.
+        let promise = match Self::take_unresolved_promise(captures) {
+            Some(promise) => promise,
+            None => return Ok(Value::undefined()),
+        };
+        let resolution = args.get(0).cloned().unwrap_or_default();
+
+        let thenable = match resolution {
+            // 7.
+            Value::Object(ref object) if GcObject::equals(object, &promise) => {
+                let error = context.construct_type_error("cannot resolve a promise with itself");
+                Self::reject_promise(&promise, error, context);
+                return Ok(Value::undefined());
+            }
+            Value::Object(ref object) => object.clone(),
+            // 8.
+            _ => {
+                Self::fulfill_promise(&promise, resolution, context);
+                return Ok(Value::undefined());
+            }
+        };
+
+        // 9-10.
+        let then = match resolution.get_field("then", context) {
+            Ok(then) => then,
+            Err(error) => {
+                Self::reject_promise(&promise, error, context);
+                return Ok(Value::undefined());
+            }
+        };
+
+        // 11-13.
+        match then {
+            Value::Object(ref then) if then.is_callable() => {
+                let then = then.clone();
+                context.enqueue_job(Job::new(move |context| {
+                    Self::resolve_thenable_job(&promise, &thenable, &then, context)
+                }));
+            }
+            _ => Self::fulfill_promise(&promise, resolution, context),
+        }
+
+        Ok(Value::undefined())
+    }
+
+    /// Promise Reject Functions
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise-reject-functions
+    fn reject_function(
+        _: &Value,
+        args: &[Value],
+        captures: &Captures,
+        context: &mut Context,
+    ) -> Result<Value> {
+        if let Some(promise) = Self::take_unresolved_promise(captures) {
+            let reason = args.get(0).cloned().unwrap_or_default();
+            Self::reject_promise(&promise, reason, context);
+        }
+
+        Ok(Value::undefined())
+    }
+
+    /// `NewPromiseResolveThenableJob ( promiseToResolve, thenable, then )`
+    ///
+    /// Resolves a promise with the settlement of a thenable.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromiseresolvethenablejob
+    fn resolve_thenable_job(
+        promise: &GcObject,
+        thenable: &GcObject,
+        then: &GcObject,
+        context: &mut Context,
+    ) -> Result<Value> {
+        let (resolve, reject) = Self::create_resolving_functions(promise, context);
+
+        match then.call(
+            &thenable.clone().into(),
+            &[resolve.into(), reject.clone().into()],
+            context,
+        ) {
+            Err(error) => reject.call(&Value::undefined(), &[error], context),
+            result => result,
+        }
+    }
+
+    /// `FulfillPromise ( promise, value )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-fulfillpromise
+    fn fulfill_promise(promise: &GcObject, value: Value, context: &mut Context) {
+        let reactions = {
+            let mut object = promise.borrow_mut();
+            let promise = object
+                .as_promise_mut()
+                .expect("cannot fulfill a non-promise object");
+            debug_assert!(matches!(promise.state, PromiseState::Pending));
+
+            promise.reject_reactions.clear();
+            promise.state = PromiseState::Fulfilled(value.clone());
+            std::mem::take(&mut promise.fulfill_reactions)
+        };
+
+        Self::trigger_promise_reactions(reactions, &value, context);
+    }
+
+    /// `RejectPromise ( promise, reason )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-rejectpromise
+    fn reject_promise(promise: &GcObject, reason: Value, context: &mut Context) {
+        let reactions = {
+            let mut object = promise.borrow_mut();
+            let promise = object
+                .as_promise_mut()
+                .expect("cannot reject a non-promise object");
+            debug_assert!(matches!(promise.state, PromiseState::Pending));
+
+            promise.fulfill_reactions.clear();
+            promise.state = PromiseState::Rejected(reason.clone());
+            std::mem::take(&mut promise.reject_reactions)
+        };
+
+        Self::trigger_promise_reactions(reactions, &reason, context);
+    }
+
+    /// `TriggerPromiseReactions ( reactions, argument )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-triggerpromisereactions
+    fn trigger_promise_reactions(
+        reactions: Vec<ReactionRecord>,
+        argument: &Value,
+        context: &mut Context,
+    ) {
+        for reaction in reactions {
+            Self::enqueue_reaction_job(reaction, argument.clone(), context);
+        }
+    }
+
+    /// `NewPromiseReactionJob ( reaction, argument )`
+    ///
+    /// Enqueues a job that calls the handler of the reaction, and settles the promise of the
+    /// reaction with its result.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisereactionjob
+    fn enqueue_reaction_job(reaction: ReactionRecord, argument: Value, context: &mut Context) {
+        context.enqueue_job(Job::new(move |context| {
+            let handler_result = match reaction.handler {
+                Some(ref handler) => context.call(handler, &Value::undefined(), &[argument]),
+                None if reaction.reaction_type == ReactionType::Fulfill => Ok(argument),
+                None => Err(argument),
+            };
+
+            let capability = &reaction.capability;
+            match handler_result {
+                Ok(value) => context.call(&capability.resolve, &Value::undefined(), &[value]),
+                Err(reason) => context.call(&capability.reject, &Value::undefined(), &[reason]),
+            }
+        }));
+    }
+
+    /// `NewPromiseCapability ( %Promise% )`
+    ///
+    /// Creates a new pending promise with its resolving functions.
+    ///
+    /// Subclasses of `Promise` are not supported yet, so the promise is always created by the
+    /// intrinsic `Promise` constructor.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-newpromisecapability
+    fn new_promise_capability(context: &Context) -> PromiseCapability {
+        let prototype = context.standard_objects().promise_object().prototype();
+        let promise = Self::create(prototype, context);
+        let (resolve, reject) = Self::create_resolving_functions(&promise, context);
+
+        PromiseCapability {
+            promise: promise.into(),
+            resolve: resolve.into(),
+            reject: reject.into(),
+        }
+    }
+
+    /// `PerformPromiseThen ( promise, onFulfilled, onRejected, resultCapability )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-performpromisethen
+    fn perform_promise_then(
+        promise: &GcObject,
+        on_fulfilled: &Value,
+        on_rejected: &Value,
+        capability: PromiseCapability,
+        context: &mut Context,
+    ) -> Value {
+        // 3-6.
+        let callable = |handler: &Value| match handler {
+            Value::Object(ref object) if object.is_callable() => Some(handler.clone()),
+            _ => None,
+        };
+        let result = capability.promise.clone();
+
+        // 7-8.
+        let fulfill_reaction = ReactionRecord {
+            capability: capability.clone(),
+            reaction_type: ReactionType::Fulfill,
+            handler: callable(on_fulfilled),
+        };
+        let reject_reaction = ReactionRecord {
+            capability,
+            reaction_type: ReactionType::Reject,
+            handler: callable(on_rejected),
+        };
+
+        // 9-11.
+        let state = promise
+            .borrow()
+            .as_promise()
+            .expect("PerformPromiseThen called on a non-promise object")
+            .state
+            .clone();
+        match &state {
+            PromiseState::Pending => {
+                let mut object = promise.borrow_mut();
+                let promise = object
+                    .as_promise_mut()
+                    .expect("PerformPromiseThen called on a non-promise object");
+                promise.fulfill_reactions.push(fulfill_reaction);
+                promise.reject_reactions.push(reject_reaction);
+            }
+            PromiseState::Fulfilled(value) => {
+                Self::enqueue_reaction_job(fulfill_reaction, value.clone(), context)
+            }
+            PromiseState::Rejected(reason) => {
+                Self::enqueue_reaction_job(reject_reaction, reason.clone(), context)
+            }
+        }
+
+        // 13.
+        result
+    }
+
+    /// `get Promise [ @@species ]`
+    ///
+    /// get accessor for the @@species property of Promise
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-get-promise-@@species
+    fn species_getter(this: &Value, _: &[Value], _: &mut Context) -> Result<Value> {
+        Ok(this.clone())
+    }
+
+    /// `Promise.resolve ( x )`
+    ///
+    /// Returns a promise resolved with the given value. If the value is already a promise, it is
+    /// returned as is.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.resolve
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/resolve
+    pub(crate) fn resolve(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 2.
+        if !this.is_object() {
+            return context.throw_type_error("Promise.resolve called on a non-object");
+        }
+
+        let x = args.get(0).cloned().unwrap_or_default();
+
+        // PromiseResolve ( C, x )
+        // 1.
+        if let Value::Object(ref object) = x {
+            if object.borrow().is_promise() {
+                let constructor = x.get_field("constructor", context)?;
+                if constructor.strict_equals(this) {
+                    return Ok(x);
+                }
+            }
+        }
+
+        // 2-4.
+        let capability = Self::new_promise_capability(context);
+        context.call(&capability.resolve, &Value::undefined(), &[x])?;
+        Ok(capability.promise.clone())
+    }
+
+    /// `Promise.reject ( r )`
+    ///
+    /// Returns a promise rejected with the given reason.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.reject
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/reject
+    pub(crate) fn reject(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        if !this.is_object() {
+            return context.throw_type_error("Promise.reject called on a non-object");
+        }
+
+        let reason = args.get(0).cloned().unwrap_or_default();
+
+        let capability = Self::new_promise_capability(context);
+        context.call(&capability.reject, &Value::undefined(), &[reason])?;
+        Ok(capability.promise.clone())
+    }
+
+    /// `Promise.prototype.then ( onFulfilled, onRejected )`
+    ///
+    /// Appends fulfillment and rejection handlers to the promise, and returns a new promise
+    /// resolving to the return value of the called handler.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.then
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/then
+    pub(crate) fn then(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1-2.
+        let promise = match this {
+            Value::Object(ref object) if object.borrow().is_promise() => object.clone(),
+            _ => {
+                return context
+                    .throw_type_error("Promise.prototype.then called on incompatible receiver")
+            }
+        };
+
+        let on_fulfilled = args.get(0).cloned().unwrap_or_default();
+        let on_rejected = args.get(1).cloned().unwrap_or_default();
+
+        // 3-5.
+        let capability = Self::new_promise_capability(context);
+        Ok(Self::perform_promise_then(
+            &promise,
+            &on_fulfilled,
+            &on_rejected,
+            capability,
+            context,
+        ))
+    }
+
+    /// `Promise.prototype.catch ( onRejected )`
+    ///
+    /// Appends a rejection handler to the promise. This is the same as calling
+    /// `then(undefined, onRejected)`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-promise.prototype.catch
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Promise/catch
+    pub(crate) fn catch(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let on_rejected = args.get(0).cloned().unwrap_or_default();
+
+        let then = this.get_field("then", context)?;
+        context.call(&then, this, &[Value::undefined(), on_rejected])
+    }
+
+    /// `queueMicrotask ( callback )`
+    ///
+    /// Enqueues the callback in the job queue, to be called after the current script has
+    /// completed, in order with the reactions of promises.
+    ///
+    /// More information:
+    ///  - [HTML reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://html.spec.whatwg.org/multipage/timers-and-user-prompts.html#dom-queuemicrotask
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/WindowOrWorkerGlobalScope/queueMicrotask
+    pub(crate) fn queue_microtask(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let callback = match args.get(0) {
+            Some(Value::Object(ref callback)) if callback.is_callable() => callback.clone(),
+            _ => return context.throw_type_error("queueMicrotask: callback is not a function"),
+        };
+
+        context.enqueue_job(Job::new(move |context| {
+            callback.call(&Value::undefined(), &[], context)
+        }));
+
+        Ok(Value::undefined())
+    }
+}
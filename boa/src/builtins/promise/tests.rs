@@ -0,0 +1,180 @@
+use crate::{forward, Context};
+
+#[test]
+fn then_runs_after_script() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        Promise.resolve(1).then(function (value) { log.push("then " + value); });
+        log.push("script");
+        log.join()
+        "#;
+    let result = context.eval(init).unwrap();
+    assert_eq!(result.display().to_string(), "\"script\"");
+    assert_eq!(forward(&mut context, "log.join()"), "\"script,then 1\"");
+}
+
+#[test]
+fn executor_is_called_synchronously() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        new Promise(function (resolve) {
+            log.push("executor");
+            resolve(2);
+        }).then(function (value) { log.push("then " + value); });
+        log.push("script");
+        "#;
+    context.eval(init).unwrap();
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"executor,script,then 2\""
+    );
+}
+
+#[test]
+fn chaining() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        Promise.resolve(1)
+            .then(function (value) { return value + 1; })
+            .then(function (value) { return Promise.resolve(value * 10); })
+            .then(function (value) { result = value; });
+        "#;
+    context.eval(init).unwrap();
+    assert_eq!(forward(&mut context, "result"), "20");
+}
+
+#[test]
+fn catch() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        Promise.reject("no")
+            .then(function () { log.push("skipped"); })
+            .catch(function (reason) { log.push("caught " + reason); });
+        new Promise(function () { throw "thrown"; })
+            .catch(function (reason) { log.push("caught " + reason); });
+        Promise.resolve(1)
+            .then(function () { throw "later"; })
+            .catch(function (reason) { log.push("caught " + reason); return "recovered"; })
+            .then(function (value) { log.push(value); });
+        "#;
+    context.eval(init).unwrap();
+    assert_eq!(
+        forward(&mut context, "log.join()"),
+        "\"caught thrown,caught no,caught later,recovered\""
+    );
+}
+
+#[test]
+fn settles_only_once() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        new Promise(function (resolve, reject) {
+            resolve(1);
+            resolve(2);
+            reject(3);
+        }).then(
+            function (value) { log.push("fulfilled " + value); },
+            function (reason) { log.push("rejected " + reason); }
+        );
+        "#;
+    context.eval(init).unwrap();
+    assert_eq!(forward(&mut context, "log.join()"), "\"fulfilled 1\"");
+}
+
+#[test]
+fn resolve_with_thenable() {
+    let mut context = Context::new();
+    let init = r#"
+        var result;
+        var thenable = { then: function (resolve) { resolve(5); } };
+        Promise.resolve(thenable).then(function (value) { result = value; });
+        "#;
+    context.eval(init).unwrap();
+    assert_eq!(forward(&mut context, "result"), "5");
+}
+
+#[test]
+fn resolve_with_itself() {
+    let mut context = Context::new();
+    let init = r#"
+        var resolvePromise;
+        var result;
+        var promise = new Promise(function (resolve) { resolvePromise = resolve; });
+        resolvePromise(promise);
+        promise.catch(function (error) { result = error.name; });
+        "#;
+    context.eval(init).unwrap();
+    assert_eq!(forward(&mut context, "result"), "\"TypeError\"");
+}
+
+#[test]
+fn resolve_returns_promises_as_is() {
+    let mut context = Context::new();
+    let init = r#"
+        var promise = new Promise(function () {});
+        "#;
+    forward(&mut context, init);
+    assert_eq!(
+        forward(&mut context, "Promise.resolve(promise) === promise"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "Promise.reject(promise) === promise"),
+        "false"
+    );
+}
+
+#[test]
+fn jobs_are_run_in_order() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        queueMicrotask(function () { log.push("a"); });
+        Promise.resolve()
+            .then(function () { log.push("b"); })
+            .then(function () { log.push("d"); });
+        queueMicrotask(function () { log.push("c"); });
+        "#;
+    context.eval(init).unwrap();
+    assert_eq!(forward(&mut context, "log.join()"), "\"a,b,c,d\"");
+}
+
+#[test]
+fn errors() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "Promise(function () {})"),
+        "Uncaught \"TypeError\": \"calling a builtin Promise constructor without new is forbidden\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Promise(1)"),
+        "Uncaught \"TypeError\": \"Promise executor is not a function\""
+    );
+    assert_eq!(
+        forward(&mut context, "Promise.prototype.then.call({})"),
+        "Uncaught \"TypeError\": \"Promise.prototype.then called on incompatible receiver\""
+    );
+    assert_eq!(
+        forward(&mut context, "queueMicrotask(1)"),
+        "Uncaught \"TypeError\": \"queueMicrotask: callback is not a function\""
+    );
+}
+
+#[test]
+fn to_string_tag() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "Object.prototype.toString.call(Promise.resolve())"
+        ),
+        "\"[object Promise]\""
+    );
+}
@@ -0,0 +1,194 @@
+//! This module implements the global `Proxy` object.
+//!
+//! The `Proxy` object enables you to create a proxy for another object, which can intercept and
+//! redefine fundamental operations for that object. The `get`, `set`, `has` and
+//! `deleteProperty` traps of the handler are supported, every other operation is forwarded to
+//! the target.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-proxy-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy
+
+use crate::{
+    builtins::BuiltIn,
+    gc::{Finalize, Trace},
+    object::{FunctionBuilder, GcObject, ObjectData},
+    property::{Attribute, PropertyKey},
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+/// The target and handler of a proxy object.
+#[derive(Debug, Clone, Trace, Finalize)]
+pub struct Proxy {
+    target: GcObject,
+    handler: GcObject,
+}
+
+impl BuiltIn for Proxy {
+    const NAME: &'static str = "Proxy";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let proxy = FunctionBuilder::new(context, Self::constructor)
+            .name(Self::NAME)
+            .length(Self::LENGTH)
+            .constructable(true)
+            .build();
+
+        (Self::NAME, proxy.into(), Self::attribute())
+    }
+}
+
+impl Proxy {
+    pub(crate) const LENGTH: usize = 2;
+
+    /// `Proxy ( target, handler )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-target-handler
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Proxy/Proxy
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1.
+        if new_target.is_undefined() {
+            return context
+                .throw_type_error("calling a builtin Proxy constructor without new is forbidden");
+        }
+
+        // 2. ProxyCreate ( target, handler )
+        let target = args
+            .get(0)
+            .and_then(Value::as_object)
+            .ok_or_else(|| context.construct_type_error("proxy target must be an object"))?;
+        let handler = args
+            .get(1)
+            .and_then(Value::as_object)
+            .ok_or_else(|| context.construct_type_error("proxy handler must be an object"))?;
+
+        let proxy = Value::from(context.construct_object());
+        proxy.set_data(ObjectData::Proxy(Self { target, handler }));
+        Ok(proxy)
+    }
+
+    /// Gets the object the proxy forwards the operations it doesn't trap to.
+    #[inline]
+    pub(crate) fn target(&self) -> &GcObject {
+        &self.target
+    }
+
+    /// Gets the trap of the handler with the given name, if it defines one.
+    fn trap(&self, name: &str, context: &mut Context) -> Result<Option<GcObject>> {
+        self.handler.get_method(context, name)
+    }
+
+    /// `[[HasProperty]] ( P )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-hasproperty-p
+    pub(crate) fn has(&self, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+        match self.trap("has", context)? {
+            Some(trap) => {
+                let args = [self.target.clone().into(), key_to_value(key)];
+                Ok(trap
+                    .call(&self.handler.clone().into(), &args, context)?
+                    .to_boolean())
+            }
+            None => context.has_property(&self.target.clone().into(), key),
+        }
+    }
+
+    /// `[[Get]] ( P, Receiver )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-get-p-receiver
+    pub(crate) fn get(
+        &self,
+        key: &PropertyKey,
+        receiver: Value,
+        context: &mut Context,
+    ) -> Result<Value> {
+        match self.trap("get", context)? {
+            Some(trap) => {
+                let args = [self.target.clone().into(), key_to_value(key), receiver];
+                trap.call(&self.handler.clone().into(), &args, context)
+            }
+            None => self.target.get(key, receiver, context),
+        }
+    }
+
+    /// `[[Set]] ( P, V, Receiver )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-set-p-v-receiver
+    pub(crate) fn set(
+        &self,
+        key: PropertyKey,
+        value: Value,
+        receiver: Value,
+        context: &mut Context,
+    ) -> Result<bool> {
+        match self.trap("set", context)? {
+            Some(trap) => {
+                let args = [
+                    self.target.clone().into(),
+                    key_to_value(&key),
+                    value,
+                    receiver,
+                ];
+                Ok(trap
+                    .call(&self.handler.clone().into(), &args, context)?
+                    .to_boolean())
+            }
+            None => self.target.clone().set(key, value, receiver, context),
+        }
+    }
+
+    /// `[[Delete]] ( P )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-proxy-object-internal-methods-and-internal-slots-delete-p
+    pub(crate) fn delete(&self, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+        match self.trap("deleteProperty", context)? {
+            Some(trap) => {
+                let args = [self.target.clone().into(), key_to_value(key)];
+                Ok(trap
+                    .call(&self.handler.clone().into(), &args, context)?
+                    .to_boolean())
+            }
+            None => self.target.clone().delete(key, context),
+        }
+    }
+}
+
+/// Converts a property key to the value passed to traps, where array indices are strings.
+fn key_to_value(key: &PropertyKey) -> Value {
+    match key {
+        PropertyKey::Index(index) => index.to_string().into(),
+        key => key.into(),
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{forward, Context};
+
+#[test]
+fn get_trap_logs_accesses() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        var target = { a: 1, b: 2 };
+        var proxy = new Proxy(target, {
+            get(target, key, receiver) {
+                log.push(key);
+                return target[key];
+            }
+        });
+        var sum = proxy.a + proxy["b"] + proxy[0];
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "sum"), "NaN");
+    assert_eq!(forward(&mut context, "log.join()"), "\"a,b,0\"");
+    assert_eq!(forward(&mut context, "typeof log[2]"), "\"string\"");
+}
+
+#[test]
+fn set_trap_validates_values() {
+    let mut context = Context::new();
+    let init = r#"
+        var person = {};
+        var proxy = new Proxy(person, {
+            set(target, key, value) {
+                if (key === "age" && typeof value !== "number") {
+                    throw new TypeError("age must be a number");
+                }
+                target[key] = value;
+                return true;
+            }
+        });
+        proxy.age = 42;
+        proxy.name = "boa";
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "person.age"), "42");
+    assert_eq!(forward(&mut context, "person.name"), "\"boa\"");
+    assert_eq!(
+        forward(&mut context, "proxy.age = 'old'"),
+        "Uncaught \"TypeError\": \"age must be a number\""
+    );
+    assert_eq!(forward(&mut context, "person.age"), "42");
+}
+
+#[test]
+fn has_and_delete_property_traps() {
+    let mut context = Context::new();
+    let init = r#"
+        var target = { _secret: 1, visible: 2 };
+        var proxy = new Proxy(target, {
+            has(target, key) {
+                return !key.startsWith("_") && key in target;
+            },
+            deleteProperty(target, key) {
+                if (key.startsWith("_")) {
+                    return false;
+                }
+                return delete target[key];
+            }
+        });
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "'visible' in proxy"), "true");
+    assert_eq!(forward(&mut context, "'_secret' in proxy"), "false");
+    assert_eq!(
+        forward(&mut context, "Reflect.has(proxy, '_secret')"),
+        "false"
+    );
+    assert_eq!(forward(&mut context, "delete proxy._secret"), "false");
+    assert_eq!(forward(&mut context, "target._secret"), "1");
+    assert_eq!(
+        forward(&mut context, "Reflect.deleteProperty(proxy, 'visible')"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "'visible' in target"), "false");
+}
+
+#[test]
+fn delete_property_trap_of_proxied_proxy() {
+    let mut context = Context::new();
+    let init = r#"
+        var log = [];
+        var inner = new Proxy({ a: 1, b: 2 }, {
+            deleteProperty(target, key) {
+                log.push(key);
+                return delete target[key];
+            }
+        });
+        var outer = new Proxy(inner, {});
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "delete outer.a"), "true");
+    assert_eq!(
+        forward(&mut context, "Reflect.deleteProperty(outer, 'b')"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "log.join()"), "\"a,b\"");
+    assert_eq!(forward(&mut context, "Reflect.ownKeys(inner).length"), "0");
+}
+
+#[test]
+fn operations_without_traps_are_forwarded() {
+    let mut context = Context::new();
+    let init = r#"
+        var target = { a: 1 };
+        var proxy = new Proxy(target, {});
+        proxy.b = 2;
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "proxy.a"), "1");
+    assert_eq!(forward(&mut context, "target.b"), "2");
+    assert_eq!(forward(&mut context, "'a' in proxy"), "true");
+    assert_eq!(forward(&mut context, "delete proxy.a"), "true");
+    assert_eq!(forward(&mut context, "target.a"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Reflect.ownKeys(proxy).join()"),
+        "\"b\""
+    );
+}
+
+#[test]
+fn errors() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "Proxy({}, {})"),
+        "Uncaught \"TypeError\": \"calling a builtin Proxy constructor without new is forbidden\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Proxy(1, {})"),
+        "Uncaught \"TypeError\": \"proxy target must be an object\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Proxy({}, null)"),
+        "Uncaught \"TypeError\": \"proxy handler must be an object\""
+    );
+    assert_eq!(
+        forward(&mut context, "new Proxy({}, { get: 1 }).a"),
+        "Uncaught \"TypeError\": \"value returned for property of object is not a function\""
+    );
+}
@@ -13,7 +13,7 @@
 use crate::{
     builtins::{self, BuiltIn},
     object::{Object, ObjectData, ObjectInitializer},
-    property::{Attribute, DataDescriptor},
+    property::{Attribute, DataDescriptor, PropertyKey},
     symbol::WellKnownSymbols,
     BoaProfiler, Context, Result, Value,
 };
@@ -54,7 +54,7 @@ impl BuiltIn for Reflect {
             .function(Self::own_keys, "ownKeys", 1)
             .function(Self::prevent_extensions, "preventExtensions", 1)
             .function(Self::set, "set", 3)
-            .function(Self::set_prototype_of, "setPrototypeOf", 3)
+            .function(Self::set_prototype_of, "setPrototypeOf", 2)
             .property(
                 to_string_tag,
                 Self::NAME,
@@ -178,7 +178,7 @@ impl Reflect {
             .ok_or_else(|| context.construct_type_error("target must be an object"))?;
         let key = args.get(1).unwrap_or(&undefined).to_property_key(context)?;
 
-        Ok(target.delete(&key).into())
+        Ok(target.delete(&key, context)?.into())
     }
 
     /// Gets a property of an object.
@@ -263,7 +263,7 @@ impl Reflect {
             .get(1)
             .unwrap_or(&Value::undefined())
             .to_property_key(context)?;
-        Ok(target.has_property(&key).into())
+        Ok(context.has_property(&target.into(), &key)?.into())
     }
 
     /// Returns `true` if the object is extensible, `false` otherwise.
@@ -308,7 +308,12 @@ impl Reflect {
 
         let keys = target.own_property_keys();
         for (i, k) in keys.iter().enumerate() {
-            result.set_field(i, k, context)?;
+            // Array index keys are still strings as far as JavaScript is concerned.
+            let key = match k {
+                PropertyKey::Index(index) => Value::from(index.to_string()),
+                _ => Value::from(k),
+            };
+            result.set_field(i, key, context)?;
         }
 
         Ok(result)
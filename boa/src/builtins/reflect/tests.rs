@@ -146,6 +146,37 @@ fn own_keys() {
     assert_eq!(forward(&mut context, "ownKeys"), r#"[ "p" ]"#);
 }
 
+#[test]
+fn own_keys_index_keys_are_strings() {
+    let mut context = Context::new();
+
+    let init = r#"
+        let keys = Reflect.ownKeys({ 0: "a", 1: "b" });
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "keys.length"), "2");
+    assert_eq!(
+        forward(&mut context, "keys.every(k => typeof k === 'string')"),
+        "true"
+    );
+}
+
+#[test]
+fn apply_argument_list() {
+    let mut context = Context::new();
+
+    let init = r#"
+        let max = Reflect.apply(Math.max, undefined, [1, 3, 2]);
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "max"), "3");
+    assert_eq!(forward(&mut context, "Reflect.setPrototypeOf.length"), "2");
+}
+
 #[test]
 fn prevent_extensions() {
     let mut context = Context::new();
@@ -9,6 +9,7 @@
 //! [spec]: https://tc39.es/ecma262/#sec-regexp-constructor
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp
 
+use self::regexp_string_iterator::RegExpStringIterator;
 use crate::{
     builtins::BuiltIn,
     gc::{empty_trace, Finalize, Trace},
@@ -17,8 +18,9 @@ use crate::{
     value::{RcString, Value},
     BoaProfiler, Context, Result,
 };
-use regress::Regex;
+use regress::{Match, Regex};
 
+pub mod regexp_string_iterator;
 #[cfg(test)]
 mod tests;
 
@@ -52,6 +54,12 @@ pub struct RegExp {
     /// Flag 'u' - Unicode.
     unicode: bool,
 
+    /// The names of the named capture groups, and the index of each group.
+    named_groups: Box<[(Box<str>, usize)]>,
+
+    /// The pattern handed to the matcher, with the named groups translated to numbered ones.
+    pub(crate) pattern: Box<str>,
+
     pub(crate) original_source: Box<str>,
     original_flags: Box<str>,
 }
@@ -191,6 +199,16 @@ impl RegExp {
             regex_flags = flags.to_string().into_boxed_str();
         }
 
+        // the flags may only contain "g", "i", "m", "s", "u" and "y", each at most once
+        for (i, flag) in regex_flags.char_indices() {
+            if !"gimsuy".contains(flag) || regex_flags[i + flag.len_utf8()..].contains(flag) {
+                return Err(ctx.construct_syntax_error(format!(
+                    "invalid regular expression flags '{}'",
+                    regex_flags
+                )));
+            }
+        }
+
         // parse flags
         let mut sorted_flags = String::new();
         let mut dot_all = false;
@@ -224,7 +242,16 @@ impl RegExp {
             sorted_flags.push('y');
         }
 
-        let matcher = match Regex::with_flags(&regex_body, sorted_flags.as_str()) {
+        let (pattern, named_groups) = match translate_named_groups(&regex_body) {
+            Err(error) => {
+                return Err(
+                    ctx.construct_syntax_error(format!("failed to create matcher: {}", error))
+                );
+            }
+            Ok(val) => val,
+        };
+
+        let matcher = match Regex::with_flags(&pattern, sorted_flags.as_str()) {
             Err(error) => {
                 return Err(
                     ctx.construct_syntax_error(format!("failed to create matcher: {}", error.text))
@@ -243,6 +270,8 @@ impl RegExp {
             multiline,
             sticky,
             unicode,
+            named_groups: named_groups.into_boxed_slice(),
+            pattern: pattern.into_boxed_str(),
             original_source: regex_body,
             original_flags: regex_flags,
         };
@@ -252,6 +281,22 @@ impl RegExp {
         Ok(this)
     }
 
+    /// Matches the regular expression against `input`, starting at `last_index`.
+    ///
+    /// If neither the global nor the sticky flag is set, the match starts at the beginning of
+    /// `input` instead. If the sticky flag is set, the match must start exactly at `last_index`.
+    fn find_match(&self, input: &str, last_index: usize) -> Option<Match> {
+        let last_index = if self.use_last_index { last_index } else { 0 };
+        if last_index > input.len() {
+            return None;
+        }
+
+        self.matcher
+            .find_from(input, last_index)
+            .next()
+            .filter(|m| !self.sticky || m.start() == last_index)
+    }
+
     #[inline]
     fn regexp_has_flag(this: &Value, flag: char, context: &mut Context) -> Result<Value> {
         if let Some(object) = this.as_object() {
@@ -479,18 +524,17 @@ impl RegExp {
             // 4. Let match be ? RegExpExec(R, string).
             let object = object.borrow();
             if let Some(regex) = object.as_regexp() {
-                let result =
-                    if let Some(m) = regex.matcher.find_from(arg_str.as_str(), last_index).next() {
-                        if regex.use_last_index {
-                            last_index = m.end();
-                        }
-                        true
-                    } else {
-                        if regex.use_last_index {
-                            last_index = 0;
-                        }
-                        false
-                    };
+                let result = if let Some(m) = regex.find_match(arg_str.as_str(), last_index) {
+                    if regex.use_last_index {
+                        last_index = m.end();
+                    }
+                    true
+                } else {
+                    if regex.use_last_index {
+                        last_index = 0;
+                    }
+                    false
+                };
 
                 // 5. If match is not null, return true; else return false.
                 Ok(Value::boolean(result))
@@ -534,14 +578,7 @@ impl RegExp {
                     .to_string(context)?;
 
                 let result = {
-                    if last_index > arg_str.len() {
-                        if regex.use_last_index {
-                            last_index = 0;
-                        }
-                        Value::null()
-                    } else if let Some(m) =
-                        regex.matcher.find_from(arg_str.as_str(), last_index).next()
-                    {
+                    if let Some(m) = regex.find_match(arg_str.as_str(), last_index) {
                         if regex.use_last_index {
                             last_index = m.end();
                         }
@@ -557,6 +594,28 @@ impl RegExp {
                             }
                         }
 
+                        // the `groups` object holds the named capture groups, and has no prototype
+                        let named_groups = if regex.named_groups.is_empty() {
+                            Value::undefined()
+                        } else {
+                            let mut groups = context.construct_object();
+                            groups.set_prototype_instance(Value::null());
+                            let groups = Value::from(groups);
+                            for (name, index) in regex.named_groups.iter() {
+                                let value = match m.group(*index) {
+                                    Some(range) => Value::from(
+                                        arg_str.get(range).expect("Could not get slice"),
+                                    ),
+                                    None => Value::undefined(),
+                                };
+                                groups.set_property(
+                                    &**name,
+                                    DataDescriptor::new(value, Attribute::all()),
+                                );
+                            }
+                            groups
+                        };
+
                         let result = Value::from(result);
                         result.set_property(
                             "index",
@@ -564,6 +623,10 @@ impl RegExp {
                         );
                         result
                             .set_property("input", DataDescriptor::new(arg_str, Attribute::all()));
+                        result.set_property(
+                            "groups",
+                            DataDescriptor::new(named_groups, Attribute::all()),
+                        );
                         result
                     } else {
                         if regex.use_last_index {
@@ -664,38 +727,17 @@ impl RegExp {
     ///
     /// [spec]: https://tc39.es/ecma262/#sec-regexp-prototype-matchall
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/RegExp/@@matchAll
-    // TODO: it's returning an array, it should return an iterator
-    pub(crate) fn match_all(this: &Value, arg_str: String, context: &mut Context) -> Result<Value> {
-        let matches = if let Some(object) = this.as_object() {
+    pub(crate) fn match_all(
+        this: &Value,
+        arg_str: RcString,
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 5. Let C be ? SpeciesConstructor(R, %RegExp%).
+        // 6-9. Let matcher be a copy of R with the same flags.
+        let global = if let Some(object) = this.as_object() {
             let object = object.borrow();
             if let Some(regex) = object.as_regexp() {
-                let mut matches = Vec::new();
-
-                for mat in regex.matcher.find_iter(&arg_str) {
-                    let match_vec: Vec<Value> = mat
-                        .groups()
-                        .map(|group| match group {
-                            Some(range) => Value::from(&arg_str[range]),
-                            None => Value::undefined(),
-                        })
-                        .collect();
-
-                    let match_val = Value::from(match_vec);
-
-                    match_val
-                        .set_property("index", DataDescriptor::new(mat.start(), Attribute::all()));
-                    match_val.set_property(
-                        "input",
-                        DataDescriptor::new(arg_str.clone(), Attribute::all()),
-                    );
-                    matches.push(match_val);
-
-                    if !regex.flags.contains('g') {
-                        break;
-                    }
-                }
-
-                matches
+                regex.global
             } else {
                 return context.throw_type_error(
                     "RegExp.prototype.match_all method called on incompatible value",
@@ -706,12 +748,106 @@ impl RegExp {
                 "RegExp.prototype.match_all method called on incompatible value",
             );
         };
+        let matcher = Self::constructor(&Value::undefined(), &[this.clone()], context)?;
+
+        // 10. Let lastIndex be ? ToLength(? Get(R, "lastIndex")).
+        // 11. Perform ? Set(matcher, "lastIndex", lastIndex, true).
+        let last_index = this.get_field("lastIndex", context)?.to_length(context)?;
+        matcher.set_field("lastIndex", Value::from(last_index), context)?;
+
+        // 16. Return ! CreateRegExpStringIterator(matcher, S, global, fullUnicode).
+        RegExpStringIterator::create_regexp_string_iterator(context, matcher, arg_str, global)
+    }
+}
+
+/// Translates the named capture groups of a pattern to numbered ones, which is all the matcher
+/// supports.
+///
+/// `(?<name>...)` becomes `(...)`, and the backreference `\k<name>` becomes `(?:\n)`, where `n`
+/// is the index of the group. Returns the translated pattern, along with the name and index of
+/// every named group.
+fn translate_named_groups(
+    pattern: &str,
+) -> std::result::Result<(String, Vec<(Box<str>, usize)>), String> {
+    let mut translated = String::with_capacity(pattern.len());
+    let mut named_groups: Vec<(Box<str>, usize)> = Vec::new();
+    // The named backreferences, with their position in the translated pattern. A backreference
+    // can come before the group it refers to, so they are only resolved at the end.
+    let mut backreferences = Vec::new();
+    let mut group_count = 0;
+    let mut in_class = false;
+
+    let mut rest = pattern;
+    while let Some(ch) = rest.chars().next() {
+        if ch == '\\' {
+            if !in_class && rest.starts_with("\\k<") {
+                let end = rest
+                    .find('>')
+                    .ok_or_else(|| "invalid named reference".to_string())?;
+                backreferences.push((translated.len(), &rest[3..end]));
+                rest = &rest[end + 1..];
+            } else {
+                // Copy escapes as is, so that escaped brackets and parentheses are not mistaken
+                // for classes and groups.
+                let len = 1 + rest[1..].chars().next().map_or(0, char::len_utf8);
+                translated.push_str(&rest[..len]);
+                rest = &rest[len..];
+            }
+            continue;
+        }
 
-        let length = matches.len();
-        let result = Value::from(matches);
-        result.set_field("length", Value::from(length), context)?;
-        result.set_data(ObjectData::Array);
+        match ch {
+            '[' if !in_class => in_class = true,
+            ']' if in_class => in_class = false,
+            '(' if !in_class
+                && rest.starts_with("(?<")
+                && !rest.starts_with("(?<=")
+                && !rest.starts_with("(?<!") =>
+            {
+                let end = rest
+                    .find('>')
+                    .ok_or_else(|| "invalid capture group name".to_string())?;
+                let name = &rest[3..end];
+                let is_valid_name = name
+                    .chars()
+                    .next()
+                    .map_or(false, |first| !first.is_ascii_digit())
+                    && name
+                        .chars()
+                        .all(|ch| ch.is_alphanumeric() || ch == '_' || ch == '$');
+                if !is_valid_name {
+                    return Err(format!("invalid capture group name '{}'", name));
+                }
+                if named_groups.iter().any(|(other, _)| &**other == name) {
+                    return Err(format!("duplicate capture group name '{}'", name));
+                }
 
-        Ok(result)
+                group_count += 1;
+                named_groups.push((name.into(), group_count));
+                translated.push('(');
+                rest = &rest[end + 1..];
+                continue;
+            }
+            '(' if !in_class && !rest.starts_with("(?") => group_count += 1,
+            _ => {}
+        }
+        translated.push(ch);
+        rest = &rest[ch.len_utf8()..];
+    }
+
+    // Without named groups, `\k` is an identity escape.
+    if named_groups.is_empty() {
+        return Ok((pattern.to_string(), named_groups));
     }
+
+    for (position, name) in backreferences.into_iter().rev() {
+        let index = named_groups
+            .iter()
+            .find(|(group, _)| &**group == name)
+            .map(|(_, index)| *index)
+            .ok_or_else(|| format!("invalid named reference '{}'", name))?;
+        translated.insert_str(position, &format!("(?:\\{})", index));
+    }
+
+    Ok((translated, named_groups))
 }
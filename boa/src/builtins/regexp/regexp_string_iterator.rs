@@ -0,0 +1,146 @@
+use crate::{
+    builtins::{function::make_builtin_fn, iterable::create_iter_result_object, RegExp},
+    gc::{Finalize, Trace},
+    object::{GcObject, ObjectData},
+    property::{Attribute, DataDescriptor},
+    symbol::WellKnownSymbols,
+    value::RcString,
+    BoaProfiler, Context, Result, Value,
+};
+
+/// The internal representation of a `RegExp String Iterator` object.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-regexp-string-iterator-objects
+#[derive(Debug, Clone, Finalize, Trace)]
+pub struct RegExpStringIterator {
+    matcher: Value,
+    string: RcString,
+    global: bool,
+    completed: bool,
+}
+
+impl RegExpStringIterator {
+    fn new(matcher: Value, string: RcString, global: bool) -> Self {
+        Self {
+            matcher,
+            string,
+            global,
+            completed: false,
+        }
+    }
+
+    /// CreateRegExpStringIterator( R, S, global, fullUnicode )
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-createregexpstringiterator
+    pub(crate) fn create_regexp_string_iterator(
+        context: &mut Context,
+        matcher: Value,
+        string: RcString,
+        global: bool,
+    ) -> Result<Value> {
+        let regexp_string_iterator = Value::new_object(context);
+        regexp_string_iterator.set_data(ObjectData::RegExpStringIterator(Self::new(
+            matcher, string, global,
+        )));
+        regexp_string_iterator
+            .as_object()
+            .expect("regexp string iterator object")
+            .set_prototype_instance(
+                context
+                    .iterator_prototypes()
+                    .regexp_string_iterator()
+                    .into(),
+            );
+        Ok(regexp_string_iterator)
+    }
+
+    /// `%RegExpStringIteratorPrototype%.next( )`
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%regexpstringiteratorprototype%.next
+    pub fn next(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let iterator = if let Value::Object(ref object) = this {
+            match object.borrow().as_regexp_string_iterator() {
+                Some(iterator) => iterator.clone(),
+                None => return context.throw_type_error("`this` is not a RegExpStringIterator"),
+            }
+        } else {
+            return context.throw_type_error("`this` is not a RegExpStringIterator");
+        };
+
+        if iterator.completed {
+            return Ok(create_iter_result_object(context, Value::undefined(), true));
+        }
+
+        let matched = RegExp::exec(
+            &iterator.matcher,
+            &[Value::from(iterator.string.clone())],
+            context,
+        )?;
+
+        // The iterator is done once there are no more matches, or after the first match of a
+        // non-global regular expression.
+        if matched.is_null() || !iterator.global {
+            if let Some(object) = this.as_object() {
+                if let Some(iterator) = object.borrow_mut().as_regexp_string_iterator_mut() {
+                    iterator.completed = true;
+                }
+            }
+            if matched.is_null() {
+                return Ok(create_iter_result_object(context, Value::undefined(), true));
+            }
+            return Ok(create_iter_result_object(context, matched, false));
+        }
+
+        // An empty match doesn't move `lastIndex`, so it has to be advanced past the current
+        // character to avoid matching at the same position forever.
+        let match_str = matched.get_field(0, context)?.to_string(context)?;
+        if match_str.is_empty() {
+            let last_index = iterator
+                .matcher
+                .get_field("lastIndex", context)?
+                .to_length(context)?;
+            let next_index = last_index
+                + iterator.string[last_index.min(iterator.string.len())..]
+                    .chars()
+                    .next()
+                    .map_or(1, char::len_utf8);
+            iterator
+                .matcher
+                .set_field("lastIndex", Value::from(next_index), context)?;
+        }
+
+        Ok(create_iter_result_object(context, matched, false))
+    }
+
+    /// Create the %RegExpStringIteratorPrototype% object
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-%regexpstringiteratorprototype%-object
+    pub(crate) fn create_prototype(context: &mut Context, iterator_prototype: Value) -> GcObject {
+        let _timer = BoaProfiler::global().start_event("RegExp String Iterator", "init");
+
+        // Create prototype
+        let mut regexp_string_iterator = context.construct_object();
+        make_builtin_fn(Self::next, "next", &regexp_string_iterator, 0, context);
+        regexp_string_iterator.set_prototype_instance(iterator_prototype);
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+        let to_string_tag_property = DataDescriptor::new(
+            "RegExp String Iterator",
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        );
+        regexp_string_iterator.insert(to_string_tag, to_string_tag_property);
+        regexp_string_iterator
+    }
+}
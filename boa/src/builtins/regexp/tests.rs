@@ -15,33 +15,36 @@ fn constructors() {
     assert_eq!(forward(&mut context, "ctor_literal.test('1.0')"), "true");
 }
 
-// TODO: uncomment this test when property getters are supported
-
-//    #[test]
-//    fn flags() {
-//        let mut context = Context::new();
-//        let init = r#"
-//                var re_gi = /test/gi;
-//                var re_sm = /test/sm;
-//                "#;
-//
-//        eprintln!("{}", forward(&mut context, init));
-//        assert_eq!(forward(&mut context, "re_gi.global"), "true");
-//        assert_eq!(forward(&mut context, "re_gi.ignoreCase"), "true");
-//        assert_eq!(forward(&mut context, "re_gi.multiline"), "false");
-//        assert_eq!(forward(&mut context, "re_gi.dotAll"), "false");
-//        assert_eq!(forward(&mut context, "re_gi.unicode"), "false");
-//        assert_eq!(forward(&mut context, "re_gi.sticky"), "false");
-//        assert_eq!(forward(&mut context, "re_gi.flags"), "gi");
-//
-//        assert_eq!(forward(&mut context, "re_sm.global"), "false");
-//        assert_eq!(forward(&mut context, "re_sm.ignoreCase"), "false");
-//        assert_eq!(forward(&mut context, "re_sm.multiline"), "true");
-//        assert_eq!(forward(&mut context, "re_sm.dotAll"), "true");
-//        assert_eq!(forward(&mut context, "re_sm.unicode"), "false");
-//        assert_eq!(forward(&mut context, "re_sm.sticky"), "false");
-//        assert_eq!(forward(&mut context, "re_sm.flags"), "ms");
-//    }
+#[test]
+fn flags() {
+    let mut context = Context::new();
+    let init = r#"
+        var re_gi = /test/gi;
+        var re_sm = /test/sm;
+        var re_uy = /test/yu;
+        "#;
+
+    eprintln!("{}", forward(&mut context, init));
+    assert_eq!(forward(&mut context, "re_gi.global"), "true");
+    assert_eq!(forward(&mut context, "re_gi.ignoreCase"), "true");
+    assert_eq!(forward(&mut context, "re_gi.multiline"), "false");
+    assert_eq!(forward(&mut context, "re_gi.dotAll"), "false");
+    assert_eq!(forward(&mut context, "re_gi.unicode"), "false");
+    assert_eq!(forward(&mut context, "re_gi.sticky"), "false");
+    assert_eq!(forward(&mut context, "re_gi.flags"), "\"gi\"");
+
+    assert_eq!(forward(&mut context, "re_sm.global"), "false");
+    assert_eq!(forward(&mut context, "re_sm.ignoreCase"), "false");
+    assert_eq!(forward(&mut context, "re_sm.multiline"), "true");
+    assert_eq!(forward(&mut context, "re_sm.dotAll"), "true");
+    assert_eq!(forward(&mut context, "re_sm.unicode"), "false");
+    assert_eq!(forward(&mut context, "re_sm.sticky"), "false");
+    assert_eq!(forward(&mut context, "re_sm.flags"), "\"ms\"");
+
+    assert_eq!(forward(&mut context, "re_uy.unicode"), "true");
+    assert_eq!(forward(&mut context, "re_uy.sticky"), "true");
+    assert_eq!(forward(&mut context, "re_uy.flags"), "\"uy\"");
+}
 
 #[test]
 fn last_index() {
@@ -58,6 +61,67 @@ fn last_index() {
     assert_eq!(forward(&mut context, "regex.lastIndex"), "0");
 }
 
+#[test]
+fn last_index_ignored_without_global_or_sticky() {
+    let mut context = Context::new();
+    let init = r#"
+        var regex = /a/;
+        regex.lastIndex = 2;
+        "#;
+
+    eprintln!("{}", forward(&mut context, init));
+    assert_eq!(forward(&mut context, "regex.test('abc')"), "true");
+    assert_eq!(forward(&mut context, "regex.lastIndex"), "2");
+}
+
+#[test]
+fn sticky() {
+    let mut context = Context::new();
+    let init = r#"
+        var regex = /foo/y;
+        "#;
+
+    eprintln!("{}", forward(&mut context, init));
+    assert_eq!(forward(&mut context, "regex.sticky"), "true");
+    assert_eq!(forward(&mut context, "regex.test('foofoo bar')"), "true");
+    assert_eq!(forward(&mut context, "regex.lastIndex"), "3");
+    assert_eq!(forward(&mut context, "regex.exec('foofoo bar').index"), "3");
+    assert_eq!(forward(&mut context, "regex.lastIndex"), "6");
+
+    // The match must start exactly at `lastIndex`.
+    assert_eq!(forward(&mut context, "regex.exec('foofoo bar')"), "null");
+    assert_eq!(forward(&mut context, "regex.lastIndex"), "0");
+    assert_eq!(forward(&mut context, "regex.test(' foo')"), "false");
+    assert_eq!(forward(&mut context, "regex.lastIndex"), "0");
+}
+
+#[test]
+fn dot_all() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "/a.b/.test('a\\nb')"), "false");
+    assert_eq!(forward(&mut context, "/a.b/s.test('a\\nb')"), "true");
+    assert_eq!(forward(&mut context, "/a.b/s.dotAll"), "true");
+    assert_eq!(
+        forward(&mut context, "new RegExp('a.b', 's').test('a\\nb')"),
+        "true"
+    );
+}
+
+#[test]
+fn invalid_flags() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "new RegExp('a', 'gg')"),
+        "Uncaught \"SyntaxError\": \"invalid regular expression flags 'gg'\""
+    );
+    assert_eq!(
+        forward(&mut context, "new RegExp('a', 'x')"),
+        "Uncaught \"SyntaxError\": \"invalid regular expression flags 'x'\""
+    );
+}
+
 #[test]
 fn exec() {
     let mut context = Context::new();
@@ -80,6 +144,42 @@ fn exec() {
     );
 }
 
+#[test]
+fn named_groups() {
+    let mut context = Context::new();
+    let init = r#"
+        var re = /(?<year>\d{4})-(?<month>\d{2})(?:-(?<day>\d{2}))?/;
+        var result = re.exec('Released 2021-06');
+        "#;
+
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "result.groups.year"), "\"2021\"");
+    assert_eq!(forward(&mut context, "result.groups.month"), "\"06\"");
+    assert_eq!(forward(&mut context, "'day' in result.groups"), "true");
+    assert_eq!(forward(&mut context, "result.groups.day"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Object.getPrototypeOf(result.groups)"),
+        "null"
+    );
+    assert_eq!(
+        forward(&mut context, "'1999-12'.match(re).groups.year"),
+        "\"1999\""
+    );
+    assert_eq!(forward(&mut context, "/(a)/.exec('a').groups"), "undefined");
+
+    // Backreferences to named groups.
+    assert_eq!(forward(&mut context, "/(?<x>.)\\k<x>/.test('aa')"), "true");
+    assert_eq!(forward(&mut context, "/(?<x>.)\\k<x>/.test('ab')"), "false");
+    assert_eq!(
+        forward(&mut context, "/(?<x>1)\\k<x>1/.test('111')"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "'2021-06'.replace(re, '$2/$1')"),
+        "\"06/2021\""
+    );
+}
+
 #[test]
 fn to_string() {
     let mut context = Context::new();
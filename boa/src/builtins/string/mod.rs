@@ -18,10 +18,10 @@ use crate::object::PROTOTYPE;
 use crate::property::DataDescriptor;
 use crate::{
     builtins::{string::string_iterator::StringIterator, Array, BuiltIn, RegExp},
-    object::{ConstructorBuilder, Object, ObjectData},
+    object::{ConstructorBuilder, FunctionBuilder, Object, ObjectData},
     property::Attribute,
     symbol::WellKnownSymbols,
-    value::{RcString, Value},
+    value::{IntegerOrInfinity, RcString, Value},
     BoaProfiler, Context, Result,
 };
 use regress::Regex;
@@ -30,6 +30,7 @@ use std::{
     cmp::{max, min},
     string::String as StdString,
 };
+use unicode_normalization::UnicodeNormalization;
 
 pub(crate) fn code_point_at(string: RcString, position: i32) -> Option<(u32, u8, bool)> {
     let size = string.encode_utf16().count() as i32;
@@ -97,6 +98,22 @@ impl BuiltIn for String {
 
         let symbol_iterator = WellKnownSymbols::iterator();
 
+        // `trimLeft` and `trimRight` are the same function objects as `trimStart` and `trimEnd`.
+        let trim_start = FunctionBuilder::new(context, Self::trim_start)
+            .name("trimStart")
+            .length(0)
+            .callable(true)
+            .constructable(false)
+            .build();
+        let trim_end = FunctionBuilder::new(context, Self::trim_end)
+            .name("trimEnd")
+            .length(0)
+            .callable(true)
+            .constructable(false)
+            .build();
+        let function_attribute =
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+
         let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT;
         let string_object = ConstructorBuilder::with_standard_object(
             context,
@@ -107,6 +124,7 @@ impl BuiltIn for String {
         .length(Self::LENGTH)
         .property("length", 0, attribute)
         .method(Self::char_at, "charAt", 1)
+        .method(Self::at, "at", 1)
         .method(Self::char_code_at, "charCodeAt", 1)
         .method(Self::code_point_at, "codePointAt", 1)
         .method(Self::to_string, "toString", 0)
@@ -122,17 +140,23 @@ impl BuiltIn for String {
         .method(Self::pad_end, "padEnd", 1)
         .method(Self::pad_start, "padStart", 1)
         .method(Self::trim, "trim", 0)
-        .method(Self::trim_start, "trimStart", 0)
-        .method(Self::trim_end, "trimEnd", 0)
+        .property("trimStart", trim_start.clone(), function_attribute)
+        .property("trimLeft", trim_start, function_attribute)
+        .property("trimEnd", trim_end.clone(), function_attribute)
+        .property("trimRight", trim_end, function_attribute)
         .method(Self::to_lowercase, "toLowerCase", 0)
         .method(Self::to_uppercase, "toUpperCase", 0)
+        .method(Self::normalize, "normalize", 0)
         .method(Self::substring, "substring", 2)
         .method(Self::substr, "substr", 2)
         .method(Self::split, "split", 2)
         .method(Self::value_of, "valueOf", 0)
         .method(Self::match_all, "matchAll", 1)
         .method(Self::replace, "replace", 2)
+        .method(Self::replace_all, "replaceAll", 2)
         .method(Self::iterator, (symbol_iterator, "[Symbol.iterator]"), 0)
+        .static_method(Self::raw, "raw", 1)
+        .static_method(Self::from_code_point, "fromCodePoint", 1)
         .build();
 
         (Self::NAME, string_object.into(), Self::attribute())
@@ -200,6 +224,96 @@ impl String {
         Ok(this)
     }
 
+    /// `String.raw( template, ...substitutions )`
+    ///
+    /// The `String.raw()` static method is a tag function of template literals. It returns the
+    /// raw strings of the template interleaved with the substitutions, without processing escapes.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.raw
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/raw
+    pub(crate) fn raw(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let substitutions be a List consisting of all the arguments passed to this function,
+        //    starting with the second argument.
+        let substitutions = args.get(1..).unwrap_or_default();
+
+        // 3. Let cooked be ? ToObject(template).
+        let cooked = Value::from(
+            args.get(0)
+                .cloned()
+                .unwrap_or_default()
+                .to_object(context)?,
+        );
+
+        // 4. Let raw be ? ToObject(? Get(cooked, "raw")).
+        let raw = Value::from(cooked.get_field("raw", context)?.to_object(context)?);
+
+        // 5. Let literalSegments be ? LengthOfArrayLike(raw).
+        let literal_segments = raw.get_field("length", context)?.to_length(context)?;
+
+        // 6. If literalSegments ≤ 0, return the empty String.
+        let mut result = StdString::new();
+
+        // 8-9. Append each raw string, followed by the next substitution if there is one.
+        for index in 0..literal_segments {
+            let segment = raw.get_field(index, context)?.to_string(context)?;
+            result.push_str(&segment);
+
+            if index + 1 == literal_segments {
+                break;
+            }
+
+            if let Some(substitution) = substitutions.get(index) {
+                result.push_str(&substitution.to_string(context)?);
+            }
+        }
+
+        Ok(result.into())
+    }
+
+    /// `String.fromCodePoint( ...codePoints )`
+    ///
+    /// The `String.fromCodePoint()` static method returns a string created from the given sequence
+    /// of code points, encoding the ones outside the Basic Multilingual Plane as surrogate pairs.
+    ///
+    /// Throws a `RangeError` if a code point is not an integer between `0` and `0x10FFFF`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.fromcodepoint
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/fromCodePoint
+    pub(crate) fn from_code_point(
+        _: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        let mut code_units = Vec::with_capacity(args.len());
+        let mut buf = [0u16; 2];
+
+        for next in args {
+            let next_cp = next.to_number(context)?;
+
+            // If ! IsIntegralNumber(nextCP) is false, or nextCP < 0 or nextCP > 0x10FFFF,
+            // throw a RangeError exception.
+            if next_cp.fract() != 0.0 || !(0.0..=f64::from(0x10FFFF)).contains(&next_cp) {
+                return context.throw_range_error(format!("invalid code point: {}", next_cp));
+            }
+
+            // Surrogate code points have no `char`, so they are pushed as lone code units.
+            match from_u32(next_cp as u32) {
+                Some(c) => code_units.extend_from_slice(c.encode_utf16(&mut buf)),
+                None => code_units.push(next_cp as u16),
+            }
+        }
+
+        Ok(StdString::from_utf16_lossy(&code_units).into())
+    }
+
     fn this_string_value(this: &Value, context: &mut Context) -> Result<RcString> {
         match this {
             Value::String(ref string) => return Ok(string.clone()),
@@ -265,6 +379,54 @@ impl String {
         }
     }
 
+    /// `String.prototype.at( index )`
+    ///
+    /// The `at()` method returns the UTF-16 code unit at the given index as a new string,
+    /// counting back from the end of the string if the index is negative.
+    ///
+    /// Returns `undefined` if the index is out of range.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/proposal-relative-indexing-method/#sec-string.prototype.at
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/at
+    pub(crate) fn at(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        // 2. Let S be ? ToString(O).
+        let this = this.require_object_coercible(context)?;
+        let string = this.to_string(context)?;
+
+        // 3. Let len be the length of S.
+        let len = string.encode_utf16().count() as i64;
+
+        // 4. Let relativeIndex be ? ToIntegerOrInfinity(index).
+        // 5-6. If relativeIndex ≥ 0, let k be relativeIndex, else let k be len + relativeIndex.
+        let k = match args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_integer_or_infinity(context)?
+        {
+            IntegerOrInfinity::Integer(i) if i >= 0 => i,
+            IntegerOrInfinity::Integer(i) => len + i,
+            _ => return Ok(Value::undefined()),
+        };
+
+        // 7. If k < 0 or k ≥ len, return undefined.
+        if k < 0 || k >= len {
+            return Ok(Value::undefined());
+        }
+
+        // 8. Return the String value consisting of only the code unit at position k in S.
+        let code_unit = string
+            .encode_utf16()
+            .nth(k as usize)
+            .expect("index was checked to be in range");
+        Ok(Value::from(StdString::from_utf16_lossy(&[code_unit])))
+    }
+
     /// `String.prototype.codePointAt( index )`
     ///
     /// The `codePointAt()` method returns an integer between `0` to `1114111` (`0x10FFFF`) representing the UTF-16 code unit at the given index.
@@ -615,7 +777,7 @@ impl String {
 
                 if let Some(regexp) = obj.as_regexp() {
                     // first argument is another `RegExp` object, so copy its pattern and flags
-                    return regexp.original_source.clone().into();
+                    return regexp.pattern.clone().into();
                 }
                 "undefined".to_string()
             }
@@ -658,105 +820,23 @@ impl String {
             Some(mat) => mat,
             None => return Ok(Value::from(primitive_val)),
         };
-        let caps = re
-            .find(&primitive_val)
-            .expect("unable to get capture groups from text")
-            .captures;
-
         let replace_value = if args.len() > 1 {
             // replace_object could be a string or function or not exist at all
             let replace_object: &Value = args.get(1).expect("second argument expected");
             match replace_object {
                 Value::String(val) => {
-                    // https://tc39.es/ecma262/#table-45
-                    let mut result = StdString::new();
-                    let mut chars = val.chars().peekable();
-
-                    let m = caps.len();
-
-                    while let Some(first) = chars.next() {
-                        if first == '$' {
-                            let second = chars.next();
-                            let second_is_digit = second.map_or(false, |ch| ch.is_digit(10));
-                            // we use peek so that it is still in the iterator if not used
-                            let third = if second_is_digit { chars.peek() } else { None };
-                            let third_is_digit = third.map_or(false, |ch| ch.is_digit(10));
-
-                            match (second, third) {
-                                (Some('$'), _) => {
-                                    // $$
-                                    result.push('$');
-                                }
-                                (Some('&'), _) => {
-                                    // $&
-                                    result.push_str(&primitive_val[mat.range()]);
-                                }
-                                (Some('`'), _) => {
-                                    // $`
-                                    let start_of_match = mat.start();
-                                    result.push_str(&primitive_val[..start_of_match]);
-                                }
-                                (Some('\''), _) => {
-                                    // $'
-                                    let end_of_match = mat.end();
-                                    result.push_str(&primitive_val[end_of_match..]);
-                                }
-                                (Some(second), Some(third))
-                                    if second_is_digit && third_is_digit =>
-                                {
-                                    // $nn
-                                    let tens = second.to_digit(10).unwrap() as usize;
-                                    let units = third.to_digit(10).unwrap() as usize;
-                                    let nn = 10 * tens + units;
-                                    if nn == 0 || nn > m {
-                                        result.push(first);
-                                        result.push(second);
-                                        if let Some(ch) = chars.next() {
-                                            result.push(ch);
-                                        }
-                                    } else {
-                                        let group = match mat.group(nn) {
-                                            Some(range) => &primitive_val[range.clone()],
-                                            _ => "",
-                                        };
-                                        result.push_str(group);
-                                        chars.next(); // consume third
-                                    }
-                                }
-                                (Some(second), _) if second_is_digit => {
-                                    // $n
-                                    let n = second.to_digit(10).unwrap() as usize;
-                                    if n == 0 || n > m {
-                                        result.push(first);
-                                        result.push(second);
-                                    } else {
-                                        let group = match mat.group(n) {
-                                            Some(range) => &primitive_val[range.clone()],
-                                            _ => "",
-                                        };
-                                        result.push_str(group);
-                                    }
-                                }
-                                (Some('<'), _) => {
-                                    // $<
-                                    // TODO: named capture groups
-                                    result.push_str("$<");
-                                }
-                                _ => {
-                                    // $?, ? is none of the above
-                                    // we can consume second because it isn't $
-                                    result.push(first);
-                                    if let Some(second) = second {
-                                        result.push(second);
-                                    }
-                                }
-                            }
-                        } else {
-                            result.push(first);
-                        }
-                    }
-
-                    result
+                    let captures: Vec<Option<&str>> = mat
+                        .groups()
+                        .skip(1)
+                        .map(|group| group.map(|range| &primitive_val[range]))
+                        .collect();
+                    Self::get_substitution(
+                        &primitive_val[mat.range()],
+                        &primitive_val,
+                        mat.start(),
+                        &captures,
+                        val,
+                    )
                 }
                 Value::Object(_) => {
                     // This will return the matched substring first, then captured parenthesized groups later
@@ -791,6 +871,221 @@ impl String {
         )))
     }
 
+    /// `String.prototype.replaceAll( pattern, replacement )`
+    ///
+    /// The `replaceAll()` method returns a new string with all matches of a `pattern` replaced by a `replacement`.
+    ///
+    /// The `pattern` can be a string or a global `RegExp`, and the `replacement` can be a string or a function to be called for each match.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.replaceall
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/replaceAll
+    pub(crate) fn replace_all(
+        this: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let this = this.require_object_coercible(context)?;
+        let search_value = args.get(0).cloned().unwrap_or_default();
+        let replace_value = args.get(1).cloned().unwrap_or_default();
+
+        // 3. Let string be ? ToString(O).
+        let string = this.to_string(context)?;
+
+        // 5. If functionalReplace is false, then set replaceValue to ? ToString(replaceValue).
+        let functional_replace = replace_value.is_function();
+        let replace_str = if functional_replace {
+            RcString::default()
+        } else {
+            replace_value.to_string(context)?
+        };
+
+        // A list of matches, each given as the matched substring, its position and its captures.
+        let matches: Vec<(StdString, usize, Vec<Option<StdString>>)> =
+            if Self::is_regexp_object(&search_value) {
+                // 2.b.i. Let flags be ? Get(searchValue, "flags").
+                // 2.b.iii. If ? ToString(flags) does not contain "g", throw a TypeError exception.
+                let flags = search_value
+                    .get_field("flags", context)?
+                    .to_string(context)?;
+                if !flags.contains('g') {
+                    return context.throw_type_error(
+                        "String.prototype.replaceAll called with a non-global RegExp argument",
+                    );
+                }
+
+                let regex_body = Self::get_regex_string(&search_value);
+                let re = Regex::with_flags(&regex_body, flags.as_str()).map_err(|error| {
+                    context
+                        .construct_syntax_error(format!("failed to create matcher: {}", error.text))
+                })?;
+                re.find_iter(&string)
+                    .map(|mat| {
+                        let captures = mat
+                            .groups()
+                            .skip(1)
+                            .map(|group| group.map(|range| string[range].to_string()))
+                            .collect();
+                        (string[mat.range()].to_string(), mat.start(), captures)
+                    })
+                    .collect()
+            } else {
+                // 6. Let searchLength be the length of searchString.
+                // 7. Let advanceBy be max(1, searchLength).
+                let search_string = search_value.to_string(context)?;
+                let mut matches = Vec::new();
+                let mut position = string.find(search_string.as_str());
+                while let Some(p) = position {
+                    matches.push((search_string.to_string(), p, Vec::new()));
+                    let advance_by = if search_string.is_empty() {
+                        string[p..].chars().next().map_or(1, char::len_utf8)
+                    } else {
+                        search_string.len()
+                    };
+                    position = string
+                        .get(p + advance_by..)
+                        .and_then(|rest| rest.find(search_string.as_str()))
+                        .map(|next| p + advance_by + next);
+                }
+                matches
+            };
+
+        // 13-15. Build the result from the preserved parts and the replacements.
+        let mut end_of_last_match = 0;
+        let mut result = StdString::new();
+        for (matched, position, captures) in matches {
+            result.push_str(&string[end_of_last_match..position]);
+
+            let replacement = if functional_replace {
+                let mut replacer_args = Vec::with_capacity(captures.len() + 3);
+                replacer_args.push(Value::from(matched.as_str()));
+                replacer_args.extend(captures.iter().map(|capture| match capture {
+                    Some(capture) => Value::from(capture.as_str()),
+                    None => Value::undefined(),
+                }));
+                // The position is a byte offset, but JavaScript indexes strings by UTF-16 code
+                // units.
+                replacer_args.push(Value::from(string[..position].encode_utf16().count()));
+                replacer_args.push(Value::from(string.clone()));
+
+                context
+                    .call(&replace_value, &Value::undefined(), &replacer_args)?
+                    .to_string(context)?
+                    .to_string()
+            } else {
+                let captures: Vec<Option<&str>> = captures.iter().map(Option::as_deref).collect();
+                Self::get_substitution(&matched, &string, position, &captures, &replace_str)
+            };
+            result.push_str(&replacement);
+
+            end_of_last_match = position + matched.len();
+        }
+        result.push_str(&string[end_of_last_match..]);
+
+        Ok(Value::from(result))
+    }
+
+    /// GetSubstitution( matched, str, position, captures, namedCaptures, replacement )
+    ///
+    /// Expands the `$` patterns of a replacement string for a single match.
+    ///
+    /// `position` is the byte offset of the match in `string`, which is only used to slice it.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-getsubstitution
+    fn get_substitution(
+        matched: &str,
+        string: &str,
+        position: usize,
+        captures: &[Option<&str>],
+        replacement: &str,
+    ) -> StdString {
+        // https://tc39.es/ecma262/#table-45
+        let mut result = StdString::new();
+        let mut chars = replacement.chars().peekable();
+
+        let m = captures.len();
+
+        while let Some(first) = chars.next() {
+            if first == '$' {
+                let second = chars.next();
+                let second_is_digit = second.map_or(false, |ch| ch.is_digit(10));
+                // we use peek so that it is still in the iterator if not used
+                let third = if second_is_digit { chars.peek() } else { None };
+                let third_is_digit = third.map_or(false, |ch| ch.is_digit(10));
+
+                match (second, third) {
+                    (Some('$'), _) => {
+                        // $$
+                        result.push('$');
+                    }
+                    (Some('&'), _) => {
+                        // $&
+                        result.push_str(matched);
+                    }
+                    (Some('`'), _) => {
+                        // $`
+                        result.push_str(&string[..position]);
+                    }
+                    (Some('\''), _) => {
+                        // $'
+                        let end_of_match = min(position + matched.len(), string.len());
+                        result.push_str(&string[end_of_match..]);
+                    }
+                    (Some(second), Some(third)) if second_is_digit && third_is_digit => {
+                        // $nn
+                        let tens = second.to_digit(10).unwrap() as usize;
+                        let units = third.to_digit(10).unwrap() as usize;
+                        let nn = 10 * tens + units;
+                        if nn == 0 || nn > m {
+                            result.push(first);
+                            result.push(second);
+                            if let Some(ch) = chars.next() {
+                                result.push(ch);
+                            }
+                        } else {
+                            result.push_str(captures[nn - 1].unwrap_or(""));
+                            chars.next(); // consume third
+                        }
+                    }
+                    (Some(second), _) if second_is_digit => {
+                        // $n
+                        let n = second.to_digit(10).unwrap() as usize;
+                        if n == 0 || n > m {
+                            result.push(first);
+                            result.push(second);
+                        } else {
+                            result.push_str(captures[n - 1].unwrap_or(""));
+                        }
+                    }
+                    (Some('<'), _) => {
+                        // $<
+                        // TODO: named capture groups
+                        result.push_str("$<");
+                    }
+                    _ => {
+                        // $?, ? is none of the above
+                        // we can consume second because it isn't $
+                        result.push(first);
+                        if let Some(second) = second {
+                            result.push(second);
+                        }
+                    }
+                }
+            } else {
+                result.push(first);
+            }
+        }
+
+        result
+    }
+
     /// `String.prototype.indexOf( searchValue[, fromIndex] )`
     ///
     /// The `indexOf()` method returns the index within the calling `String` object of the first occurrence
@@ -906,32 +1201,43 @@ impl String {
     /// Performs the actual string padding for padStart/End.
     /// <https://tc39.es/ecma262/#sec-stringpad/>
     fn string_pad(
-        primitive: RcString,
-        max_length: i32,
-        fill_string: Option<RcString>,
+        this: &Value,
+        args: &[Value],
         at_start: bool,
-    ) -> Value {
-        let primitive_length = primitive.len() as i32;
+        context: &mut Context,
+    ) -> Result<Value> {
+        let this = this.require_object_coercible(context)?;
+        let primitive = this.to_string(context)?;
+
+        let max_length = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_length(context)?;
+        let primitive_length = primitive.encode_utf16().count();
 
         if max_length <= primitive_length {
-            return Value::from(primitive);
+            return Ok(Value::from(primitive));
         }
 
-        let filter = fill_string.as_deref().unwrap_or(" ");
-
-        let fill_len = max_length.wrapping_sub(primitive_length);
-        let mut fill_str = StdString::new();
-
-        while fill_str.len() < fill_len as usize {
-            fill_str.push_str(filter);
+        let fill_string = match args.get(1) {
+            Some(arg) if !arg.is_undefined() => arg.to_string(context)?,
+            _ => RcString::from(" "),
+        };
+        if fill_string.is_empty() {
+            return Ok(Value::from(primitive));
         }
-        // Cut to size max_length
-        let concat_fill_str: StdString = fill_str.chars().take(fill_len as usize).collect();
+
+        // The filler is repeated and then truncated so the result is exactly `max_length` code
+        // units long.
+        let fill_len = max_length - primitive_length;
+        let filler: Vec<u16> = fill_string.encode_utf16().cycle().take(fill_len).collect();
+        let filler = StdString::from_utf16_lossy(&filler);
 
         if at_start {
-            Value::from(format!("{}{}", concat_fill_str, &primitive))
+            Ok(Value::from(format!("{}{}", filler, primitive)))
         } else {
-            Value::from(format!("{}{}", primitive, &concat_fill_str))
+            Ok(Value::from(format!("{}{}", primitive, filler)))
         }
     }
 
@@ -948,18 +1254,7 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.padend
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/padEnd
     pub(crate) fn pad_end(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let primitive = this.to_string(context)?;
-        if args.is_empty() {
-            return Err(Value::from("padEnd requires maxLength argument"));
-        }
-        let max_length = args
-            .get(0)
-            .expect("failed to get argument for String method")
-            .to_integer(context)? as i32;
-
-        let fill_string = args.get(1).map(|arg| arg.to_string(context)).transpose()?;
-
-        Ok(Self::string_pad(primitive, max_length, fill_string, false))
+        Self::string_pad(this, args, false, context)
     }
 
     /// `String.prototype.padStart( targetLength [, padString] )`
@@ -975,18 +1270,7 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.padstart
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/padStart
     pub(crate) fn pad_start(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let primitive = this.to_string(context)?;
-        if args.is_empty() {
-            return Err(Value::from("padStart requires maxLength argument"));
-        }
-        let max_length = args
-            .get(0)
-            .expect("failed to get argument for String method")
-            .to_integer(context)? as i32;
-
-        let fill_string = args.get(1).map(|arg| arg.to_string(context)).transpose()?;
-
-        Ok(Self::string_pad(primitive, max_length, fill_string, true))
+        Self::string_pad(this, args, true, context)
     }
 
     /// String.prototype.trim()
@@ -1020,6 +1304,7 @@ impl String {
     /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.trimstart
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/trimStart
     pub(crate) fn trim_start(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
+        let this = this.require_object_coercible(context)?;
         let string = this.to_string(context)?;
         Ok(Value::from(
             string.trim_start_matches(is_trimmable_whitespace),
@@ -1088,6 +1373,43 @@ impl String {
         Ok(Value::from(this_str.to_uppercase()))
     }
 
+    /// `String.prototype.normalize( [ form ] )`
+    ///
+    /// The `normalize()` method returns the Unicode Normalization Form of the string, which is
+    /// one of `"NFC"` (the default), `"NFD"`, `"NFKC"` or `"NFKD"`.
+    ///
+    /// Throws a `RangeError` if `form` is not one of these values.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-string.prototype.normalize
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/normalize
+    pub(crate) fn normalize(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let this_str = this.to_string(context)?;
+
+        let form = match args.get(0) {
+            None | Some(Value::Undefined) => RcString::from("NFC"),
+            Some(form) => form.to_string(context)?,
+        };
+
+        let normalized: StdString = match form.as_str() {
+            "NFC" => this_str.nfc().collect(),
+            "NFD" => this_str.nfd().collect(),
+            "NFKC" => this_str.nfkc().collect(),
+            "NFKD" => this_str.nfkd().collect(),
+            _ => {
+                return context.throw_range_error(format!(
+                    "The normalization form should be one of NFC, NFD, NFKC, NFKD, got {}",
+                    form
+                ))
+            }
+        };
+
+        Ok(Value::from(normalized))
+    }
+
     /// `String.prototype.substring( indexStart[, indexEnd] )`
     ///
     /// The `substring()` method returns the part of the `string` between the start and end indexes, or to the end of the string.
@@ -1294,34 +1616,36 @@ impl String {
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/String/matchAll
     /// [regex]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions
     /// [cg]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Regular_Expressions/Groups_and_Ranges
-    // TODO: update this method to return iterator
     pub(crate) fn match_all(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
-        let re: Value = match args.get(0) {
-            Some(arg) => {
-                if arg.is_null() {
-                    RegExp::constructor(
-                        &Value::from(Object::default()),
-                        &[Value::from(arg.to_string(context)?), Value::from("g")],
-                        context,
-                    )
-                } else if arg.is_undefined() {
-                    RegExp::constructor(
-                        &Value::from(Object::default()),
-                        &[Value::undefined(), Value::from("g")],
-                        context,
-                    )
-                } else {
-                    Ok(arg.clone())
-                }
+        // 1. Let O be ? RequireObjectCoercible(this value).
+        let primitive_val = this.to_string(context)?;
+
+        // 2. If regexp is neither undefined nor null, then
+        //     a. Let isRegExp be ? IsRegExp(regexp).
+        //     b. If isRegExp is true, then
+        //         i. Let flags be ? Get(regexp, "flags").
+        //         iii. If ? ToString(flags) does not contain "g", throw a TypeError exception.
+        let regexp = args.get(0).cloned().unwrap_or_default();
+        if Self::is_regexp_object(&regexp) {
+            let flags = regexp.get_field("flags", context)?.to_string(context)?;
+            if !flags.contains('g') {
+                return context.throw_type_error(
+                    "String.prototype.matchAll called with a non-global RegExp argument",
+                );
             }
-            None => RegExp::constructor(
-                &Value::from(Object::default()),
-                &[Value::from(""), Value::from("g")],
-                context,
-            ),
-        }?;
-
-        RegExp::match_all(&re, this.to_string(context)?.to_string(), context)
+            return RegExp::match_all(&regexp, primitive_val, context);
+        }
+
+        // 3. Let rx be ? RegExpCreate(regexp, "g").
+        let pattern = if regexp.is_undefined() {
+            Value::from("")
+        } else {
+            Value::from(regexp.to_string(context)?)
+        };
+        let rx = RegExp::constructor(&Value::undefined(), &[pattern, Value::from("g")], context)?;
+
+        // 4. Return ? Invoke(rx, @@matchAll, « S »).
+        RegExp::match_all(&rx, primitive_val, context)
     }
 
     pub(crate) fn iterator(this: &Value, _: &[Value], context: &mut Context) -> Result<Value> {
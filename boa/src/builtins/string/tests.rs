@@ -206,6 +206,53 @@ fn repeat_generic() {
     assert_eq!(forward(&mut context, "(12).repeat(3)"), "\"121212\"");
 }
 
+#[test]
+fn at() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "'abc'.at(0)"), "\"a\"");
+    assert_eq!(forward(&mut context, "'abc'.at(-1)"), "\"c\"");
+    assert_eq!(forward(&mut context, "'abc'.at(-3)"), "\"a\"");
+    assert_eq!(forward(&mut context, "'abc'.at(3)"), "undefined");
+    assert_eq!(forward(&mut context, "'abc'.at(-4)"), "undefined");
+    assert_eq!(forward(&mut context, "''.at(0)"), "undefined");
+}
+
+#[test]
+fn pad_start() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "'abc'.padStart(6)"), "\"   abc\"");
+    assert_eq!(
+        forward(&mut context, "'abc'.padStart(6, '12')"),
+        "\"121abc\""
+    );
+    assert_eq!(
+        forward(&mut context, "'abc'.padStart(10, 'foo')"),
+        "\"foofoofabc\""
+    );
+    assert_eq!(forward(&mut context, "'abc'.padStart(3, 'x')"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'abc'.padStart(1, 'x')"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'abc'.padStart(6, '')"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'abc'.padStart()"), "\"abc\"");
+}
+
+#[test]
+fn pad_end() {
+    let mut context = Context::new();
+
+    assert_eq!(forward(&mut context, "'abc'.padEnd(6)"), "\"abc   \"");
+    assert_eq!(forward(&mut context, "'abc'.padEnd(6, '12')"), "\"abc121\"");
+    assert_eq!(
+        forward(&mut context, "'abc'.padEnd(10, 'foo')"),
+        "\"abcfoofoof\""
+    );
+    assert_eq!(forward(&mut context, "'abc'.padEnd(3, 'x')"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'abc'.padEnd(-1, 'x')"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'abc'.padEnd(6, '')"), "\"abc\"");
+    assert_eq!(forward(&mut context, "'é'.padEnd(3, 'é')"), "\"ééé\"");
+}
+
 #[test]
 fn replace() {
     let mut context = Context::new();
@@ -312,6 +359,103 @@ fn replace_with_function() {
     assert_eq!(forward(&mut context, "length"), "14");
 }
 
+#[test]
+fn replace_all_string() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "'a-b-c'.replaceAll('-', '+')"),
+        "\"a+b+c\""
+    );
+    assert_eq!(
+        forward(&mut context, "'aaa'.replaceAll('aa', 'b')"),
+        "\"ba\""
+    );
+    assert_eq!(
+        forward(&mut context, "'abc'.replaceAll('', '_')"),
+        "\"_a_b_c_\""
+    );
+    assert_eq!(
+        forward(&mut context, "'abc'.replaceAll('x', '_')"),
+        "\"abc\""
+    );
+    assert_eq!(
+        forward(&mut context, "'a.b'.replaceAll('.', '[$&]')"),
+        "\"a[.]b\""
+    );
+}
+
+#[test]
+fn replace_all_regexp() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "'1a2b3'.replaceAll(/\\d/g, '#')"),
+        "\"#a#b#\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "'John Smith, Jane Doe'.replaceAll(/(\\w+)\\s(\\w+)/g, '$2 $1')"
+        ),
+        "\"Smith John, Doe Jane\""
+    );
+    assert_eq!(
+        forward(&mut context, "'aaa'.replaceAll(/a/g, '$$')"),
+        "\"$$$\""
+    );
+    assert_eq!(
+        forward(&mut context, "'aa'.replaceAll(/a/, 'b')"),
+        "Uncaught \"TypeError\": \"String.prototype.replaceAll called with a non-global RegExp argument\""
+    );
+}
+
+#[test]
+fn replace_all_with_function() {
+    let mut context = Context::new();
+    let init = r#"
+        var positions = [];
+        var replacer = (match, position, string) => {
+            positions.push(position);
+            return match.toUpperCase();
+        };
+        var a = "a cat and a hat".replaceAll("at", replacer);
+        var b = "x1y22".replaceAll(/(\d)(\d?)/g, (match, p1, p2) => `<${p1}|${p2}>`);
+        "#;
+
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "a"), "\"a cAT and a hAT\"");
+    assert_eq!(forward(&mut context, "positions.length"), "2");
+    assert_eq!(forward(&mut context, "positions[0]"), "3");
+    assert_eq!(forward(&mut context, "positions[1]"), "13");
+    assert_eq!(forward(&mut context, "b"), "\"x<1|>y<2|2>\"");
+}
+
+#[test]
+fn replace_all_positions_are_utf16_indices() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(
+            &mut context,
+            "'é-😀-'.replaceAll('-', (match, position) => position)"
+        ),
+        "\"é1😀4\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "'é-😀-'.replaceAll(/-/g, (match, position) => position)"
+        ),
+        "\"é1😀4\""
+    );
+    assert_eq!(
+        forward(&mut context, "'é-ü'.replaceAll('-', '[$`|$\\']')"),
+        "\"é[é|ü]ü\""
+    );
+}
+
 #[test]
 fn starts_with() {
     let mut context = Context::new();
@@ -448,14 +592,27 @@ fn includes_with_regex_arg() {
 fn match_all() {
     let mut context = Context::new();
 
-    assert_eq!(forward(&mut context, "'aa'.matchAll(null).length"), "0");
-    assert_eq!(forward(&mut context, "'aa'.matchAll(/b/).length"), "0");
-    assert_eq!(forward(&mut context, "'aa'.matchAll(/a/).length"), "1");
-    assert_eq!(forward(&mut context, "'aa'.matchAll(/a/g).length"), "2");
+    assert_eq!(
+        forward(&mut context, "[...'aa'.matchAll(null)].length"),
+        "0"
+    );
+    assert_eq!(
+        forward(&mut context, "[...'aa'.matchAll(/b/g)].length"),
+        "0"
+    );
+    assert_eq!(
+        forward(&mut context, "[...'aa'.matchAll(/a/g)].length"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut context, "[...'a.a'.matchAll('.')].length"),
+        "3"
+    );
+    assert_eq!(forward(&mut context, "[...'abc'.matchAll()].length"), "4");
 
     forward(
         &mut context,
-        "var groupMatches = 'test1test2'.matchAll(/t(e)(st(\\d?))/g)",
+        "var groupMatches = [...'test1test2'.matchAll(/t(e)(st(\\d?))/g)]",
     );
 
     assert_eq!(forward(&mut context, "groupMatches.length"), "2");
@@ -464,26 +621,37 @@ fn match_all() {
     assert_eq!(forward(&mut context, "groupMatches[0][3]"), "\"1\"");
     assert_eq!(forward(&mut context, "groupMatches[1][3]"), "\"2\"");
 
-    assert_eq!(
-        forward(
-            &mut context,
-            "'test1test2'.matchAll(/t(e)(st(\\d?))/).length"
-        ),
-        "1"
-    );
-
     let init = r#"
         var regexp = RegExp('foo[a-z]*','g');
         var str = 'table football, foosball';
         var matches = str.matchAll(regexp);
+        var first = matches.next();
+        var second = matches.next();
+        var third = matches.next();
         "#;
 
     forward(&mut context, init);
 
-    assert_eq!(forward(&mut context, "matches[0][0]"), "\"football\"");
-    assert_eq!(forward(&mut context, "matches[0].index"), "6");
-    assert_eq!(forward(&mut context, "matches[1][0]"), "\"foosball\"");
-    assert_eq!(forward(&mut context, "matches[1].index"), "16");
+    assert_eq!(forward(&mut context, "first.value[0]"), "\"football\"");
+    assert_eq!(forward(&mut context, "first.value.index"), "6");
+    assert_eq!(forward(&mut context, "second.value[0]"), "\"foosball\"");
+    assert_eq!(forward(&mut context, "second.value.index"), "16");
+    assert_eq!(forward(&mut context, "third.done"), "true");
+    assert_eq!(forward(&mut context, "regexp.lastIndex"), "0");
+    assert_eq!(
+        forward(&mut context, "Object.prototype.toString.call(matches)"),
+        "\"[object RegExp String Iterator]\""
+    );
+}
+
+#[test]
+fn match_all_non_global() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "'aa'.matchAll(/a/)"),
+        "Uncaught \"TypeError\": \"String.prototype.matchAll called with a non-global RegExp argument\""
+    );
 }
 
 #[test]
@@ -575,6 +743,54 @@ fn trim_end() {
     );
 }
 
+#[test]
+fn trim_start_end_whitespace() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(&mut context, r#"'\t\u00A0Hello\t\u00A0'.trimStart()"#),
+        "\"Hello\t\u{a0}\""
+    );
+    assert_eq!(
+        forward(&mut context, r#"'\t\u00A0Hello\t\u00A0'.trimEnd()"#),
+        "\"\t\u{a0}Hello\""
+    );
+    assert_eq!(
+        forward(&mut context, r#"'\uFEFF\u00A0Hello\u3000'.trim()"#),
+        "\"Hello\""
+    );
+}
+
+#[test]
+fn trim_left_right_aliases() {
+    let mut context = Context::new();
+    assert_eq!(
+        forward(
+            &mut context,
+            "String.prototype.trimLeft === String.prototype.trimStart"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "String.prototype.trimRight === String.prototype.trimEnd"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "String.prototype.trimLeft.name"),
+        "\"trimStart\""
+    );
+    assert_eq!(
+        forward(&mut context, r#"' Hello '.trimLeft()"#),
+        "\"Hello \""
+    );
+    assert_eq!(
+        forward(&mut context, r#"' Hello '.trimRight()"#),
+        "\" Hello\""
+    );
+}
+
 #[test]
 fn split() {
     let mut context = Context::new();
@@ -1104,3 +1320,128 @@ fn string_get_property() {
     assert_eq!(forward(&mut context, "'abc'['foo']"), "undefined");
     assert_eq!(forward(&mut context, "'😀'[0]"), "\"\\ud83d\"");
 }
+
+#[test]
+fn code_point_at_astral() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "'a😀b'.codePointAt(1)"), "128512");
+    assert_eq!(forward(&mut context, "'a😀b'.codePointAt(2)"), "56832");
+    assert_eq!(forward(&mut context, "'a😀b'.codePointAt(3)"), "98");
+    assert_eq!(forward(&mut context, "'a😀b'.codePointAt(4)"), "undefined");
+}
+
+#[test]
+fn from_code_point() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "String.fromCodePoint()"), "\"\"");
+    assert_eq!(
+        forward(&mut context, "String.fromCodePoint(97, 98, 99)"),
+        "\"abc\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.fromCodePoint(0x1F600) === '😀'"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "String.fromCodePoint(0x1F600).length"),
+        "2"
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "String.fromCodePoint(0x1F600).codePointAt(0) === 0x1F600"
+        ),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "String.fromCodePoint(0x10FFFF).length"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut context, "String.fromCodePoint(0x110000)"),
+        "Uncaught \"RangeError\": \"invalid code point: 1114112\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.fromCodePoint(-1)"),
+        "Uncaught \"RangeError\": \"invalid code point: -1\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.fromCodePoint(1.5)"),
+        "Uncaught \"RangeError\": \"invalid code point: 1.5\""
+    );
+}
+
+#[test]
+fn raw() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "String.raw`\\n${1}` === '\\\\n1'"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "String.raw`a${1}b${2}c${3}`"),
+        "\"a1b2c3\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "String.raw({ raw: ['x', 'y', 'z'] }, 1, 2, 3)"
+        ),
+        "\"x1y2z\""
+    );
+    assert_eq!(
+        forward(&mut context, "String.raw({ raw: 'abc' }, '-', '-')"),
+        "\"a-b-c\""
+    );
+    assert_eq!(forward(&mut context, "String.raw({ raw: [] }, 1)"), "\"\"");
+    assert_eq!(
+        forward(&mut context, "String.raw()"),
+        "Uncaught \"TypeError\": \"cannot convert 'null' or 'undefined' to object\""
+    );
+}
+
+#[test]
+fn normalize() {
+    let mut context = Context::new();
+
+    // "é" as "e" followed by a combining acute accent, and as a single precomposed character.
+    forward(
+        &mut context,
+        "let decomposed = 'e\\u0301'; let composed = '\\u00e9';",
+    );
+
+    assert_eq!(forward(&mut context, "decomposed === composed"), "false");
+    assert_eq!(
+        forward(&mut context, "decomposed.normalize() === composed"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "decomposed.normalize('NFC') === composed"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "composed.normalize('NFD') === decomposed"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "composed.normalize('NFD').length"),
+        "2"
+    );
+    assert_eq!(
+        forward(&mut context, "'\\ufb01'.normalize('NFKC')"),
+        "\"fi\""
+    );
+    assert_eq!(
+        forward(&mut context, "'\\ufb01'.normalize('NFKD')"),
+        "\"fi\""
+    );
+    assert_eq!(
+        forward(&mut context, "'\\ufb01'.normalize('NFC') === '\\ufb01'"),
+        "true"
+    );
+    assert_eq!(
+        forward(&mut context, "'abc'.normalize('nfc')"),
+        "Uncaught \"RangeError\": \"The normalization form should be one of NFC, NFD, NFKC, NFKD, got nfc\""
+    );
+}
@@ -82,6 +82,8 @@ impl BuiltIn for Symbol {
         .static_property("toPrimitive", symbol_to_primitive, attribute)
         .static_property("toStringTag", symbol_to_string_tag.clone(), attribute)
         .static_property("unscopables", symbol_unscopables, attribute)
+        .static_method(Self::for_, "for", 1)
+        .static_method(Self::key_for, "keyFor", 1)
         .method(Self::to_string, "toString", 0)
         .accessor(
             "description",
@@ -148,6 +150,47 @@ impl Symbol {
         Err(context.construct_type_error("'this' is not a Symbol"))
     }
 
+    /// `Symbol.for( key )`
+    ///
+    /// This method returns the symbol of the global symbol registry with the given key, creating
+    /// it if it doesn't exist yet.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.for
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/for
+    pub(crate) fn for_(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let key = args
+            .get(0)
+            .cloned()
+            .unwrap_or_default()
+            .to_string(context)?;
+
+        Ok(RcSymbol::for_key(key).into())
+    }
+
+    /// `Symbol.keyFor( sym )`
+    ///
+    /// This method returns the key of the symbol in the global symbol registry, or `undefined` if
+    /// it is not registered.
+    ///
+    /// More information:
+    /// - [MDN documentation][mdn]
+    /// - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-symbol.keyfor
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Symbol/keyFor
+    pub(crate) fn key_for(_: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        match args.get(0) {
+            Some(Value::Symbol(ref symbol)) => {
+                Ok(symbol.registry_key().map(Value::from).unwrap_or_default())
+            }
+            _ => context.throw_type_error("Symbol.keyFor: argument is not a symbol"),
+        }
+    }
+
     /// `Symbol.prototype.toString()`
     ///
     /// This method returns a string representing the specified `Symbol` object.
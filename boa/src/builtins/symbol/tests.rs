@@ -37,3 +37,64 @@ fn symbol_access() {
     assert_eq!(forward(&mut context, "x[sym2]"), "20");
     assert_eq!(forward(&mut context, "x['Symbol(Hello)']"), "undefined");
 }
+
+#[test]
+fn symbols_are_unique() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "Symbol() === Symbol()"), "false");
+    assert_eq!(
+        forward(&mut context, "Symbol('a') === Symbol('a')"),
+        "false"
+    );
+    assert_eq!(
+        forward(&mut context, "var s = Symbol('a'); s === s"),
+        "true"
+    );
+    assert_eq!(forward(&mut context, "Symbol('a').description"), "\"a\"");
+}
+
+#[test]
+fn symbol_for_interns_keys() {
+    let mut context = Context::new();
+    let init = r#"
+        var a = Symbol.for("app");
+        var b = Symbol.for("app");
+        var local = Symbol("app");
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "a === b"), "true");
+    assert_eq!(forward(&mut context, "a === local"), "false");
+    assert_eq!(forward(&mut context, "a.description"), "\"app\"");
+    assert_eq!(forward(&mut context, "Symbol.keyFor(a)"), "\"app\"");
+    assert_eq!(forward(&mut context, "Symbol.keyFor(local)"), "undefined");
+    assert_eq!(
+        forward(&mut context, "Symbol.keyFor(Symbol.iterator)"),
+        "undefined"
+    );
+    assert_eq!(
+        forward(&mut context, "Symbol.keyFor('app')"),
+        "Uncaught \"TypeError\": \"Symbol.keyFor: argument is not a symbol\""
+    );
+}
+
+#[test]
+fn typeof_symbol() {
+    let mut context = Context::new();
+    assert_eq!(forward(&mut context, "typeof Symbol()"), "\"symbol\"");
+    assert_eq!(
+        forward(&mut context, "typeof Symbol.for('x')"),
+        "\"symbol\""
+    );
+    assert_eq!(
+        forward(&mut context, "typeof Symbol.iterator"),
+        "\"symbol\""
+    );
+    assert_eq!(
+        forward(&mut context, "typeof Symbol.hasInstance"),
+        "\"symbol\""
+    );
+    assert_eq!(
+        forward(&mut context, "typeof Symbol.toPrimitive"),
+        "\"symbol\""
+    );
+}
@@ -0,0 +1,243 @@
+//! This module implements the global `WeakMap` object.
+//!
+//! The JavaScript `WeakMap` class is a global object that is used in the construction of weak
+//! maps; which are key-value stores whose keys are objects, and that do not prevent their keys
+//! from being garbage collected.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakmap-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap
+
+use crate::{
+    builtins::{iterable::get_iterator, BuiltIn},
+    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, Result, Value,
+};
+use weak_table::WeakTable;
+
+pub mod weak_table;
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakMap;
+
+impl BuiltIn for WeakMap {
+    const NAME: &'static str = "WeakMap";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+
+        let weak_map_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().weak_map_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::delete, "delete", 1)
+        .method(Self::get, "get", 1)
+        .method(Self::has, "has", 1)
+        .method(Self::set, "set", 2)
+        .property(
+            to_string_tag,
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        (Self::NAME, weak_map_object.into(), Self::attribute())
+    }
+}
+
+impl WeakMap {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `WeakMap ( [ iterable ] )`
+    ///
+    /// Creates a new weak map, adding the entries of the iterable if one is given.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap-iterable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/WeakMap
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin WeakMap constructor without new is forbidden",
+            );
+        }
+
+        // 2.
+        let weak_map_prototype = context.standard_objects().weak_map_object().prototype();
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or(weak_map_prototype);
+
+        let mut obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+
+        let map = Value::from(obj);
+        map.set_data(ObjectData::WeakMap(WeakTable::new()));
+
+        // 3.
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        if iterable.is_null_or_undefined() {
+            return Ok(map);
+        }
+
+        // 4.
+        let adder = map.get_field("set", context)?;
+
+        // 5.
+        if !adder.is_function() {
+            return context.throw_type_error("'set' of 'newTarget' is not a function");
+        }
+
+        // AddEntriesFromIterable ( target, iterable, adder )
+        let iterator_record = get_iterator(context, iterable)?;
+
+        let mut next = iterator_record.next(context)?;
+        while !next.is_done() {
+            let entry = next.value();
+            if !entry.is_object() {
+                let error = context.construct_type_error("iterator value is not an entry object");
+                return iterator_record.close(Err(error), context);
+            }
+
+            let status = entry.get_field(0, context).and_then(|key| {
+                let value = entry.get_field(1, context)?;
+                context.call(&adder, &map, &[key, value])
+            });
+            if let Err(status) = status {
+                return iterator_record.close(Err(status), context);
+            }
+
+            next = iterator_record.next(context)?;
+        }
+
+        Ok(map)
+    }
+
+    /// Returns the table of entries of the weak map, or throws if `this` is not a weak map.
+    fn this_table(this: &Value, method: &str, context: &mut Context) -> Result<WeakTable<Value>> {
+        let table = match this {
+            Value::Object(ref object) => object.borrow().as_weak_map().cloned(),
+            _ => None,
+        };
+
+        table.ok_or_else(|| {
+            context.construct_type_error(format!(
+                "WeakMap.prototype.{} called on incompatible receiver",
+                method
+            ))
+        })
+    }
+
+    /// `WeakMap.prototype.delete( key )`
+    ///
+    /// This method removes the entry of the key, if it exists. Returns true if there was an entry,
+    /// false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let table = Self::this_table(this, "delete", context)?;
+
+        Ok(match args.get(0) {
+            Some(Value::Object(ref key)) => table.remove(key).into(),
+            _ => false.into(),
+        })
+    }
+
+    /// `WeakMap.prototype.get( key )`
+    ///
+    /// This method returns the value associated with the key, or undefined if there is none.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.get
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/get
+    pub(crate) fn get(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let table = Self::this_table(this, "get", context)?;
+
+        Ok(match args.get(0) {
+            Some(Value::Object(ref key)) => table.get(key).unwrap_or_default(),
+            _ => Value::undefined(),
+        })
+    }
+
+    /// `WeakMap.prototype.has( key )`
+    ///
+    /// This method checks if the weak map contains an entry for the key.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/has
+    pub(crate) fn has(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let table = Self::this_table(this, "has", context)?;
+
+        Ok(match args.get(0) {
+            Some(Value::Object(ref key)) => table.contains_key(key).into(),
+            _ => false.into(),
+        })
+    }
+
+    /// `WeakMap.prototype.set( key, value )`
+    ///
+    /// This method associates the value with the key, which must be an object. Returns the weak
+    /// map.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakmap.prototype.set
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakMap/set
+    pub(crate) fn set(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let table = Self::this_table(this, "set", context)?;
+
+        let key = match args.get(0) {
+            Some(Value::Object(ref key)) => key.clone(),
+            _ => return context.throw_type_error("WeakMap keys must be objects"),
+        };
+        let value = args.get(1).cloned().unwrap_or_default();
+
+        table.insert(&key, value);
+        Ok(this.clone())
+    }
+}
@@ -0,0 +1,123 @@
+use super::WeakTable;
+use crate::{forward, forward_val, gc::force_collect, Context, Value};
+
+#[test]
+fn construct_empty() {
+    let mut context = Context::new();
+    let init = r#"
+        var empty = new WeakMap();
+        "#;
+    forward(&mut context, init);
+    let result = forward(&mut context, "empty.has({})");
+    assert_eq!(result, "false");
+}
+
+#[test]
+fn construct_from_entries() {
+    let mut context = Context::new();
+    let init = r#"
+        var a = {};
+        var b = {};
+        var map = new WeakMap([[a, "a"], [b, "b"]]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "map.get(a)"), "\"a\"");
+    assert_eq!(forward(&mut context, "map.get(b)"), "\"b\"");
+    assert_eq!(
+        forward(&mut context, "new WeakMap([1])"),
+        "Uncaught \"TypeError\": \"iterator value is not an entry object\""
+    );
+}
+
+#[test]
+fn set_get_has_delete() {
+    let mut context = Context::new();
+    let init = r#"
+        var key = {};
+        var other = {};
+        var map = new WeakMap();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "map.set(key, 1) === map"), "true");
+    assert_eq!(forward(&mut context, "map.get(key)"), "1");
+    assert_eq!(forward(&mut context, "map.has(key)"), "true");
+    assert_eq!(forward(&mut context, "map.get(other)"), "undefined");
+    assert_eq!(forward(&mut context, "map.has(other)"), "false");
+
+    forward(&mut context, "map.set(key, 2)");
+    assert_eq!(forward(&mut context, "map.get(key)"), "2");
+
+    assert_eq!(forward(&mut context, "map.delete(key)"), "true");
+    assert_eq!(forward(&mut context, "map.delete(key)"), "false");
+    assert_eq!(forward(&mut context, "map.has(key)"), "false");
+
+    // The weak map itself can be used as a key.
+    assert_eq!(forward(&mut context, "map.set(map, 3).get(map)"), "3");
+}
+
+#[test]
+fn primitive_keys() {
+    let mut context = Context::new();
+    let init = r#"
+        var map = new WeakMap();
+        "#;
+    forward(&mut context, init);
+
+    for key in &["1", "\"key\"", "Symbol()", "null", "undefined", "true"] {
+        assert_eq!(
+            forward(&mut context, format!("map.set({}, 1)", key)),
+            "Uncaught \"TypeError\": \"WeakMap keys must be objects\""
+        );
+        assert_eq!(forward(&mut context, format!("map.has({})", key)), "false");
+        assert_eq!(
+            forward(&mut context, format!("map.get({})", key)),
+            "undefined"
+        );
+        assert_eq!(
+            forward(&mut context, format!("map.delete({})", key)),
+            "false"
+        );
+    }
+}
+
+#[test]
+fn errors() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "WeakMap()"),
+        "Uncaught \"TypeError\": \"calling a builtin WeakMap constructor without new is forbidden\""
+    );
+    assert_eq!(
+        forward(&mut context, "WeakMap.prototype.get.call(new Map(), {})"),
+        "Uncaught \"TypeError\": \"WeakMap.prototype.get called on incompatible receiver\""
+    );
+}
+
+#[test]
+fn entries_do_not_keep_keys_alive() {
+    let mut context = Context::new();
+    let init = r#"
+        var key = {};
+        var map = new WeakMap();
+        map.set(key, 1);
+        map.set({}, 2);
+        "#;
+    forward(&mut context, init);
+    force_collect();
+
+    let map = forward_map(&mut context);
+    assert_eq!(map.len(), 1);
+    assert_eq!(forward(&mut context, "map.get(key)"), "1");
+
+    forward(&mut context, "key = null");
+    force_collect();
+    assert!(map.is_empty());
+}
+
+/// Returns the table of entries of the `map` weak map.
+fn forward_map(context: &mut Context) -> WeakTable<Value> {
+    let map = forward_val(context, "map").unwrap().as_object().unwrap();
+    let table = map.borrow().as_weak_map().cloned().unwrap();
+    table
+}
@@ -0,0 +1,124 @@
+//! This module implements the table holding the entries of `WeakMap` and `WeakSet` objects.
+//!
+//! The garbage collector does not support weak references, so the table does not store the key
+//! objects of its entries. Instead, entries are indexed by the address of their key object, and
+//! each key object keeps a weak reference to the tables it is a key of. When a key object is
+//! finalized, it removes its entries from these tables, before its address can be reused by
+//! another object.
+//!
+//! The values of the entries are traced as long as the table is alive, so a value that references
+//! its own key keeps the key alive.
+
+use crate::{
+    gc::{custom_trace, Finalize, Trace},
+    object::{GcObject, Object},
+};
+use gc::GcCell;
+use rustc_hash::FxHashMap;
+use std::{
+    fmt::{self, Debug},
+    rc::{Rc, Weak},
+};
+
+/// A table of entries indexed by the address of their key object.
+pub(crate) trait WeakKeyTable {
+    /// Removes the entry of the key object at the given address, if there is one.
+    fn remove_key(&self, address: usize);
+}
+
+/// A weak reference to a table that an object is a key of, along with the address of the table.
+pub(crate) type WeakKeyTableRef = (usize, Weak<dyn WeakKeyTable>);
+
+type Entries<V> = GcCell<FxHashMap<usize, V>>;
+
+impl<V: Trace> WeakKeyTable for Entries<V> {
+    fn remove_key(&self, address: usize) {
+        self.borrow_mut().remove(&address);
+    }
+}
+
+/// The entries of a `WeakMap` or a `WeakSet`, which do not keep their key objects alive.
+///
+/// Clones of a table share the same entries.
+#[derive(Clone)]
+pub struct WeakTable<V: Trace + 'static> {
+    entries: Rc<Entries<V>>,
+}
+
+impl<V: Trace + 'static> Finalize for WeakTable<V> {}
+unsafe impl<V: Trace + 'static> Trace for WeakTable<V> {
+    custom_trace!(this, {
+        mark(&*this.entries);
+    });
+}
+
+impl<V: Trace + 'static> Debug for WeakTable<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("WeakTable")
+    }
+}
+
+impl<V: Trace + 'static> Default for WeakTable<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: Trace + 'static> WeakTable<V> {
+    /// Creates an empty table.
+    pub fn new() -> Self {
+        Self {
+            entries: Rc::new(GcCell::new(FxHashMap::default())),
+        }
+    }
+
+    /// Returns the address identifying the given key object.
+    fn address(key: &GcObject) -> usize {
+        &*key.borrow() as *const Object as usize
+    }
+
+    /// Returns the number of entries in the table.
+    pub fn len(&self) -> usize {
+        self.entries.borrow().len()
+    }
+
+    /// Returns `true` if the table contains no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.borrow().is_empty()
+    }
+
+    /// Returns a clone of the value of the entry of the given key, if there is one.
+    pub fn get(&self, key: &GcObject) -> Option<V>
+    where
+        V: Clone,
+    {
+        self.entries.borrow().get(&Self::address(key)).cloned()
+    }
+
+    /// Returns `true` if the table contains an entry for the given key.
+    pub fn contains_key(&self, key: &GcObject) -> bool {
+        self.entries.borrow().contains_key(&Self::address(key))
+    }
+
+    /// Inserts an entry, replacing the value of the entry with the same key if there is one.
+    ///
+    /// The key object must not be borrowed.
+    pub fn insert(&self, key: &GcObject, value: V) {
+        let address = Self::address(key);
+        let previous = self.entries.borrow_mut().insert(address, value);
+
+        if previous.is_none() {
+            let table: Rc<dyn WeakKeyTable> = self.entries.clone();
+            key.borrow_mut()
+                .add_weak_key_table((Rc::as_ptr(&self.entries) as usize, Rc::downgrade(&table)));
+        }
+    }
+
+    /// Removes the entry of the given key, returning `true` if there was one.
+    pub fn remove(&self, key: &GcObject) -> bool {
+        self.entries
+            .borrow_mut()
+            .remove(&Self::address(key))
+            .is_some()
+    }
+}
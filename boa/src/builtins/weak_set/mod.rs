@@ -0,0 +1,208 @@
+//! This module implements the global `WeakSet` object.
+//!
+//! The JavaScript `WeakSet` class is a global object that is used in the construction of weak
+//! sets; which are collections of objects that do not prevent them from being garbage collected.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-weakset-objects
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet
+
+use crate::{
+    builtins::{iterable::get_iterator, weak_map::weak_table::WeakTable, BuiltIn},
+    object::{ConstructorBuilder, ObjectData, PROTOTYPE},
+    property::Attribute,
+    symbol::WellKnownSymbols,
+    BoaProfiler, Context, Result, Value,
+};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct WeakSet;
+
+impl BuiltIn for WeakSet {
+    const NAME: &'static str = "WeakSet";
+
+    fn attribute() -> Attribute {
+        Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE
+    }
+
+    fn init(context: &mut Context) -> (&'static str, Value, Attribute) {
+        let _timer = BoaProfiler::global().start_event(Self::NAME, "init");
+
+        let to_string_tag = WellKnownSymbols::to_string_tag();
+
+        let weak_set_object = ConstructorBuilder::with_standard_object(
+            context,
+            Self::constructor,
+            context.standard_objects().weak_set_object().clone(),
+        )
+        .name(Self::NAME)
+        .length(Self::LENGTH)
+        .method(Self::add, "add", 1)
+        .method(Self::delete, "delete", 1)
+        .method(Self::has, "has", 1)
+        .property(
+            to_string_tag,
+            Self::NAME,
+            Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        )
+        .build();
+
+        (Self::NAME, weak_set_object.into(), Self::attribute())
+    }
+}
+
+impl WeakSet {
+    pub(crate) const LENGTH: usize = 0;
+
+    /// `WeakSet ( [ iterable ] )`
+    ///
+    /// Creates a new weak set, adding the values of the iterable if one is given.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset-iterable
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/WeakSet
+    pub(crate) fn constructor(
+        new_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+    ) -> Result<Value> {
+        // 1.
+        if new_target.is_undefined() {
+            return context.throw_type_error(
+                "calling a builtin WeakSet constructor without new is forbidden",
+            );
+        }
+
+        // 2.
+        let weak_set_prototype = context.standard_objects().weak_set_object().prototype();
+        let prototype = new_target
+            .as_object()
+            .and_then(|obj| {
+                obj.get(&PROTOTYPE.into(), obj.clone().into(), context)
+                    .map(|o| o.as_object())
+                    .transpose()
+            })
+            .transpose()?
+            .unwrap_or(weak_set_prototype);
+
+        let mut obj = context.construct_object();
+        obj.set_prototype_instance(prototype.into());
+
+        let set = Value::from(obj);
+        set.set_data(ObjectData::WeakSet(WeakTable::new()));
+
+        // 3.
+        let iterable = args.get(0).cloned().unwrap_or_default();
+        if iterable.is_null_or_undefined() {
+            return Ok(set);
+        }
+
+        // 4.
+        let adder = set.get_field("add", context)?;
+
+        // 5.
+        if !adder.is_function() {
+            return context.throw_type_error("'add' of 'newTarget' is not a function");
+        }
+
+        // 6.
+        let iterator_record = get_iterator(context, iterable)?;
+
+        // 7.
+        let mut next = iterator_record.next(context)?;
+        while !next.is_done() {
+            if let Err(status) = context.call(&adder, &set, &[next.value()]) {
+                return iterator_record.close(Err(status), context);
+            }
+
+            next = iterator_record.next(context)?;
+        }
+
+        Ok(set)
+    }
+
+    /// Returns the table of entries of the weak set, or throws if `this` is not a weak set.
+    fn this_table(this: &Value, method: &str, context: &mut Context) -> Result<WeakTable<()>> {
+        let table = match this {
+            Value::Object(ref object) => object.borrow().as_weak_set().cloned(),
+            _ => None,
+        };
+
+        table.ok_or_else(|| {
+            context.construct_type_error(format!(
+                "WeakSet.prototype.{} called on incompatible receiver",
+                method
+            ))
+        })
+    }
+
+    /// `WeakSet.prototype.add( value )`
+    ///
+    /// This method adds the value, which must be an object, to the weak set. Returns the weak set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.add
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/add
+    pub(crate) fn add(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let table = Self::this_table(this, "add", context)?;
+
+        let value = match args.get(0) {
+            Some(Value::Object(ref value)) => value.clone(),
+            _ => return context.throw_type_error("WeakSet values must be objects"),
+        };
+
+        table.insert(&value, ());
+        Ok(this.clone())
+    }
+
+    /// `WeakSet.prototype.delete( value )`
+    ///
+    /// This method removes the value from the weak set. Returns true if it was in the weak set,
+    /// false otherwise.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.delete
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/delete
+    pub(crate) fn delete(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let table = Self::this_table(this, "delete", context)?;
+
+        Ok(match args.get(0) {
+            Some(Value::Object(ref value)) => table.remove(value).into(),
+            _ => false.into(),
+        })
+    }
+
+    /// `WeakSet.prototype.has( value )`
+    ///
+    /// This method checks if the value is in the weak set.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-weakset.prototype.has
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/WeakSet/has
+    pub(crate) fn has(this: &Value, args: &[Value], context: &mut Context) -> Result<Value> {
+        let table = Self::this_table(this, "has", context)?;
+
+        Ok(match args.get(0) {
+            Some(Value::Object(ref value)) => table.contains_key(value).into(),
+            _ => false.into(),
+        })
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{forward, Context};
+
+#[test]
+fn construct_from_iterable() {
+    let mut context = Context::new();
+    let init = r#"
+        var a = {};
+        var b = {};
+        var set = new WeakSet([a, a]);
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "set.has(a)"), "true");
+    assert_eq!(forward(&mut context, "set.has(b)"), "false");
+    assert_eq!(
+        forward(&mut context, "new WeakSet([a, 1])"),
+        "Uncaught \"TypeError\": \"WeakSet values must be objects\""
+    );
+}
+
+#[test]
+fn add_has_delete() {
+    let mut context = Context::new();
+    let init = r#"
+        var value = {};
+        var set = new WeakSet();
+        "#;
+    forward(&mut context, init);
+    assert_eq!(forward(&mut context, "set.has(value)"), "false");
+    assert_eq!(forward(&mut context, "set.add(value) === set"), "true");
+    assert_eq!(forward(&mut context, "set.has(value)"), "true");
+    assert_eq!(forward(&mut context, "set.delete(value)"), "true");
+    assert_eq!(forward(&mut context, "set.delete(value)"), "false");
+    assert_eq!(forward(&mut context, "set.has(value)"), "false");
+}
+
+#[test]
+fn primitive_values() {
+    let mut context = Context::new();
+    let init = r#"
+        var set = new WeakSet();
+        "#;
+    forward(&mut context, init);
+
+    for value in &["1", "\"value\"", "Symbol()", "null", "undefined", "true"] {
+        assert_eq!(
+            forward(&mut context, format!("set.add({})", value)),
+            "Uncaught \"TypeError\": \"WeakSet values must be objects\""
+        );
+        assert_eq!(
+            forward(&mut context, format!("set.has({})", value)),
+            "false"
+        );
+        assert_eq!(
+            forward(&mut context, format!("set.delete({})", value)),
+            "false"
+        );
+    }
+}
+
+#[test]
+fn errors() {
+    let mut context = Context::new();
+
+    assert_eq!(
+        forward(&mut context, "WeakSet()"),
+        "Uncaught \"TypeError\": \"calling a builtin WeakSet constructor without new is forbidden\""
+    );
+    assert_eq!(
+        forward(
+            &mut context,
+            "WeakSet.prototype.has.call(new WeakMap(), {})"
+        ),
+        "Uncaught \"TypeError\": \"WeakSet.prototype.has called on incompatible receiver\""
+    );
+}
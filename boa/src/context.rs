@@ -3,13 +3,19 @@
 use crate::{
     builtins::{
         self,
-        function::{Function, FunctionFlags, NativeFunction},
+        function::{make_capturing_fn, BuiltInClosure, Function, FunctionFlags, NativeFunction},
         iterable::IteratorPrototypes,
     },
     class::{Class, ClassBuilder},
+    environment::{
+        declarative_environment_record::DeclarativeEnvironmentRecord,
+        lexical_environment::Environment,
+    },
     exec::Interpreter,
+    job::Job,
+    module::{get_namespace_export, ExportEntry, ModuleLoader, ModuleRecord},
     object::{FunctionBuilder, GcObject, Object, PROTOTYPE},
-    property::{Attribute, DataDescriptor, PropertyKey},
+    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyKey},
     realm::Realm,
     symbol::{RcSymbol, Symbol},
     syntax::{
@@ -25,14 +31,19 @@ use crate::{
     value::{RcString, Value},
     BoaProfiler, Executable, Result,
 };
+use gc::{Gc, GcCell};
+use rustc_hash::FxHashMap;
+use std::{collections::VecDeque, rc::Rc};
 
 #[cfg(feature = "console")]
 use crate::builtins::console::Console;
 
+#[cfg(feature = "vm")]
+use crate::exec::InterpreterState;
 #[cfg(feature = "vm")]
 use crate::vm::{
     compilation::{CodeGen, Compiler},
-    VM,
+    CodeBlock, VM,
 };
 
 /// Store a builtin constructor (such as `Object`) and its corresponding prototype.
@@ -98,6 +109,9 @@ pub struct StandardObjects {
     uri_error: StandardConstructor,
     map: StandardConstructor,
     set: StandardConstructor,
+    promise: StandardConstructor,
+    weak_map: StandardConstructor,
+    weak_set: StandardConstructor,
 }
 
 impl Default for StandardObjects {
@@ -121,6 +135,9 @@ impl Default for StandardObjects {
             uri_error: StandardConstructor::default(),
             map: StandardConstructor::default(),
             set: StandardConstructor::default(),
+            promise: StandardConstructor::default(),
+            weak_map: StandardConstructor::default(),
+            weak_set: StandardConstructor::default(),
         }
     }
 }
@@ -215,6 +232,21 @@ impl StandardObjects {
     pub fn set_object(&self) -> &StandardConstructor {
         &self.set
     }
+
+    #[inline]
+    pub fn promise_object(&self) -> &StandardConstructor {
+        &self.promise
+    }
+
+    #[inline]
+    pub fn weak_map_object(&self) -> &StandardConstructor {
+        &self.weak_map
+    }
+
+    #[inline]
+    pub fn weak_set_object(&self) -> &StandardConstructor {
+        &self.weak_set
+    }
 }
 
 /// Javascript context. It is the primary way to interact with the runtime.
@@ -242,6 +274,30 @@ pub struct Context {
 
     /// Whether or not to show trace of instructions being ran
     pub trace: bool,
+
+    /// The `import.meta` object of the module being run, if it was already created.
+    import_meta: Option<GcObject>,
+
+    /// The hook resolving the modules imported by module code, if it was set.
+    module_loader: Option<ModuleLoader>,
+
+    /// The modules imported so far, by specifier.
+    modules: FxHashMap<Box<str>, ModuleRecord>,
+
+    /// The jobs waiting to be run once the current script has completed.
+    job_queue: VecDeque<Job>,
+
+    /// Whether the job queue is being drained by [`Context::run_jobs`].
+    running_jobs: bool,
+
+    /// The number of calls of ordinary functions currently being run.
+    pub(crate) call_depth: usize,
+
+    /// The maximum number of nested calls of ordinary functions.
+    max_call_depth: usize,
+
+    /// The number of nodes that can still be evaluated, if the evaluation is bounded.
+    op_budget: Option<usize>,
 }
 
 impl Default for Context {
@@ -256,6 +312,14 @@ impl Default for Context {
             iterator_prototypes: IteratorPrototypes::default(),
             standard_objects: Default::default(),
             trace: false,
+            import_meta: None,
+            module_loader: None,
+            modules: FxHashMap::default(),
+            job_queue: VecDeque::new(),
+            running_jobs: false,
+            call_depth: 0,
+            max_call_depth: Self::DEFAULT_MAX_CALL_DEPTH,
+            op_budget: None,
         };
 
         // Add new builtIns to Context Realm
@@ -268,6 +332,16 @@ impl Default for Context {
 }
 
 impl Context {
+    /// The default maximum number of nested function calls.
+    ///
+    /// Every call of the interpreter takes several kilobytes of native stack, and a lot more in
+    /// debug builds, so the default keeps unbounded recursion within the 2 MiB stack of the
+    /// threads spawned by the standard library.
+    pub const DEFAULT_MAX_CALL_DEPTH: usize = if cfg!(debug_assertions) { 128 } else { 512 };
+
+    /// The error thrown once the operation budget is exhausted.
+    pub const OP_BUDGET_EXHAUSTED: &'static str = "operation budget exhausted";
+
     /// Create a new `Context`.
     #[inline]
     pub fn new() -> Self {
@@ -328,19 +402,56 @@ impl Context {
         self.realm.global_object.clone()
     }
 
+    /// Replaces the global object, so that the global bindings of the code evaluated afterwards
+    /// resolve against `global` instead.
+    ///
+    /// This lets embedders restrict what scripts can access, by only exposing some of the
+    /// builtins. The standard objects are still used internally, for instance as the prototypes
+    /// of literals, even if they aren't reachable from `global`.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{property::Attribute, Context, Value};
+    /// let mut context = Context::new();
+    /// let math = Value::from(context.global_object())
+    ///     .get_field("Math", &mut context)
+    ///     .unwrap();
+    ///
+    /// let mut global = context.construct_object();
+    /// global.insert_property("Math", math, Attribute::all());
+    /// context.set_global_object(global);
+    ///
+    /// assert_eq!(context.eval("Math.abs(-1)").unwrap().as_number(), Some(1.0));
+    /// assert!(context.eval("Array").is_err());
+    /// ```
+    #[inline]
+    pub fn set_global_object(&mut self, global: GcObject) {
+        self.realm = Realm::with_global_object(global);
+    }
+
+    /// Constructs an error by running a `new <constructor>(message)`.
+    ///
+    /// The operation budget is not consumed by this, so that errors can still be constructed once
+    /// it is exhausted.
+    fn construct_error(&mut self, constructor: &str, message: Box<str>) -> Value {
+        let op_budget = self.op_budget.take();
+        let error = New::from(Call::new(
+            Identifier::from(constructor),
+            vec![Const::from(message).into()],
+        ))
+        .run(self)
+        .expect("Into<String> used as message");
+        self.op_budget = op_budget;
+        error
+    }
+
     /// Constructs a `RangeError` with the specified message.
     #[inline]
     pub fn construct_range_error<M>(&mut self, message: M) -> Value
     where
         M: Into<Box<str>>,
     {
-        // Runs a `new RangeError(message)`.
-        New::from(Call::new(
-            Identifier::from("RangeError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_error("RangeError", message.into())
     }
 
     /// Throws a `RangeError` with the specified message.
@@ -358,13 +469,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        // Runs a `new TypeError(message)`.
-        New::from(Call::new(
-            Identifier::from("TypeError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_error("TypeError", message.into())
     }
 
     /// Throws a `TypeError` with the specified message.
@@ -382,12 +487,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        New::from(Call::new(
-            Identifier::from("ReferenceError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_error("ReferenceError", message.into())
     }
 
     /// Throws a `ReferenceError` with the specified message.
@@ -405,12 +505,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        New::from(Call::new(
-            Identifier::from("SyntaxError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_error("SyntaxError", message.into())
     }
 
     /// Throws a `SyntaxError` with the specified message.
@@ -427,12 +522,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        New::from(Call::new(
-            Identifier::from("EvalError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_error("EvalError", message.into())
     }
 
     /// Constructs a `URIError` with the specified message.
@@ -440,12 +530,7 @@ impl Context {
     where
         M: Into<Box<str>>,
     {
-        New::from(Call::new(
-            Identifier::from("URIError"),
-            vec![Const::from(message.into()).into()],
-        ))
-        .run(self)
-        .expect("Into<String> used as message")
+        self.construct_error("URIError", message.into())
     }
 
     /// Throws a `EvalError` with the specified message.
@@ -537,13 +622,68 @@ impl Context {
         Ok(())
     }
 
+    /// Register a global closure.
+    ///
+    /// Unlike [`Context::register_global_function`](Context::register_global_function), the
+    /// function body can be a closure capturing Rust state. The function is only `callable`,
+    /// and receives the `this` value and the arguments it was called with.
+    ///
+    /// The function will be bound to the global object with `writable`, `non-enumerable`
+    /// and `configurable` attributes.
+    ///
+    /// # Note
+    ///
+    /// The captured state is not traced by the garbage collector, so the closure must not
+    /// capture garbage collected values such as `Value` or `GcObject`.
+    ///
+    /// # Example
+    /// ```
+    /// use boa::{Context, Value};
+    ///
+    /// let mut context = Context::new();
+    ///
+    /// let offset = 10.0;
+    /// context
+    ///     .register_global_closure("addOffset", 1, move |_, args, context| {
+    ///         let x = args.get(0).cloned().unwrap_or_default().to_number(context)?;
+    ///         Ok(Value::from(x + offset))
+    ///     })
+    ///     .unwrap();
+    ///
+    /// let value = context.eval("addOffset(5)").unwrap();
+    /// assert_eq!(value.as_number().unwrap(), 15.0);
+    /// ```
+    #[inline]
+    pub fn register_global_closure<F>(&mut self, name: &str, length: usize, body: F) -> Result<()>
+    where
+        F: Fn(&Value, &[Value], &mut Context) -> Result<Value> + 'static,
+    {
+        let mut function = Object::function(
+            Function::Closure(BuiltInClosure(Rc::new(body)), FunctionFlags::CALLABLE),
+            self.standard_objects().function_object().prototype().into(),
+        );
+        let attribute = Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE;
+        function.insert_property("name", name, attribute);
+        function.insert_property("length", length, attribute);
+
+        self.global_object().insert_property(
+            name,
+            GcObject::new(function),
+            Attribute::WRITABLE | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+        );
+        Ok(())
+    }
+
     /// <https://tc39.es/ecma262/#sec-hasproperty>
     #[inline]
-    pub(crate) fn has_property(&self, obj: &Value, key: &PropertyKey) -> bool {
+    pub(crate) fn has_property(&mut self, obj: &Value, key: &PropertyKey) -> Result<bool> {
         if let Some(obj) = obj.as_object() {
-            obj.has_property(key)
+            if let Some(proxy) = obj.as_proxy() {
+                return proxy.has(key, self);
+            }
+            Ok(obj.has_property(key))
         } else {
-            false
+            Ok(false)
         }
     }
 
@@ -622,6 +762,8 @@ impl Context {
 
     /// Evaluates the given code.
     ///
+    /// The jobs enqueued by the code, such as `Promise` reactions, are run once it has completed.
+    ///
     /// # Examples
     /// ```
     ///# use boa::Context;
@@ -647,12 +789,13 @@ impl Context {
             Ok(statement_list) => statement_list.run(self),
             Err(e) => self.throw_syntax_error(e),
         };
+        let jobs_result = self.run_jobs();
 
         // The main_timer needs to be dropped before the BoaProfiler is.
         drop(main_timer);
         BoaProfiler::global().drop();
 
-        execution_result
+        execution_result.and_then(|value| jobs_result.map(|_| value))
     }
 
     /// Evaluates the given code by compiling down to bytecode, then interpreting the bytecode into a value
@@ -682,17 +825,335 @@ impl Context {
             Err(e) => return self.throw_syntax_error(e),
         };
 
+        // Generate Bytecode and place it into instruction_stack
+        let code_block = self.compile(&statement_list);
+        // Interpret the Bytecode
+        let result = self.execute(&code_block);
+
+        // The main_timer needs to be dropped before the BoaProfiler is.
+        drop(main_timer);
+        BoaProfiler::global().drop();
+
+        result
+    }
+
+    /// Compiles the given statements down to bytecode.
+    ///
+    /// The returned code block doesn't depend on the state of the context, so it can be cached
+    /// and run any number of times with [`Context::execute`], without parsing it again.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{parse, Context};
+    /// let mut context = Context::new();
+    ///
+    /// let statement_list = parse("1 + 3", false).unwrap();
+    /// let code_block = context.compile(&statement_list);
+    ///
+    /// let value = context.execute(&code_block).unwrap();
+    /// assert_eq!(value.as_number().unwrap(), 4.0);
+    /// ```
+    #[cfg(feature = "vm")]
+    pub fn compile(&self, statement_list: &StatementList) -> CodeBlock {
+        let _timer = BoaProfiler::global().start_event("compile", "vm");
         let mut compiler = Compiler::default();
         statement_list.compile(&mut compiler);
+        compiler.into()
+    }
+
+    /// Runs the bytecode compiled by [`Context::compile`].
+    ///
+    /// As with [`Context::eval`], the enqueued jobs are run once the code has completed.
+    #[cfg(feature = "vm")]
+    pub fn execute(&mut self, code_block: &CodeBlock) -> Result<Value> {
+        // A previous run may have stopped on an error, which must not cut this one short.
+        self.executor()
+            .set_current_state(InterpreterState::Executing);
+        let result = VM::with_code_block(code_block.clone(), self).run();
+        let jobs_result = self.run_jobs();
+
+        result.and_then(|value| jobs_result.map(|_| value))
+    }
+
+    /// Evaluates the given code as module code.
+    ///
+    /// Module code is always strict, and can use `import.meta`. As with [`Context::eval`], the
+    /// enqueued jobs are run once the module has completed.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{property::Attribute, Context};
+    /// let mut context = Context::new();
+    ///
+    /// let mut meta = context.import_meta();
+    /// meta.insert_property("url", "file:///main.js", Attribute::all());
+    ///
+    /// let value = context.eval_module("import.meta.url").unwrap();
+    ///
+    /// assert_eq!(value.as_string().unwrap().as_str(), "file:///main.js");
+    /// ```
+    #[allow(clippy::unit_arg, clippy::drop_copy)]
+    pub fn eval_module<T: AsRef<[u8]>>(&mut self, src: T) -> Result<Value> {
+        let main_timer = BoaProfiler::global().start_event("Main", "Main");
+        let src_bytes: &[u8] = src.as_ref();
+
+        let parsing_result = Parser::new_module(src_bytes)
+            .parse_module()
+            .map_err(|e| e.to_string());
+
+        let execution_result = match parsing_result {
+            Ok(statement_list) => statement_list.run(self),
+            Err(e) => self.throw_syntax_error(e),
+        };
+        let jobs_result = self.run_jobs();
 
-        let mut vm = VM::new(compiler, self);
-        // Generate Bytecode and place it into instruction_stack
-        // Interpret the Bytecode
-        let result = vm.run();
         // The main_timer needs to be dropped before the BoaProfiler is.
         drop(main_timer);
         BoaProfiler::global().drop();
 
+        execution_result.and_then(|value| jobs_result.map(|_| value))
+    }
+
+    /// Returns the `import.meta` object of the current module.
+    ///
+    /// The object is created on first use, with a `null` prototype, and can be populated by the
+    /// embedder, for example with the `url` of the module.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-meta-properties-runtime-semantics-evaluation
+    pub fn import_meta(&mut self) -> GcObject {
+        self.import_meta
+            .get_or_insert_with(|| GcObject::new(Object::create(Value::null())))
+            .clone()
+    }
+
+    /// Sets the `import.meta` object of the current module.
+    #[inline]
+    pub fn set_import_meta(&mut self, import_meta: GcObject) {
+        self.import_meta = Some(import_meta);
+    }
+
+    /// Sets the loader resolving the specifiers of the modules imported by module code to their
+    /// source code.
+    ///
+    /// Without a loader, `import` declarations don't load anything, and the imported bindings
+    /// are `undefined`.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{module::ModuleLoader, Context};
+    /// let mut context = Context::new();
+    ///
+    /// context.set_module_loader(ModuleLoader::new(|specifier| match specifier {
+    ///     "answer" => Some("export const answer = 42;".to_owned()),
+    ///     _ => None,
+    /// }));
+    ///
+    /// let value = context
+    ///     .eval_module("import { answer } from 'answer'; answer")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(value.as_number(), Some(42.0));
+    /// ```
+    #[inline]
+    pub fn set_module_loader(&mut self, loader: ModuleLoader) {
+        self.module_loader = Some(loader);
+    }
+
+    /// Imports the module with the given specifier, evaluating it the first time it is imported.
+    ///
+    /// If the module is already being evaluated, because modules import each other, its record is
+    /// returned as is. Its bindings which weren't initialized yet can't be accessed until they
+    /// are.
+    ///
+    /// Returns `None` if no module loader was set.
+    pub(crate) fn import_module(&mut self, specifier: &str) -> Result<Option<ModuleRecord>> {
+        if let Some(record) = self.modules.get(specifier) {
+            return Ok(Some(record.clone()));
+        }
+
+        let source = match self.module_loader {
+            Some(ref loader) => loader.load(specifier),
+            None => return Ok(None),
+        };
+        let source = source.ok_or_else(|| {
+            self.construct_type_error(format!("cannot find module `{}`", specifier))
+        })?;
+        let statement_list = Parser::new_module(source.as_bytes())
+            .parse_module()
+            .map_err(|e| self.construct_syntax_error(e.to_string()))?;
+
+        // Modules are evaluated in their own environment, on top of the global one.
+        let env = self.get_global_environment();
+        let env: Environment = DeclarativeEnvironmentRecord::new(Some(env)).into();
+        let namespace = GcObject::new(Object::create(Value::null()));
+        let record = ModuleRecord::new(specifier, &statement_list, namespace, env.clone());
+        let names: Vec<Box<str>> = record.export_names().map(Into::into).collect();
+        self.modules.insert(specifier.into(), record.clone());
+        self.add_namespace_exports(&record, names);
+
+        self.push_environment(env);
+        let result = statement_list.run(self);
+        let _ = self.pop_environment();
+
+        if let Err(e) = result {
+            self.modules.remove(specifier);
+            return Err(e);
+        }
+
+        // The names exported with `export *` are only known once the modules they are
+        // re-exported from are imported, which happens while evaluating the module.
+        let mut names = Vec::new();
+        for module in record.star_exports() {
+            if let Some(star) = self.modules.get(module) {
+                names.extend(
+                    star.namespace()
+                        .own_property_keys()
+                        .into_iter()
+                        .filter_map(|key| match key {
+                            PropertyKey::String(name) if name.as_str() != "default" => {
+                                Some(name.as_str().into())
+                            }
+                            _ => None,
+                        }),
+                );
+            }
+        }
+        self.add_namespace_exports(&record, names);
+        Ok(Some(record))
+    }
+
+    /// Adds accessor properties for the given exports to the namespace object of a module, which
+    /// read the current values of the exported bindings.
+    fn add_namespace_exports(&mut self, record: &ModuleRecord, names: Vec<Box<str>>) {
+        let mut namespace = record.namespace().clone();
+        for name in names {
+            let key = PropertyKey::from(name.as_ref());
+            if namespace.get_own_property(&key).is_some() {
+                continue;
+            }
+            let captures = Gc::new(GcCell::new(vec![
+                record.specifier().into(),
+                name.as_ref().into(),
+            ]));
+            let getter = make_capturing_fn(get_namespace_export, captures, "", 0, self);
+            namespace.insert(
+                key,
+                AccessorDescriptor::new(Some(getter), None, Attribute::ENUMERABLE),
+            );
+        }
+    }
+
+    /// Finds the module and the local binding of the export `name` of the module `specifier`,
+    /// following re-exports.
+    ///
+    /// Returns `None` if the module has no such export.
+    pub(crate) fn resolve_export(
+        &mut self,
+        specifier: &str,
+        name: &str,
+    ) -> Result<Option<(ModuleRecord, Box<str>)>> {
+        let record = match self.import_module(specifier)? {
+            Some(record) => record,
+            None => return Ok(None),
+        };
+
+        match record.export_entry(name) {
+            Some(ExportEntry::Local(local)) => {
+                let local = local.clone();
+                return Ok(Some((record, local)));
+            }
+            Some(ExportEntry::Indirect { module, name }) => {
+                return self.resolve_export(module, name);
+            }
+            None => {}
+        }
+
+        if name != "default" {
+            for module in record.star_exports() {
+                if let Some(resolved) = self.resolve_export(module, name)? {
+                    return Ok(Some(resolved));
+                }
+            }
+        }
+        Ok(None)
+    }
+
+    /// Gets the current value of the binding exported by the module `specifier` as `name`.
+    ///
+    /// It is a `SyntaxError` if the module has no such export, or a `ReferenceError` if the
+    /// binding was not initialized yet, because modules import each other.
+    pub(crate) fn get_module_export(&mut self, specifier: &str, name: &str) -> Result<Value> {
+        let (record, local) = match self.resolve_export(specifier, name)? {
+            Some(resolved) => resolved,
+            None => {
+                return self.throw_syntax_error(format!(
+                    "module `{}` has no export named `{}`",
+                    specifier, name
+                ))
+            }
+        };
+
+        let env = record.environment().clone();
+        if env.has_binding(&local) {
+            env.get_binding_value(&local, true, self)
+        } else {
+            self.throw_reference_error(format!(
+                "cannot access `{}` of module `{}` before its initialization",
+                name,
+                record.specifier()
+            ))
+        }
+    }
+
+    /// Adds a job to the end of the job queue.
+    ///
+    /// The job is run by the next call to [`Context::run_jobs`], after all the jobs that were
+    /// enqueued before it.
+    #[inline]
+    pub fn enqueue_job(&mut self, job: Job) {
+        self.job_queue.push_back(job);
+    }
+
+    /// Runs the queued jobs, such as `Promise` reactions, until the queue is empty.
+    ///
+    /// Jobs are run in FIFO order: in the order they were enqueued, with the jobs enqueued while
+    /// running them being run after the ones already in the queue.
+    ///
+    /// [`Context::eval`] calls this once the script has completed, but embedders running their own
+    /// event loop can also call it to decide when the enqueued callbacks are run.
+    ///
+    /// If a job throws, the error is returned and the remaining jobs are left in the queue.
+    ///
+    /// Calling it while the jobs are already being run, such as when a job evaluates code with
+    /// [`Context::eval`], does nothing: the remaining jobs are only run by the outermost call.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::{job::Job, Context, Value};
+    /// let mut context = Context::new();
+    ///
+    /// context.enqueue_job(Job::new(|context| context.eval("var ran = true")));
+    /// context.run_jobs().unwrap();
+    ///
+    /// assert_eq!(context.eval("ran").unwrap(), Value::from(true));
+    /// ```
+    pub fn run_jobs(&mut self) -> Result<()> {
+        if self.running_jobs {
+            return Ok(());
+        }
+
+        self.running_jobs = true;
+        let mut result = Ok(());
+        while let Some(job) = self.job_queue.pop_front() {
+            if let Err(error) = job.run(self) {
+                result = Err(error);
+                break;
+            }
+        }
+        self.running_jobs = false;
         result
     }
 
@@ -712,4 +1173,77 @@ impl Context {
     pub fn set_trace(&mut self, trace: bool) {
         self.trace = trace;
     }
+
+    /// Returns the maximum number of nested function calls.
+    #[inline]
+    pub fn max_call_depth(&self) -> usize {
+        self.max_call_depth
+    }
+
+    /// Sets the maximum number of nested function calls.
+    ///
+    /// Calling a function beyond this depth throws a `RangeError` instead of overflowing the
+    /// native stack. Embedders running scripts on threads with a small stack may need to lower it
+    /// from its default of [`Context::DEFAULT_MAX_CALL_DEPTH`].
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    /// context.set_max_call_depth(10);
+    ///
+    /// let result = context.eval("function f(n) { return n === 0 ? 0 : f(n - 1); } f(20)");
+    /// assert!(result.is_err());
+    /// ```
+    #[inline]
+    pub fn set_max_call_depth(&mut self, max_call_depth: usize) {
+        self.max_call_depth = max_call_depth;
+    }
+
+    /// Returns the number of operations that can still be run, or `None` if it is unbounded.
+    #[inline]
+    pub fn op_budget(&self) -> Option<usize> {
+        self.op_budget
+    }
+
+    /// Sets the number of operations that can be run, or makes it unbounded if `None` is given.
+    ///
+    /// Each evaluation of a statement or an expression consumes one operation. Once the budget is
+    /// exhausted, the evaluation throws the [`Context::OP_BUDGET_EXHAUSTED`] string instead, and
+    /// so does every following evaluation, so that scripts cannot catch the error and carry on.
+    /// Embedders can tell it apart from a value thrown by the script with
+    /// [`Context::is_op_budget_exhausted`], and set a new budget to run scripts again.
+    ///
+    /// # Examples
+    /// ```
+    ///# use boa::Context;
+    /// let mut context = Context::new();
+    /// context.set_op_budget(Some(1000));
+    ///
+    /// assert!(context.eval("while (true) {}").is_err());
+    /// assert!(context.is_op_budget_exhausted());
+    /// ```
+    #[inline]
+    pub fn set_op_budget(&mut self, op_budget: Option<usize>) {
+        self.op_budget = op_budget;
+    }
+
+    /// Returns `true` if the operation budget set with [`Context::set_op_budget`] is exhausted.
+    #[inline]
+    pub fn is_op_budget_exhausted(&self) -> bool {
+        self.op_budget == Some(0)
+    }
+
+    /// Consumes one operation of the operation budget, or throws if it is exhausted.
+    #[inline]
+    pub(crate) fn consume_op(&mut self) -> Result<()> {
+        match self.op_budget {
+            Some(0) => Err(Value::from(Self::OP_BUDGET_EXHAUSTED)),
+            Some(ref mut op_budget) => {
+                *op_budget -= 1;
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    }
 }
@@ -28,6 +28,9 @@ pub struct DeclarativeEnvironmentRecordBinding {
     pub can_delete: bool,
     pub mutable: bool,
     pub strict: bool,
+    /// The specifier of the module and the name of the export an import binding refers to.
+    #[unsafe_ignore_trace]
+    pub import: Option<(Box<str>, Box<str>)>,
 }
 
 /// A declarative Environment Record binds the set of identifiers defined by the
@@ -75,6 +78,7 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
                 can_delete: deletion,
                 mutable: true,
                 strict: false,
+                import: None,
             },
         );
         Ok(())
@@ -99,6 +103,33 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
                 can_delete: true,
                 mutable: false,
                 strict,
+                import: None,
+            },
+        );
+        Ok(())
+    }
+
+    fn create_import_binding(
+        &self,
+        name: String,
+        module: &str,
+        export: &str,
+        _context: &mut Context,
+    ) -> Result<()> {
+        assert!(
+            !self.env_rec.borrow().contains_key(name.as_str()),
+            "Identifier {} has already been declared",
+            name
+        );
+
+        self.env_rec.borrow_mut().insert(
+            name.into_boxed_str(),
+            DeclarativeEnvironmentRecordBinding {
+                value: None,
+                can_delete: false,
+                mutable: false,
+                strict: true,
+                import: Some((module.into(), export.into())),
             },
         );
         Ok(())
@@ -135,6 +166,12 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
         let (record_strict, record_has_no_value, record_mutable) = {
             let env_rec = self.env_rec.borrow();
             let record = env_rec.get(name).unwrap();
+            if record.import.is_some() {
+                return Err(context.construct_type_error(format!(
+                    "Cannot assign to the imported binding {}",
+                    name
+                )));
+            }
             (record.strict, record.value.is_none(), record.mutable)
         };
         if record_strict {
@@ -160,6 +197,15 @@ impl EnvironmentRecordTrait for DeclarativeEnvironmentRecord {
     }
 
     fn get_binding_value(&self, name: &str, _strict: bool, context: &mut Context) -> Result<Value> {
+        let import = self
+            .env_rec
+            .borrow()
+            .get(name)
+            .and_then(|binding| binding.import.clone());
+        if let Some((module, export)) = import {
+            return context.get_module_export(&module, &export);
+        }
+
         if let Some(binding) = self.env_rec.borrow().get(name) {
             if let Some(ref val) = binding.value {
                 Ok(val.clone())
@@ -52,6 +52,18 @@ pub trait EnvironmentRecordTrait: Debug + Trace + Finalize {
         context: &mut Context,
     ) -> Result<()>;
 
+    /// Create an immutable binding for the export `export` of the module `module`, which always
+    /// has the current value of the exported binding.
+    ///
+    /// <https://tc39.es/ecma262/#sec-createimportbinding>
+    fn create_import_binding(
+        &self,
+        name: String,
+        module: &str,
+        export: &str,
+        context: &mut Context,
+    ) -> Result<()>;
+
     /// Set the value of an already existing but uninitialized binding in an Environment Record.
     /// The String value N is the text of the bound name.
     /// V is the value for the binding and is a value of any ECMAScript language type.
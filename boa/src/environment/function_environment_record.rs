@@ -138,6 +138,17 @@ impl EnvironmentRecordTrait for FunctionEnvironmentRecord {
             .create_immutable_binding(name, strict, context)
     }
 
+    fn create_import_binding(
+        &self,
+        name: String,
+        module: &str,
+        export: &str,
+        context: &mut Context,
+    ) -> Result<()> {
+        self.declarative_record
+            .create_import_binding(name, module, export, context)
+    }
+
     fn initialize_binding(&self, name: &str, value: Value, context: &mut Context) -> Result<()> {
         self.declarative_record
             .initialize_binding(name, value, context)
@@ -184,6 +184,23 @@ impl EnvironmentRecordTrait for GlobalEnvironmentRecord {
             .create_immutable_binding(name, strict, context)
     }
 
+    fn create_import_binding(
+        &self,
+        name: String,
+        module: &str,
+        export: &str,
+        context: &mut Context,
+    ) -> Result<()> {
+        if self.declarative_record.has_binding(&name) {
+            return Err(
+                context.construct_type_error(format!("Binding already exists for {}", name))
+            );
+        }
+
+        self.declarative_record
+            .create_import_binding(name, module, export, context)
+    }
+
     fn initialize_binding(&self, name: &str, value: Value, context: &mut Context) -> Result<()> {
         if self.declarative_record.has_binding(&name) {
             return self
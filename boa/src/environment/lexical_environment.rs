@@ -113,6 +113,16 @@ impl Context {
             .recursive_create_immutable_binding(name, deletion, scope, self)
     }
 
+    pub(crate) fn create_import_binding(
+        &mut self,
+        name: String,
+        module: &str,
+        export: &str,
+    ) -> Result<()> {
+        self.get_current_environment()
+            .create_import_binding(name, module, export, self)
+    }
+
     pub(crate) fn set_mutable_binding(
         &mut self,
         name: &str,
@@ -139,6 +149,16 @@ impl Context {
             .clone()
     }
 
+    /// Gets the global environment, which is the outermost one.
+    pub(crate) fn get_global_environment(&self) -> Environment {
+        self.realm
+            .environment
+            .environment_stack
+            .front()
+            .expect("Could not get the global environment")
+            .clone()
+    }
+
     pub(crate) fn has_binding(&mut self, name: &str) -> bool {
         self.get_current_environment().recursive_has_binding(name)
     }
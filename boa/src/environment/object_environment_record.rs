@@ -84,6 +84,18 @@ impl EnvironmentRecordTrait for ObjectEnvironmentRecord {
         Ok(())
     }
 
+    fn create_import_binding(
+        &self,
+        _name: String,
+        _module: &str,
+        _export: &str,
+        _context: &mut Context,
+    ) -> Result<()> {
+        // Imports are only allowed at the top level of modules, which don't have object
+        // environments.
+        Ok(())
+    }
+
     fn initialize_binding(&self, name: &str, value: Value, context: &mut Context) -> Result<()> {
         // We should never need to check if a binding has been created,
         // As all calls to create_mutable_binding are followed by initialized binding
@@ -1,4 +1,4 @@
-use crate::{builtins::Number, exec, forward, forward_val, Context, Value};
+use crate::{builtins::Number, exec, forward, forward_val, property::Attribute, Context, Value};
 
 #[test]
 fn function_declaration_returns_undefined() {
@@ -551,6 +551,23 @@ fn typeof_function() {
     assert_eq!(&exec(typeof_function), "\"function\"");
 }
 
+#[test]
+fn typeof_undeclared() {
+    let typeof_undeclared = r#"
+        typeof undeclaredVar;
+    "#;
+    assert_eq!(&exec(typeof_undeclared), "\"undefined\"");
+
+    let typeof_undeclared_property = r#"
+        try {
+            typeof undeclaredVar.prop;
+        } catch (e) {
+            e.name;
+        }
+    "#;
+    assert_eq!(&exec(typeof_undeclared_property), "\"ReferenceError\"");
+}
+
 #[test]
 fn unary_post() {
     let unary_inc = r#"
@@ -594,6 +611,24 @@ fn unary_post() {
     assert_eq!(&exec(execs_after_dec), "true");
 }
 
+#[test]
+fn exponentiation() {
+    assert_eq!(&exec("2 ** 3 ** 2 === 512"), "true");
+    assert_eq!(&exec("2 * 3 ** 2"), "18");
+    assert_eq!(&exec("(-2) ** 2"), "4");
+    assert_eq!(&exec("2 ** -1"), "0.5");
+    assert_eq!(&exec("2 ** 0.5 === Math.pow(2, 0.5)"), "true");
+    assert_eq!(&exec("'3' ** 2"), "9");
+    assert_eq!(&exec("let a = 2; a **= 3; a"), "8");
+}
+
+#[test]
+fn exponentiation_unary_base_is_syntax_error() {
+    let mut context = Context::new();
+
+    assert!(forward(&mut context, "-2 ** 2").starts_with("Uncaught \"SyntaxError\": "));
+}
+
 #[test]
 fn unary_void() {
     let void_should_return_undefined = r#"
@@ -663,6 +698,54 @@ fn unary_delete() {
     assert_eq!(&exec(delete_recursive), "true");
 }
 
+#[test]
+fn unary_delete_evaluates_operand() {
+    let delete_call = r#"
+        let called = false;
+        function f() { called = true; }
+        const a = delete f();
+        a + ',' + called
+    "#;
+    assert_eq!(&exec(delete_call), "\"true,true\"");
+}
+
+#[test]
+fn unary_delete_evaluates_key_before_base_conversion() {
+    let delete_null_base = r#"
+        let called = false;
+        function f() { called = true; return 'a'; }
+        try {
+            delete null[f()];
+        } catch (e) {
+            e.name + ',' + called;
+        }
+    "#;
+    assert_eq!(&exec(delete_null_base), "\"TypeError,true\"");
+}
+
+#[test]
+fn unary_delete_non_configurable() {
+    let delete_sloppy = r#"
+        const a = {};
+        Object.defineProperty(a, 'b', { value: 5, configurable: false });
+        const c = delete a.b;
+        a.b + ',' + c
+    "#;
+    assert_eq!(&exec(delete_sloppy), "\"5,false\"");
+
+    let delete_strict = r#"
+        'use strict';
+        const a = {};
+        Object.defineProperty(a, 'b', { value: 5, configurable: false });
+        try {
+            delete a['b'];
+        } catch (e) {
+            e.name + ',' + a.b;
+        }
+    "#;
+    assert_eq!(&exec(delete_strict), "\"TypeError,5\"");
+}
+
 #[cfg(test)]
 mod in_operator {
     use super::*;
@@ -687,6 +770,16 @@ mod in_operator {
         assert_eq!(&exec(p_in_o), "true");
     }
 
+    #[test]
+    fn property_in_user_prototype() {
+        let p_in_o = r#"
+            var proto = {a: 'a'};
+            var o = Object.create(proto);
+            ('a' in o) + ',' + o.hasOwnProperty('a')
+        "#;
+        assert_eq!(&exec(p_in_o), "\"true,false\"");
+    }
+
     #[test]
     fn property_not_in_object() {
         let p_not_in_o = r#"
@@ -709,6 +802,15 @@ mod in_operator {
         assert_eq!(&exec(num_in_array), "true");
     }
 
+    #[test]
+    fn index_out_of_bounds_not_in_array() {
+        let num_in_array = r#"
+            var a = ['a'];
+            (1 in a) + ',' + ('length' in a)
+        "#;
+        assert_eq!(&exec(num_in_array), "\"false,true\"");
+    }
+
     #[test]
     fn symbol_in_object() {
         let sym_in_object = r#"
@@ -737,6 +839,18 @@ mod in_operator {
         assert_eq!(forward(&mut context, "x"), "true");
     }
 
+    #[test]
+    fn should_type_error_when_rhs_is_primitive() {
+        let scenario = r#"
+            try {
+                'length' in 'abc'
+            } catch(e) {
+                e.name
+            }
+        "#;
+        assert_eq!(&exec(scenario), "\"TypeError\"");
+    }
+
     #[test]
     fn should_set_this_value() {
         let mut context = Context::new();
@@ -1157,6 +1271,37 @@ fn check_this_binding_in_object_literal() {
     assert_eq!(forward(&mut context, init), "8");
 }
 
+#[test]
+fn check_arrow_function_lexical_this() {
+    let mut context = Context::new();
+    let init = r#"
+        const o = { v: 1, f() { return (() => this.v)(); } };
+
+        function make() {
+            return { v: 2, g: () => this.v };
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "o.f()"), "1");
+    assert_eq!(forward(&mut context, "make.call({ v: 3 }).g()"), "3");
+    assert_eq!(
+        forward(&mut context, "const h = o.f; h.call({ v: 4 })"),
+        "4"
+    );
+}
+
+#[test]
+fn check_arrow_function_not_constructor() {
+    let mut context = Context::new();
+    let init = r#"
+        const A = () => {};
+        new A();
+        "#;
+
+    assert!(forward(&mut context, init).starts_with("Uncaught \"TypeError\""));
+}
+
 #[test]
 fn array_creation_benchmark() {
     let mut context = Context::new();
@@ -1223,6 +1368,30 @@ fn number_object_access_benchmark() {
     assert!(forward_val(&mut context, init).is_ok());
 }
 
+#[test]
+fn calling_generator_function_is_not_supported() {
+    let mut context = Context::new();
+    let init = r#"
+        var ran = false;
+        function* gen() {
+            ran = true;
+            yield 1;
+        }
+        "#;
+    forward(&mut context, init);
+
+    assert_eq!(forward(&mut context, "typeof gen"), "\"function\"");
+    assert_eq!(
+        forward(&mut context, "gen()"),
+        "Uncaught \"TypeError\": \"generator functions are not supported yet\""
+    );
+    assert_eq!(forward(&mut context, "ran"), "false");
+    assert_eq!(
+        forward(&mut context, "new gen()"),
+        "Uncaught \"TypeError\": \"\"gen\" is not a constructor\""
+    );
+}
+
 #[test]
 fn not_a_function() {
     let mut context = Context::new();
@@ -1285,6 +1454,18 @@ fn comma_operator() {
     assert_eq!(&exec(scenario), "2");
 }
 
+#[test]
+fn comma_operator_in_for_header() {
+    let scenario = r#"
+        var i, j, sum = 0;
+        for (i = 0, j = 10; i < j; i++, j--) {
+            sum += j - i;
+        }
+        sum + ',' + i + ',' + j
+    "#;
+    assert_eq!(&exec(scenario), "\"30,5,5\"");
+}
+
 #[test]
 fn assignment_to_non_assignable() {
     // Relates to the behaviour described at
@@ -1393,6 +1574,37 @@ fn test_conditional_op() {
     assert_eq!(&exec(scenario), "\"b\"");
 }
 
+#[test]
+fn test_nested_conditional_op() {
+    let scenario = r#"
+        function grade(n) {
+            return n > 2 ? 'high' : n > 1 ? 'mid' : 'low';
+        }
+        grade(3) + grade(2) + grade(1);
+    "#;
+    assert_eq!(&exec(scenario), "\"highmidlow\"");
+
+    // Only the selected branches are evaluated.
+    let scenario = r#"
+        var log = '';
+        function f(name, value) { log += name; return value; }
+        f('a', false) ? f('b', 1) : f('c', true) ? f('d', 2) : f('e', 3);
+        log;
+    "#;
+    assert_eq!(&exec(scenario), "\"acd\"");
+}
+
+#[test]
+fn test_conditional_op_assignment_in_branches() {
+    let scenario = r#"
+        var b = 0, c = 0;
+        true ? b = 1 : c = 2;
+        false ? b = 3 : c = 4;
+        b + ',' + c;
+    "#;
+    assert_eq!(&exec(scenario), "\"1,4\"");
+}
+
 #[test]
 fn test_identifier_op() {
     let scenario = "break = 1";
@@ -1455,6 +1667,47 @@ fn test_strict_mode_delete() {
     assert!(string.starts_with("Uncaught \"SyntaxError\": "));
 }
 
+#[test]
+fn test_strict_mode_assign_undeclared() {
+    // Checks as per https://tc39.es/ecma262/#sec-putvalue that assigning to an unresolvable
+    // reference is an error in strict mode code.
+
+    let scenario = r#"
+    'use strict';
+    try {
+        undeclared = 1;
+    } catch (e) {
+        e.toString();
+    }
+    "#;
+    assert_eq!(
+        &exec(scenario),
+        "\"ReferenceError: undeclared is not defined\""
+    );
+
+    let scenario = r#"
+    function f() {
+        'use strict';
+        undeclared = 1;
+    }
+    try {
+        f();
+    } catch (e) {
+        e.name + ',' + typeof undeclared;
+    }
+    "#;
+    assert_eq!(&exec(scenario), "\"ReferenceError,undefined\"");
+}
+
+#[test]
+fn test_sloppy_mode_assign_undeclared() {
+    let scenario = r#"
+    undeclared = 1;
+    undeclared
+    "#;
+    assert_eq!(&exec(scenario), "1");
+}
+
 #[test]
 fn test_strict_mode_reserved_name() {
     // Checks that usage of a reserved keyword for an identifier name is
@@ -1529,3 +1782,447 @@ fn test_empty_statement() {
     "#;
     assert_eq!(&exec(src), "10");
 }
+
+#[test]
+fn labelled_block_break() {
+    let scenario = r#"
+        var str = "";
+        outer: {
+            str = str + "a";
+            if (true) {
+                break outer;
+            }
+            str = str + "b";
+        }
+        str = str + "c";
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"ac\"");
+}
+
+#[test]
+fn labelled_block_break_from_loop() {
+    let scenario = r#"
+        var count = 0;
+        block: {
+            while (true) {
+                count++;
+                if (count === 3) {
+                    break block;
+                }
+            }
+            count = 100;
+        }
+        count
+    "#;
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn unlabelled_break_inside_labelled_block() {
+    let scenario = r#"
+        var count = 0;
+        while (true) {
+            inner: {
+                count++;
+                break;
+            }
+            count = 100;
+        }
+        count
+    "#;
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn nullish_coalescing() {
+    assert_eq!(&exec("(0 ?? 5) === 0"), "true");
+    assert_eq!(&exec("(null ?? 5) === 5"), "true");
+    assert_eq!(&exec("(undefined ?? 'default')"), "\"default\"");
+    assert_eq!(&exec("('' ?? 'default')"), "\"\"");
+    assert_eq!(&exec("(false ?? true)"), "false");
+}
+
+#[test]
+fn nullish_coalescing_short_circuits() {
+    let scenario = r#"
+        let count = 0;
+        let a = 1 ?? count++;
+        let b = null ?? count++;
+        count;
+    "#;
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn nullish_coalescing_mixed_with_logical_is_syntax_error() {
+    let mut context = Context::new();
+
+    let string = forward(&mut context, "null ?? 1 || 2");
+
+    assert!(string.starts_with("Uncaught \"SyntaxError\": "));
+}
+
+#[test]
+fn import_meta_url() {
+    use crate::property::Attribute;
+
+    let mut context = Context::new();
+    let mut meta = context.import_meta();
+    meta.insert_property("url", "file:///main.js", Attribute::all());
+
+    let value = context
+        .eval_module("const { url } = import.meta; url + '!'")
+        .expect("import.meta should be readable in module code");
+    assert_eq!(value.display().to_string(), "\"file:///main.js!\"");
+
+    let value = context
+        .eval_module("import.meta === import.meta")
+        .expect("import.meta should be readable in module code");
+    assert_eq!(value, Value::from(true));
+}
+
+#[test]
+fn import_meta_in_script_is_syntax_error() {
+    let mut context = Context::new();
+    let result = context.eval("import.meta");
+    assert!(result.is_err());
+}
+
+/// Creates a context loading its modules from the given in-memory sources.
+fn context_with_modules(modules: &[(&'static str, &'static str)]) -> Context {
+    use crate::module::ModuleLoader;
+
+    let modules = modules.to_vec();
+    let mut context = Context::new();
+    context.set_module_loader(ModuleLoader::new(move |specifier| {
+        modules
+            .iter()
+            .find(|(name, _)| *name == specifier)
+            .map(|(_, source)| source.to_string())
+    }));
+    context
+}
+
+#[test]
+fn import_function_from_module() {
+    let mut context = context_with_modules(&[(
+        "math",
+        r#"
+        export function add(a, b) {
+            return a + b;
+        }
+        export default function double(a) {
+            return add(a, a);
+        }
+        export const two = 2;
+        "#,
+    )]);
+
+    let value = context
+        .eval_module(
+            r#"
+            import double, { add, two as deux } from "math";
+            import * as math from "math";
+            [add(deux, 3), double(4), math.two, typeof math.default].join()
+            "#,
+        )
+        .expect("the imported module should be evaluated");
+    assert_eq!(value.display().to_string(), "\"5,8,2,function\"");
+}
+
+#[test]
+fn import_circular_modules() {
+    let mut context = context_with_modules(&[
+        (
+            "a",
+            r#"
+            import { b } from "b";
+            export function a() {
+                return "a";
+            }
+            export const result = b();
+            "#,
+        ),
+        (
+            "b",
+            r#"
+            import { a } from "a";
+            export function b() {
+                return a() + "b";
+            }
+            "#,
+        ),
+    ]);
+
+    let value = context
+        .eval_module(r#"import { result } from "a"; result"#)
+        .expect("modules importing each other should be evaluated");
+    assert_eq!(value.display().to_string(), "\"ab\"");
+}
+
+#[test]
+fn export_binding_declared_later() {
+    let mut context = context_with_modules(&[("late", "export { a }; const a = 1;")]);
+
+    let value = context
+        .eval_module(r#"import { a } from "late"; a"#)
+        .expect("the binding should be exported once it is declared");
+    assert_eq!(value.display().to_string(), "1");
+}
+
+#[test]
+fn imported_bindings_are_live() {
+    let mut context = context_with_modules(&[(
+        "counter",
+        r#"
+        export let n = 0;
+        export function inc() {
+            n++;
+        }
+        "#,
+    )]);
+
+    let value = context
+        .eval_module(
+            r#"
+            import { n, inc } from "counter";
+            import * as counter from "counter";
+            const before = n;
+            inc();
+            inc();
+            [before, n, counter.n].join()
+            "#,
+        )
+        .expect("the imported bindings should be updated");
+    assert_eq!(value.display().to_string(), "\"0,2,2\"");
+}
+
+#[test]
+fn import_default_function_from_circular_module() {
+    let mut context = context_with_modules(&[
+        (
+            "a",
+            r#"
+            import { fromA } from "b";
+            export default function a() {
+                return "a";
+            }
+            export const result = fromA + "b";
+            "#,
+        ),
+        (
+            "b",
+            r#"
+            import a from "a";
+            export const fromA = a();
+            "#,
+        ),
+    ]);
+
+    let value = context
+        .eval_module(r#"import { result } from "a"; result"#)
+        .expect("the default exported function should be hoisted");
+    assert_eq!(value.display().to_string(), "\"ab\"");
+}
+
+#[test]
+fn import_errors() {
+    let mut context = context_with_modules(&[("empty", "export const x = 1;")]);
+
+    let error = context
+        .eval_module(r#"import { y } from "empty";"#)
+        .expect_err("the module has no export named `y`");
+    assert_eq!(
+        error
+            .to_string(&mut context)
+            .expect("errors can be converted to strings"),
+        "SyntaxError: module `empty` has no export named `y`"
+    );
+
+    let error = context
+        .eval_module(r#"import "missing";"#)
+        .expect_err("the module can't be found");
+    assert_eq!(
+        error
+            .to_string(&mut context)
+            .expect("errors can be converted to strings"),
+        "TypeError: cannot find module `missing`"
+    );
+}
+
+#[test]
+fn run_jobs_in_fifo_order() {
+    use crate::job::Job;
+    use std::{cell::RefCell, rc::Rc};
+
+    let mut context = Context::new();
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    for id in 1..=2 {
+        let log = log.clone();
+        context.enqueue_job(Job::new(move |context| {
+            log.borrow_mut().push(id);
+
+            // Jobs enqueued while running the queue are run after the ones already in it.
+            let log = log.clone();
+            context.enqueue_job(Job::new(move |_| {
+                log.borrow_mut().push(id * 10);
+                Ok(Value::undefined())
+            }));
+            Ok(Value::undefined())
+        }));
+    }
+    assert!(log.borrow().is_empty());
+
+    context.run_jobs().expect("jobs should not throw");
+    assert_eq!(*log.borrow(), vec![1, 2, 10, 20]);
+}
+
+#[test]
+fn job_evaluating_code_does_not_run_the_queue() {
+    use crate::job::Job;
+    use std::{cell::RefCell, rc::Rc};
+
+    let mut context = Context::new();
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    let first_log = log.clone();
+    context.enqueue_job(Job::new(move |context| {
+        context.eval("var evaluated = true")?;
+        first_log.borrow_mut().push(1);
+
+        let log = first_log.clone();
+        context.enqueue_job(Job::new(move |_| {
+            log.borrow_mut().push(3);
+            Ok(Value::undefined())
+        }));
+        Ok(Value::undefined())
+    }));
+    let second_log = log.clone();
+    context.enqueue_job(Job::new(move |_| {
+        second_log.borrow_mut().push(2);
+        Ok(Value::undefined())
+    }));
+
+    context.run_jobs().expect("jobs should not throw");
+    assert_eq!(*log.borrow(), vec![1, 2, 3]);
+    assert_eq!(forward(&mut context, "evaluated"), "true");
+}
+
+#[test]
+fn run_jobs_stops_at_first_error() {
+    use crate::job::Job;
+
+    let mut context = Context::new();
+    context.enqueue_job(Job::new(|_| Err(Value::from("first"))));
+    context.enqueue_job(Job::new(|context| context.eval("var second = true")));
+
+    assert_eq!(context.run_jobs(), Err(Value::from("first")));
+    assert_eq!(forward(&mut context, "typeof second"), "\"undefined\"");
+
+    context
+        .run_jobs()
+        .expect("the remaining job should not throw");
+    assert_eq!(forward(&mut context, "second"), "true");
+}
+
+#[test]
+fn custom_global_object() {
+    let mut context = Context::new();
+    let math = Value::from(context.global_object())
+        .get_field("Math", &mut context)
+        .expect("Math is defined");
+
+    let mut global = context.construct_object();
+    global.insert_property("Math", math, Attribute::all());
+    context.set_global_object(global);
+
+    assert_eq!(forward(&mut context, "Math.max(1, 2)"), "2");
+    assert_eq!(forward(&mut context, "typeof window"), "\"undefined\"");
+    assert_eq!(forward(&mut context, "typeof Array"), "\"undefined\"");
+
+    // Literals still get their standard prototypes.
+    assert_eq!(forward(&mut context, "[1, 2].length"), "2");
+}
+
+#[test]
+fn max_call_depth() {
+    let mut context = Context::new();
+    context.set_max_call_depth(20);
+
+    let scenario = r#"
+        function recurse() {
+            return recurse();
+        }
+
+        var result;
+        try {
+            recurse();
+        } catch (e) {
+            result = e instanceof RangeError && e.message;
+        }
+        result;
+    "#;
+    assert_eq!(
+        forward(&mut context, scenario),
+        "\"Maximum call stack size exceeded\""
+    );
+
+    // The call depth is restored once the error has been thrown.
+    let scenario = r#"
+        function countdown(n) {
+            return n === 0 ? "done" : countdown(n - 1);
+        }
+        countdown(15);
+    "#;
+    assert_eq!(forward(&mut context, scenario), "\"done\"");
+    assert_eq!(
+        forward(&mut context, "countdown(25)"),
+        "Uncaught \"RangeError\": \"Maximum call stack size exceeded\""
+    );
+}
+
+#[test]
+fn default_max_call_depth_stops_unbounded_recursion() {
+    let mut context = Context::new();
+    assert_eq!(context.max_call_depth(), Context::DEFAULT_MAX_CALL_DEPTH);
+
+    assert_eq!(
+        forward(&mut context, "function f() { return f(); } f()"),
+        "Uncaught \"RangeError\": \"Maximum call stack size exceeded\""
+    );
+}
+
+#[test]
+fn op_budget() {
+    let mut context = Context::new();
+    context.set_op_budget(Some(10_000));
+
+    assert_eq!(
+        context.eval("while (true) {}"),
+        Err(Value::from(Context::OP_BUDGET_EXHAUSTED))
+    );
+    assert!(context.is_op_budget_exhausted());
+
+    // The script cannot catch the error to carry on.
+    context.set_op_budget(Some(10_000));
+    let scenario = r#"
+        var caught = 0;
+        while (true) {
+            try {
+                while (true) {}
+            } catch (e) {
+                caught++;
+            }
+        }
+    "#;
+    assert_eq!(
+        context.eval(scenario),
+        Err(Value::from(Context::OP_BUDGET_EXHAUSTED))
+    );
+
+    context.set_op_budget(None);
+    assert!(!context.is_op_budget_exhausted());
+    assert_eq!(forward(&mut context, "caught"), "0");
+}
@@ -0,0 +1,43 @@
+//! This module implements the job queue of the [`Context`].
+//!
+//! Jobs are units of work, such as `Promise` reactions, that are run after the script that
+//! enqueued them has completed. Embedders can also enqueue their own jobs, and decide when the
+//! queue is drained with [`Context::run_jobs`].
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-jobs
+
+use crate::{Context, Result, Value};
+use std::fmt::{self, Debug};
+
+/// A job waiting in the job queue of a [`Context`].
+pub struct Job {
+    callback: Box<dyn FnOnce(&mut Context) -> Result<Value>>,
+}
+
+impl Job {
+    /// Creates a new job from the given callback.
+    ///
+    /// The callback receives the context the job is run in.
+    pub fn new<F>(callback: F) -> Self
+    where
+        F: FnOnce(&mut Context) -> Result<Value> + 'static,
+    {
+        Self {
+            callback: Box::new(callback),
+        }
+    }
+
+    /// Runs the job, consuming it.
+    pub(crate) fn run(self, context: &mut Context) -> Result<Value> {
+        (self.callback)(context)
+    }
+}
+
+impl Debug for Job {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Job")
+    }
+}
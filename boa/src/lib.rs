@@ -49,6 +49,8 @@ pub mod class;
 pub mod environment;
 pub mod exec;
 pub mod gc;
+pub mod job;
+pub mod module;
 pub mod object;
 pub mod profiler;
 pub mod property;
@@ -0,0 +1,176 @@
+//! This module implements the loading of the modules imported by module code.
+//!
+//! The embedder provides a [`ModuleLoader`] to the [`Context`], which resolves the specifier of
+//! an imported module to its source code. Each module is evaluated once, the first time it is
+//! imported, and its exports are live bindings to its environment.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+
+use crate::{
+    builtins::function::Captures,
+    environment::lexical_environment::Environment,
+    object::GcObject,
+    syntax::ast::node::{ExportDecl, Node, StatementList},
+    Context, Result, Value,
+};
+use rustc_hash::FxHashMap;
+use std::fmt::{self, Debug};
+
+/// The hook resolving the specifiers of imported modules to their source code.
+pub struct ModuleLoader {
+    resolve: Box<dyn Fn(&str) -> Option<String>>,
+}
+
+impl ModuleLoader {
+    /// Creates a new module loader from the given callback.
+    ///
+    /// The callback receives the specifier of the imported module, such as `"./math.js"`, and
+    /// returns its source code, or `None` if the module can't be found.
+    pub fn new<F>(resolve: F) -> Self
+    where
+        F: Fn(&str) -> Option<String> + 'static,
+    {
+        Self {
+            resolve: Box::new(resolve),
+        }
+    }
+
+    /// Gets the source code of the module with the given specifier.
+    pub(crate) fn load(&self, specifier: &str) -> Option<String> {
+        (self.resolve)(specifier)
+    }
+}
+
+impl Debug for ModuleLoader {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ModuleLoader")
+    }
+}
+
+/// The binding a module exports under a given name.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-source-text-module-records
+#[derive(Debug, Clone)]
+pub(crate) enum ExportEntry {
+    /// A binding of the module itself, by local name.
+    Local(Box<str>),
+
+    /// A binding re-exported from another module: `export { name } from "module";`.
+    Indirect { module: Box<str>, name: Box<str> },
+}
+
+/// A module that was imported by module code.
+///
+/// The exports of the module are known before it is evaluated, and they are read from its
+/// environment each time they are accessed, so importers always see their current values.
+#[derive(Debug, Clone)]
+pub(crate) struct ModuleRecord {
+    specifier: Box<str>,
+    namespace: GcObject,
+    environment: Environment,
+    exports: FxHashMap<Box<str>, ExportEntry>,
+    star_exports: Box<[Box<str>]>,
+}
+
+impl ModuleRecord {
+    /// Creates the record of a module from its parsed code, before it is evaluated in the given
+    /// environment.
+    pub(crate) fn new(
+        specifier: &str,
+        code: &StatementList,
+        namespace: GcObject,
+        environment: Environment,
+    ) -> Self {
+        let mut exports = FxHashMap::default();
+        let mut star_exports = Vec::new();
+        for node in code.items() {
+            let decl = match node {
+                Node::ExportDecl(decl) => decl,
+                _ => continue,
+            };
+            match decl {
+                ExportDecl::Named {
+                    specifiers,
+                    module: Some(module),
+                } => {
+                    for specifier in specifiers.iter() {
+                        let entry = ExportEntry::Indirect {
+                            module: module.clone(),
+                            name: specifier.local().into(),
+                        };
+                        exports.insert(specifier.exported().into(), entry);
+                    }
+                }
+                ExportDecl::All { module } => star_exports.push(module.clone()),
+                _ => {
+                    for (exported, local) in decl.local_exports() {
+                        exports.insert(exported.into(), ExportEntry::Local(local.into()));
+                    }
+                }
+            }
+        }
+
+        Self {
+            specifier: specifier.into(),
+            namespace,
+            environment,
+            exports,
+            star_exports: star_exports.into(),
+        }
+    }
+
+    /// Gets the specifier the module was imported with.
+    pub(crate) fn specifier(&self) -> &str {
+        &self.specifier
+    }
+
+    /// Gets the namespace object of the module, holding its exports.
+    pub(crate) fn namespace(&self) -> &GcObject {
+        &self.namespace
+    }
+
+    /// Gets the environment the module is evaluated in.
+    pub(crate) fn environment(&self) -> &Environment {
+        &self.environment
+    }
+
+    /// Gets the names exported by the module itself, not including `export *` declarations.
+    pub(crate) fn export_names(&self) -> impl Iterator<Item = &str> {
+        self.exports.keys().map(AsRef::as_ref)
+    }
+
+    /// Gets the binding the module exports as `name`, not looking into `export *` declarations.
+    pub(crate) fn export_entry(&self, name: &str) -> Option<&ExportEntry> {
+        self.exports.get(name)
+    }
+
+    /// Gets the specifiers of the modules all of whose bindings are re-exported.
+    pub(crate) fn star_exports(&self) -> &[Box<str>] {
+        &self.star_exports
+    }
+}
+
+/// The getter of an export of a module namespace object.
+///
+/// The captures are the specifier of the module and the name of the export.
+pub(crate) fn get_namespace_export(
+    _: &Value,
+    _: &[Value],
+    captures: &Captures,
+    context: &mut Context,
+) -> Result<Value> {
+    let (specifier, name) = {
+        let captures = captures.borrow();
+        (
+            captures[0].to_string(context)?,
+            captures[1].to_string(context)?,
+        )
+    };
+    context.get_module_export(&specifier, &name)
+}
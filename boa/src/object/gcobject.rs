@@ -4,8 +4,13 @@
 
 use super::{NativeObject, Object, PROTOTYPE};
 use crate::{
-    builtins::function::{
-        create_unmapped_arguments_object, BuiltInFunction, Function, NativeFunction,
+    builtins::{
+        function::{
+            create_unmapped_arguments_object, BuiltInCapturingFunction, BuiltInClosure,
+            BuiltInFunction, Captures, CapturingFunction, ClosureFunction, Function,
+            NativeFunction,
+        },
+        Proxy,
     },
     environment::{
         environment_record_trait::EnvironmentRecordTrait,
@@ -24,6 +29,7 @@ use std::{
     collections::HashMap,
     error::Error,
     fmt::{self, Debug, Display},
+    rc::Rc,
     result::Result as StdResult,
 };
 
@@ -44,7 +50,10 @@ pub struct GcObject(Gc<GcCell<Object>>);
 enum FunctionBody {
     BuiltInFunction(NativeFunction),
     BuiltInConstructor(NativeFunction),
+    BuiltInClosure(Rc<ClosureFunction>),
+    BuiltInCapturing(CapturingFunction, Captures),
     Ordinary(RcStatementList),
+    Generator,
 }
 
 impl GcObject {
@@ -111,6 +120,38 @@ impl GcObject {
 
     /// Internal implementation of [`call`](#method.call) and [`construct`](#method.construct).
     ///
+    /// Keeps track of the number of nested calls of ordinary functions, and throws a `RangeError`
+    /// once the maximum call depth of the context is reached. Built-in functions are not counted:
+    /// they can only recurse through ordinary functions, and the error itself is created by one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[track_caller]
+    fn call_construct(
+        &self,
+        this_target: &Value,
+        args: &[Value],
+        context: &mut Context,
+        construct: bool,
+    ) -> Result<Value> {
+        let is_ordinary = matches!(self.borrow().as_function(), Some(Function::Ordinary { .. }));
+        if !is_ordinary {
+            return self.call_construct_body(this_target, args, context, construct);
+        }
+
+        if context.call_depth >= context.max_call_depth() {
+            return context.throw_range_error("Maximum call stack size exceeded");
+        }
+
+        context.call_depth += 1;
+        let result = self.call_construct_body(this_target, args, context, construct);
+        context.call_depth -= 1;
+        result
+    }
+
+    /// Calls or constructs this object, without checking the call depth.
+    ///
     /// # Panics
     ///
     /// Panics if the object is currently mutably borrowed.
@@ -120,7 +161,7 @@ impl GcObject {
     /// <https://tc39.es/ecma262/#sec-runtime-semantics-evaluatebody>
     /// <https://tc39.es/ecma262/#sec-ordinarycallevaluatebody>
     #[track_caller]
-    fn call_construct(
+    fn call_construct_body(
         &self,
         this_target: &Value,
         args: &[Value],
@@ -148,6 +189,14 @@ impl GcObject {
                             FunctionBody::BuiltInFunction(*function)
                         }
                     }
+                    Function::Closure(BuiltInClosure(function), _) => {
+                        FunctionBody::BuiltInClosure(function.clone())
+                    }
+                    Function::Capturing {
+                        function: BuiltInCapturingFunction(function),
+                        captures,
+                        ..
+                    } => FunctionBody::BuiltInCapturing(*function, captures.clone()),
                     Function::Ordinary {
                         body,
                         params,
@@ -202,8 +251,8 @@ impl GcObject {
                         for param in params.iter() {
                             has_parameter_expressions =
                                 has_parameter_expressions || param.init().is_some();
-                            arguments_in_parameter_names =
-                                arguments_in_parameter_names || param.name() == "arguments";
+                            arguments_in_parameter_names = arguments_in_parameter_names
+                                || param.names().contains(&"arguments");
                         }
 
                         // An arguments object is added when all of the following conditions are met
@@ -237,23 +286,39 @@ impl GcObject {
 
                         // Add argument bindings to the function environment
                         for (i, param) in params.iter().enumerate() {
-                            // Rest Parameters
+                            let result = if param.is_rest_param() {
+                                // Rest Parameters
+                                function.add_rest_param(param, i, args, context, &local_env)
+                            } else {
+                                // The default value is used when the argument is `undefined`,
+                                // not only when it is missing. Defaults are evaluated in the
+                                // function environment, so they can refer to earlier parameters.
+                                let value = match (args.get(i), param.init()) {
+                                    (None, Some(init)) | (Some(Value::Undefined), Some(init)) => {
+                                        init.run(context)
+                                    }
+                                    (Some(value), _) => Ok(value.clone()),
+                                    (None, None) => Ok(Value::undefined()),
+                                };
+
+                                value.and_then(|value| {
+                                    function.add_arguments_to_environment(
+                                        param, value, &local_env, context,
+                                    )
+                                })
+                            };
+
+                            // Evaluating a default value or destructuring a parameter can throw,
+                            // in which case the function environment has to be removed before
+                            // leaving.
+                            if let Err(error) = result {
+                                context.pop_environment();
+                                return Err(error);
+                            }
+
                             if param.is_rest_param() {
-                                function.add_rest_param(param, i, args, context, &local_env);
                                 break;
                             }
-
-                            let value = match args.get(i).cloned() {
-                                None | Some(Value::Undefined) => param
-                                    .init()
-                                    .map(|init| init.run(context).ok())
-                                    .flatten()
-                                    .unwrap_or_default(),
-                                Some(value) => value,
-                            };
-
-                            function
-                                .add_arguments_to_environment(param, value, &local_env, context);
                         }
 
                         if has_parameter_expressions {
@@ -280,7 +345,11 @@ impl GcObject {
                             context.push_environment(second_env);
                         }
 
-                        FunctionBody::Ordinary(body.clone())
+                        if flags.is_generator() {
+                            FunctionBody::Generator
+                        } else {
+                            FunctionBody::Ordinary(body.clone())
+                        }
                     }
                 }
             }
@@ -296,6 +365,10 @@ impl GcObject {
                 function(&Value::undefined(), args, context)
             }
             FunctionBody::BuiltInFunction(function) => function(this_target, args, context),
+            FunctionBody::BuiltInClosure(function) => function(this_target, args, context),
+            FunctionBody::BuiltInCapturing(function, captures) => {
+                function(this_target, args, &captures, context)
+            }
             FunctionBody::Ordinary(body) => {
                 let result = body.run(context);
                 let this = context.get_this_binding();
@@ -311,6 +384,16 @@ impl GcObject {
                     result
                 }
             }
+            FunctionBody::Generator => {
+                if has_parameter_expressions {
+                    context.pop_environment();
+                }
+                context.pop_environment();
+
+                // TODO: Generator objects need the body to be suspended at each `yield`, which the
+                // interpreter can't do yet.
+                context.throw_type_error("generator functions are not supported yet")
+            }
         }
     }
 
@@ -757,6 +840,19 @@ impl GcObject {
         self.borrow().is_native_object()
     }
 
+    /// Gets the target and handler of the object if it is a `Proxy` object.
+    ///
+    /// The proxy is cloned out of the object, so that its traps can borrow the object.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the object is currently mutably borrowed.
+    #[inline]
+    #[track_caller]
+    pub(crate) fn as_proxy(&self) -> Option<Proxy> {
+        self.borrow().as_proxy().cloned()
+    }
+
     /// Retrieves value of specific property, when the value of the property is expected to be a function.
     ///
     /// More information:
@@ -56,22 +56,31 @@ impl GcObject {
         true
     }
 
-    /// Delete property.
+    /// `[[Delete]]`
+    /// <https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-delete-p>
     #[inline]
-    pub fn delete(&mut self, key: &PropertyKey) -> bool {
+    pub fn delete(&mut self, key: &PropertyKey, context: &mut Context) -> Result<bool> {
+        if let Some(proxy) = self.as_proxy() {
+            return proxy.delete(key, context);
+        }
+
         match self.get_own_property(key) {
             Some(desc) if desc.configurable() => {
                 self.remove(&key);
-                true
+                Ok(true)
             }
-            Some(_) => false,
-            None => true,
+            Some(_) => Ok(false),
+            None => Ok(true),
         }
     }
 
     /// `[[Get]]`
     /// <https://tc39.es/ecma262/#sec-ordinary-object-internal-methods-and-internal-slots-get-p-receiver>
     pub fn get(&self, key: &PropertyKey, receiver: Value, context: &mut Context) -> Result<Value> {
+        if let Some(proxy) = self.as_proxy() {
+            return proxy.get(key, receiver, context);
+        }
+
         match self.get_own_property(key) {
             None => {
                 // parent will either be null or an Object
@@ -102,6 +111,10 @@ impl GcObject {
     ) -> Result<bool> {
         let _timer = BoaProfiler::global().start_event("Object::set", "object");
 
+        if let Some(proxy) = self.as_proxy() {
+            return proxy.set(key, val, receiver, context);
+        }
+
         // Fetch property key
         let own_desc = if let Some(desc) = self.get_own_property(&key) {
             desc
@@ -166,6 +179,13 @@ impl GcObject {
     where
         K: Into<PropertyKey>,
     {
+        if let Some(proxy) = self.as_proxy() {
+            return proxy
+                .target()
+                .clone()
+                .define_own_property(key, desc, context);
+        }
+
         if self.is_array() {
             self.array_define_own_property(key, desc, context)
         } else {
@@ -340,7 +360,7 @@ impl GcObject {
                             keys
                         };
                         for key in keys_to_delete.into_iter().rev() {
-                            if !self.delete(&key.into()) {
+                            if !self.delete(&key.into(), context)? {
                                 let mut new_len_desc_attribute = new_len_desc.attributes();
                                 if !new_writable {
                                     new_len_desc_attribute.set_writable(false);
@@ -399,6 +419,7 @@ impl GcObject {
         let object = self.borrow();
         match object.data {
             ObjectData::String(_) => self.string_exotic_get_own_property(key),
+            ObjectData::Proxy(ref proxy) => proxy.target().get_own_property(key),
             _ => self.ordinary_get_own_property(key),
         }
     }
@@ -484,6 +505,10 @@ impl GcObject {
     #[inline]
     #[track_caller]
     pub fn own_property_keys(&self) -> Vec<PropertyKey> {
+        if let Some(proxy) = self.as_proxy() {
+            return proxy.target().own_property_keys();
+        }
+
         self.borrow().keys().collect()
     }
 
@@ -567,6 +592,10 @@ impl GcObject {
     #[inline]
     #[track_caller]
     pub fn get_prototype_of(&self) -> Value {
+        if let Some(proxy) = self.as_proxy() {
+            return proxy.target().get_prototype_of();
+        }
+
         self.borrow().prototype.clone()
     }
 
@@ -6,9 +6,13 @@ use crate::{
         function::{BuiltInFunction, Function, FunctionFlags, NativeFunction},
         map::map_iterator::MapIterator,
         map::ordered_map::OrderedMap,
+        promise::Promise,
+        proxy::Proxy,
+        regexp::regexp_string_iterator::RegExpStringIterator,
         set::ordered_set::OrderedSet,
         set::set_iterator::SetIterator,
         string::string_iterator::StringIterator,
+        weak_map::weak_table::{WeakKeyTableRef, WeakTable},
         BigInt, Date, RegExp,
     },
     context::StandardConstructor,
@@ -63,7 +67,7 @@ impl<T: Any + Debug + Trace> NativeObject for T {
 }
 
 /// The internal representation of an JavaScript object.
-#[derive(Debug, Trace, Finalize)]
+#[derive(Debug, Trace)]
 pub struct Object {
     /// The type of the object.
     pub data: ObjectData,
@@ -76,6 +80,21 @@ pub struct Object {
     prototype: Value,
     /// Whether it can have new properties added to it.
     extensible: bool,
+    /// The tables of the `WeakMap` and `WeakSet` objects this object is a key of.
+    #[unsafe_ignore_trace]
+    weak_key_tables: Vec<WeakKeyTableRef>,
+}
+
+impl Finalize for Object {
+    fn finalize(&self) {
+        // Remove the entries this object is the key of, before its address can be reused.
+        let address = self as *const Self as usize;
+        for (_, table) in &self.weak_key_tables {
+            if let Some(table) = table.upgrade() {
+                table.remove_key(address);
+            }
+        }
+    }
 }
 
 /// Defines the different types of objects.
@@ -86,12 +105,17 @@ pub enum ObjectData {
     Map(OrderedMap<Value, Value>),
     MapIterator(MapIterator),
     RegExp(Box<RegExp>),
+    RegExpStringIterator(RegExpStringIterator),
     BigInt(RcBigInt),
     Boolean(bool),
     ForInIterator(ForInIterator),
     Function(Function),
+    Promise(Promise),
+    Proxy(Proxy),
     Set(OrderedSet<Value>),
     SetIterator(SetIterator),
+    WeakMap(WeakTable<Value>),
+    WeakSet(WeakTable<()>),
     String(RcString),
     StringIterator(StringIterator),
     Number(f64),
@@ -113,11 +137,16 @@ impl Display for ObjectData {
                 Self::ArrayIterator(_) => "ArrayIterator",
                 Self::ForInIterator(_) => "ForInIterator",
                 Self::Function(_) => "Function",
+                Self::Promise(_) => "Promise",
+                Self::Proxy(_) => "Proxy",
                 Self::RegExp(_) => "RegExp",
+                Self::RegExpStringIterator(_) => "RegExpStringIterator",
                 Self::Map(_) => "Map",
                 Self::MapIterator(_) => "MapIterator",
                 Self::Set(_) => "Set",
                 Self::SetIterator(_) => "SetIterator",
+                Self::WeakMap(_) => "WeakMap",
+                Self::WeakSet(_) => "WeakSet",
                 Self::String(_) => "String",
                 Self::StringIterator(_) => "StringIterator",
                 Self::Symbol(_) => "Symbol",
@@ -145,6 +174,7 @@ impl Default for Object {
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_key_tables: Vec::new(),
         }
     }
 }
@@ -167,6 +197,7 @@ impl Object {
             symbol_properties: FxHashMap::default(),
             prototype,
             extensible: true,
+            weak_key_tables: Vec::new(),
         }
     }
 
@@ -194,6 +225,7 @@ impl Object {
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_key_tables: Vec::new(),
         }
     }
 
@@ -207,6 +239,7 @@ impl Object {
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_key_tables: Vec::new(),
         }
     }
 
@@ -223,6 +256,7 @@ impl Object {
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_key_tables: Vec::new(),
         }
     }
 
@@ -236,6 +270,7 @@ impl Object {
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_key_tables: Vec::new(),
         }
     }
 
@@ -252,6 +287,7 @@ impl Object {
             symbol_properties: FxHashMap::default(),
             prototype: Value::null(),
             extensible: true,
+            weak_key_tables: Vec::new(),
         }
     }
 
@@ -321,6 +357,22 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_regexp_string_iterator(&self) -> Option<&RegExpStringIterator> {
+        match &self.data {
+            ObjectData::RegExpStringIterator(iter) => Some(iter),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_regexp_string_iterator_mut(&mut self) -> Option<&mut RegExpStringIterator> {
+        match &mut self.data {
+            ObjectData::RegExpStringIterator(iter) => Some(iter),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn as_for_in_iterator(&self) -> Option<&ForInIterator> {
         match &self.data {
@@ -367,6 +419,36 @@ impl Object {
         }
     }
 
+    /// Checks if it a `Promise` object.
+    #[inline]
+    pub fn is_promise(&self) -> bool {
+        matches!(self.data, ObjectData::Promise(_))
+    }
+
+    #[inline]
+    pub fn as_promise(&self) -> Option<&Promise> {
+        match self.data {
+            ObjectData::Promise(ref promise) => Some(promise),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_promise_mut(&mut self) -> Option<&mut Promise> {
+        match &mut self.data {
+            ObjectData::Promise(promise) => Some(promise),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_proxy(&self) -> Option<&Proxy> {
+        match self.data {
+            ObjectData::Proxy(ref proxy) => Some(proxy),
+            _ => None,
+        }
+    }
+
     #[inline]
     pub fn is_set(&self) -> bool {
         matches!(self.data, ObjectData::Set(_))
@@ -396,6 +478,36 @@ impl Object {
         }
     }
 
+    #[inline]
+    pub fn as_weak_map(&self) -> Option<&WeakTable<Value>> {
+        match self.data {
+            ObjectData::WeakMap(ref map) => Some(map),
+            _ => None,
+        }
+    }
+
+    #[inline]
+    pub fn as_weak_set(&self) -> Option<&WeakTable<()>> {
+        match self.data {
+            ObjectData::WeakSet(ref set) => Some(set),
+            _ => None,
+        }
+    }
+
+    /// Registers a table of a `WeakMap` or `WeakSet` that this object is a key of, so that the
+    /// entries of this object are removed from it when it is finalized.
+    pub(crate) fn add_weak_key_table(&mut self, table: WeakKeyTableRef) {
+        self.weak_key_tables
+            .retain(|(_, table)| table.strong_count() > 0);
+        if self
+            .weak_key_tables
+            .iter()
+            .all(|(address, _)| *address != table.0)
+        {
+            self.weak_key_tables.push(table);
+        }
+    }
+
     /// Checks if it a `String` object.
     #[inline]
     pub fn is_string(&self) -> bool {
@@ -24,25 +24,25 @@ pub struct Realm {
 }
 
 impl Realm {
-    #[allow(clippy::field_reassign_with_default)]
     pub fn create() -> Self {
         let _timer = BoaProfiler::global().start_event("Realm::create", "realm");
         // Create brand new global object
         // Global has no prototype to pass None to new_obj
-        let mut global = Object::default();
+        Self::with_global_object(GcObject::new(Object::default()))
+    }
 
+    /// Creates a realm whose global object is `global`.
+    pub fn with_global_object(global: GcObject) -> Self {
         // Allow identification of the global object easily
-        global.data = ObjectData::Global;
-
-        let gc_global = GcObject::new(global);
+        global.borrow_mut().data = ObjectData::Global;
 
         // We need to clone the global here because its referenced from separate places (only pointer is cloned)
-        let global_env = GlobalEnvironmentRecord::new(gc_global.clone(), gc_global.clone());
+        let global_env = GlobalEnvironmentRecord::new(global.clone(), global.clone());
 
         Self {
-            global_object: gc_global.clone(),
+            global_object: global.clone(),
             global_env: Gc::new(global_env),
-            environment: LexicalEnvironment::new(gc_global),
+            environment: LexicalEnvironment::new(global),
         }
     }
 }
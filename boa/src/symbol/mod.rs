@@ -21,8 +21,9 @@ use crate::{
     gc::{Finalize, Trace},
     value::RcString,
 };
+use rustc_hash::FxHashMap;
 use std::{
-    cell::Cell,
+    cell::{Cell, RefCell},
     hash::{Hash, Hasher},
 };
 
@@ -69,6 +70,11 @@ thread_local! {
     ///
     /// For now this is an incremented u64 number.
     static SYMBOL_HASH_COUNT: Cell<u64> = Cell::new(RESERVED_SYMBOL_HASHES);
+
+    /// The global symbol registry, used by `Symbol.for` and `Symbol.keyFor`.
+    ///
+    /// The symbols are registered by key, which is also their description.
+    static GLOBAL_SYMBOL_REGISTRY: RefCell<FxHashMap<RcString, RcSymbol>> = RefCell::default();
 }
 
 impl WellKnownSymbols {
@@ -1,6 +1,8 @@
+use super::GLOBAL_SYMBOL_REGISTRY;
 use crate::{
     gc::{empty_trace, Finalize, Trace},
     symbol::Symbol,
+    value::RcString,
 };
 
 use std::{
@@ -16,6 +18,32 @@ unsafe impl Trace for RcSymbol {
     empty_trace!();
 }
 
+impl RcSymbol {
+    /// Gets the symbol of the global symbol registry with the given key, registering a new symbol
+    /// if there is none yet.
+    pub(crate) fn for_key(key: RcString) -> Self {
+        GLOBAL_SYMBOL_REGISTRY.with(|registry| {
+            registry
+                .borrow_mut()
+                .entry(key.clone())
+                .or_insert_with(|| Symbol::new(Some(key)).into())
+                .clone()
+        })
+    }
+
+    /// Gets the key of the symbol in the global symbol registry, if it is registered.
+    pub(crate) fn registry_key(&self) -> Option<RcString> {
+        let key = self.description.as_ref()?;
+        GLOBAL_SYMBOL_REGISTRY.with(|registry| {
+            registry
+                .borrow()
+                .get(key)
+                .filter(|symbol| *symbol == self)
+                .map(|_| key.clone())
+        })
+    }
+}
+
 impl Display for RcSymbol {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self.0.description() {
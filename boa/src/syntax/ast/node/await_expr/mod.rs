@@ -32,9 +32,14 @@ impl Executable for AwaitExpr {
 }
 
 impl AwaitExpr {
+    /// Gets the expression of the await expression.
+    pub fn expr(&self) -> &Node {
+        &self.expr
+    }
+
     /// Implements the display formatting with indentation.
     pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
-        writeln!(f, "await ")?;
+        write!(f, "await ")?;
         self.expr.display(f, indentation)
     }
 }
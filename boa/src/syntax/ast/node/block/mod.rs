@@ -29,11 +29,11 @@ use serde::{Deserialize, Serialize};
 /// [spec]: https://tc39.es/ecma262/#prod-BlockStatement
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/block
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[cfg_attr(feature = "deser", serde(transparent))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
 pub struct Block {
     #[cfg_attr(feature = "deser", serde(flatten))]
     statements: StatementList,
+    label: Option<Box<str>>,
 }
 
 impl Block {
@@ -42,8 +42,27 @@ impl Block {
         self.statements.items()
     }
 
+    /// Gets the statement list of this block, which holds the spans of its statements if the block
+    /// was parsed.
+    pub fn statements(&self) -> &StatementList {
+        &self.statements
+    }
+
+    /// Gets the label of this block, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_ref().map(Box::as_ref)
+    }
+
+    /// Sets the label of this block.
+    pub fn set_label(&mut self, label: Box<str>) {
+        self.label = Some(label);
+    }
+
     /// Implements the display formatting with indentation.
     pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
+        if let Some(label) = self.label() {
+            write!(f, "{}: ", label)?;
+        }
         writeln!(f, "{{")?;
         self.statements.display(f, indentation + 1)?;
         write!(f, "{}}}", "    ".repeat(indentation))
@@ -74,8 +93,14 @@ impl Executable for Block {
                     // Early return.
                     break;
                 }
-                InterpreterState::Break(_label) => {
-                    // TODO, break to a label.
+                InterpreterState::Break(label) => {
+                    // A labelled break targeting this block completes it normally, any other
+                    // break is propagated to the enclosing statement.
+                    if label.is_some() && label.as_deref() == self.label() {
+                        context
+                            .executor()
+                            .set_current_state(InterpreterState::Executing);
+                    }
 
                     // Early break.
                     break;
@@ -88,7 +113,10 @@ impl Executable for Block {
                     // Continue execution
                 }
                 #[cfg(feature = "vm")]
-                InterpreterState::Error => {}
+                InterpreterState::Error => {
+                    // Leave the state as is, so that the enclosing statements stop as well.
+                    break;
+                }
             }
         }
 
@@ -106,6 +134,7 @@ where
     fn from(list: T) -> Self {
         Self {
             statements: list.into(),
+            label: None,
         }
     }
 }
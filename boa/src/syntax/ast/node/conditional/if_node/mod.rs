@@ -70,6 +70,9 @@ impl If {
         match self.else_node() {
             Some(else_e) => {
                 self.body().display(f, indent)?;
+                if !self.body().ends_with_block() {
+                    f.write_str(";")?;
+                }
                 f.write_str(" else ")?;
                 else_e.display(f, indent)
             }
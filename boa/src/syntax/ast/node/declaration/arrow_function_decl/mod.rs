@@ -61,8 +61,9 @@ impl ArrowFunctionDecl {
     ) -> fmt::Result {
         write!(f, "(")?;
         join_nodes(f, &self.params)?;
-        f.write_str(") => ")?;
-        self.body.display(f, indentation)
+        writeln!(f, ") => {{")?;
+        self.body.display(f, indentation + 1)?;
+        write!(f, "{}}}", "    ".repeat(indentation))
     }
 }
 
@@ -71,9 +72,8 @@ impl Executable for ArrowFunctionDecl {
         context.create_function(
             self.params().to_vec(),
             self.body().to_vec(),
-            FunctionFlags::CALLABLE
-                | FunctionFlags::CONSTRUCTABLE
-                | FunctionFlags::LEXICAL_THIS_MODE,
+            // Arrow functions are not constructors and take `this` from the enclosing scope.
+            FunctionFlags::CALLABLE | FunctionFlags::LEXICAL_THIS_MODE,
         )
     }
 }
@@ -72,11 +72,11 @@ impl AsyncFunctionDecl {
             }
         }
         join_nodes(f, &self.parameters)?;
-        f.write_str(") {{")?;
+        writeln!(f, ") {{")?;
 
         self.body.display(f, indentation + 1)?;
 
-        writeln!(f, "}}")
+        write!(f, "{}}}", "    ".repeat(indentation))
     }
 }
 
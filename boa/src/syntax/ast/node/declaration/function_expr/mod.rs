@@ -74,13 +74,23 @@ impl FunctionExpr {
         if let Some(ref name) = self.name {
             write!(f, " {}", name)?;
         }
+        self.display_parameters_and_body(f, indentation)
+    }
+
+    /// Implements the display formatting with indentation of the parameters and the body of the
+    /// function, which are shared with the method definitions of object literals.
+    pub(in crate::syntax::ast::node) fn display_parameters_and_body(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
         f.write_str("(")?;
         join_nodes(f, &self.parameters)?;
-        f.write_str(") {{")?;
+        writeln!(f, ") {{")?;
 
         self.body.display(f, indentation + 1)?;
 
-        writeln!(f, "}}")
+        write!(f, "{}}}", "    ".repeat(indentation))
     }
 }
 
@@ -1,8 +1,13 @@
 //! Declaration nodes
 use crate::{
+    builtins::{
+        iterable::{self, IteratorRecord},
+        Array,
+    },
     environment::lexical_environment::VariableScope,
     exec::Executable,
     gc::{Finalize, Trace},
+    property::{Attribute, DataDescriptor, PropertyKey},
     syntax::ast::node::{join_nodes, Identifier, Node},
     Context, Result, Value,
 };
@@ -16,11 +21,13 @@ pub mod async_function_decl;
 pub mod async_function_expr;
 pub mod function_decl;
 pub mod function_expr;
+pub mod generator_decl;
+pub mod generator_expr;
 
 pub use self::{
     arrow_function_decl::ArrowFunctionDecl, async_function_decl::AsyncFunctionDecl,
     async_function_expr::AsyncFunctionExpr, function_decl::FunctionDecl,
-    function_expr::FunctionExpr,
+    function_expr::FunctionExpr, generator_decl::GeneratorDecl, generator_expr::GeneratorExpr,
 };
 
 #[cfg(test)]
@@ -92,41 +99,23 @@ pub enum DeclarationList {
 impl Executable for DeclarationList {
     fn run(&self, context: &mut Context) -> Result<Value> {
         for decl in self.as_ref() {
-            use DeclarationList::*;
-            let val = match decl.init() {
-                None if self.is_const() => {
-                    return context.throw_syntax_error("missing = in const declaration")
+            match &decl {
+                Declaration::Identifier { ident, init } => {
+                    let val = match init {
+                        None if self.is_const() => {
+                            return context.throw_syntax_error("missing = in const declaration")
+                        }
+                        Some(init) => Some(init.run(context)?),
+                        None => None,
+                    };
+                    self.declare(ident.as_ref(), val, context)?;
                 }
-                Some(init) => init.run(context)?,
-                None => Value::undefined(),
-            };
-
-            if self.is_var() && context.has_binding(decl.name()) {
-                if decl.init().is_some() {
-                    context.set_mutable_binding(decl.name(), val, true)?;
+                Declaration::Pattern(pattern) => {
+                    for (ident, val) in pattern.run(None, context)? {
+                        self.declare(ident.as_ref(), Some(val), context)?;
+                    }
                 }
-                continue;
             }
-
-            match &self {
-                Const(_) => context.create_immutable_binding(
-                    decl.name().to_owned(),
-                    false,
-                    VariableScope::Block,
-                )?,
-                Let(_) => context.create_mutable_binding(
-                    decl.name().to_owned(),
-                    false,
-                    VariableScope::Block,
-                )?,
-                Var(_) => context.create_mutable_binding(
-                    decl.name().to_owned(),
-                    false,
-                    VariableScope::Function,
-                )?,
-            }
-
-            context.initialize_binding(decl.name(), val)?;
         }
 
         Ok(Value::undefined())
@@ -134,6 +123,32 @@ impl Executable for DeclarationList {
 }
 
 impl DeclarationList {
+    /// Creates the binding for a declared variable, and initializes it.
+    ///
+    /// A `var` declaration of an existing binding only assigns the value, if there is one.
+    fn declare(&self, name: &str, val: Option<Value>, context: &mut Context) -> Result<()> {
+        if self.is_var() && context.has_binding(name) {
+            if let Some(val) = val {
+                context.set_mutable_binding(name, val, true)?;
+            }
+            return Ok(());
+        }
+
+        match &self {
+            Self::Const(_) => {
+                context.create_immutable_binding(name.to_owned(), false, VariableScope::Block)?
+            }
+            Self::Let(_) => {
+                context.create_mutable_binding(name.to_owned(), false, VariableScope::Block)?
+            }
+            Self::Var(_) => {
+                context.create_mutable_binding(name.to_owned(), false, VariableScope::Function)?
+            }
+        }
+
+        context.initialize_binding(name, val.unwrap_or_default())
+    }
+
     #[allow(dead_code)]
     pub(in crate::syntax) fn is_let(&self) -> bool {
         matches!(self, Self::Let(_))
@@ -191,16 +206,29 @@ impl From<Declaration> for Box<[Declaration]> {
 /// Individual declaration.
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, Trace, Finalize, PartialEq)]
-pub struct Declaration {
-    name: Identifier,
-    init: Option<Node>,
+pub enum Declaration {
+    /// A declaration of a single variable, such as `a = 1`.
+    Identifier {
+        ident: Identifier,
+        init: Option<Node>,
+    },
+
+    /// A declaration binding the variables of a destructuring pattern, such as `{ a, b } = obj`.
+    Pattern(DeclarationPattern),
 }
 
 impl fmt::Display for Declaration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        fmt::Display::fmt(&self.name, f)?;
-        if let Some(ref init) = self.init {
-            write!(f, " = {}", init)?;
+        match &self {
+            Self::Identifier { ident, init } => {
+                fmt::Display::fmt(&ident, f)?;
+                if let Some(init) = init {
+                    write!(f, " = {}", init)?;
+                }
+            }
+            Self::Pattern(pattern) => {
+                fmt::Display::fmt(&pattern, f)?;
+            }
         }
         Ok(())
     }
@@ -213,19 +241,596 @@ impl Declaration {
         N: Into<Identifier>,
         I: Into<Option<Node>>,
     {
-        Self {
-            name: name.into(),
+        Self::Identifier {
+            ident: name.into(),
             init: init.into(),
         }
     }
 
-    /// Gets the name of the variable.
+    /// Creates a new variable declaration with an object binding pattern.
+    pub(in crate::syntax) fn new_with_object_pattern<I>(
+        bindings: Vec<BindingPatternTypeObject>,
+        init: I,
+    ) -> Self
+    where
+        I: Into<Option<Node>>,
+    {
+        Self::Pattern(DeclarationPattern::Object(DeclarationPatternObject::new(
+            bindings,
+            init.into(),
+        )))
+    }
+
+    /// Creates a new variable declaration with an array binding pattern.
+    pub(in crate::syntax) fn new_with_array_pattern<I>(
+        bindings: Vec<BindingPatternTypeArray>,
+        init: I,
+    ) -> Self
+    where
+        I: Into<Option<Node>>,
+    {
+        Self::Pattern(DeclarationPattern::Array(DeclarationPatternArray::new(
+            bindings,
+            init.into(),
+        )))
+    }
+
+    /// Gets the name of the variable declared by an identifier declaration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if this is a destructuring declaration, whose bindings are given by
+    /// [`Declaration::pattern`].
     pub fn name(&self) -> &str {
-        self.name.as_ref()
+        match &self {
+            Self::Identifier { ident, .. } => ident.as_ref(),
+            Self::Pattern(_) => panic!("destructuring declarations don't have a single name"),
+        }
+    }
+
+    /// Gets the destructuring pattern of the declaration, if it has one.
+    pub fn pattern(&self) -> Option<&DeclarationPattern> {
+        match &self {
+            Self::Identifier { .. } => None,
+            Self::Pattern(pattern) => Some(pattern),
+        }
+    }
+
+    /// Gets the initialization node for the declaration, if any.
+    pub fn init(&self) -> Option<&Node> {
+        match &self {
+            Self::Identifier { init, .. } => init.as_ref(),
+            Self::Pattern(pattern) => pattern.init(),
+        }
+    }
+
+    /// Gets the names of all the variables bound by this declaration.
+    pub fn idents(&self) -> Vec<&str> {
+        match &self {
+            Self::Identifier { ident, .. } => vec![ident.as_ref()],
+            Self::Pattern(pattern) => pattern.idents(),
+        }
+    }
+}
+
+/// A destructuring pattern in a declaration.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-BindingPattern
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Destructuring_assignment
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum DeclarationPattern {
+    Object(DeclarationPatternObject),
+    Array(DeclarationPatternArray),
+}
+
+impl fmt::Display for DeclarationPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            Self::Object(pattern) => fmt::Display::fmt(&pattern, f),
+            Self::Array(pattern) => fmt::Display::fmt(&pattern, f),
+        }
+    }
+}
+
+impl DeclarationPattern {
+    /// Destructures the given value, or the value of the initializer if `init` is `None`.
+    ///
+    /// Returns the name and the value of every binding of the pattern, in order.
+    pub(crate) fn run(
+        &self,
+        init: Option<Value>,
+        context: &mut Context,
+    ) -> Result<Vec<(Box<str>, Value)>> {
+        match &self {
+            Self::Object(pattern) => pattern.run(init, context),
+            Self::Array(pattern) => pattern.run(init, context),
+        }
+    }
+
+    /// Gets the names of all the variables bound by this pattern.
+    pub fn idents(&self) -> Vec<&str> {
+        match &self {
+            Self::Object(pattern) => pattern.idents(),
+            Self::Array(pattern) => pattern.idents(),
+        }
+    }
+
+    /// Gets the initialization node of the pattern, if any.
+    pub fn init(&self) -> Option<&Node> {
+        match &self {
+            Self::Object(pattern) => pattern.init(),
+            Self::Array(pattern) => pattern.init(),
+        }
+    }
+}
+
+/// An object binding pattern, such as `{ a, b: c, d = 1 }`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct DeclarationPatternObject {
+    bindings: Vec<BindingPatternTypeObject>,
+    init: Option<Node>,
+}
+
+impl fmt::Display for DeclarationPatternObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        for (i, binding) in self.bindings.iter().enumerate() {
+            if i == 0 {
+                write!(f, " {}", binding)?;
+            } else {
+                write!(f, ", {}", binding)?;
+            }
+        }
+        f.write_str(" }")?;
+        if let Some(ref init) = self.init {
+            write!(f, " = {}", init)?;
+        }
+        Ok(())
+    }
+}
+
+impl DeclarationPatternObject {
+    /// Creates a new object binding pattern.
+    pub(in crate::syntax) fn new(
+        bindings: Vec<BindingPatternTypeObject>,
+        init: Option<Node>,
+    ) -> Self {
+        Self { bindings, init }
+    }
+
+    /// Gets the bindings of the pattern.
+    pub fn bindings(&self) -> &[BindingPatternTypeObject] {
+        &self.bindings
+    }
+
+    /// Gets the initialization node of the pattern, if any.
+    pub fn init(&self) -> Option<&Node> {
+        self.init.as_ref()
+    }
+
+    /// Destructures the given value, or the value of the initializer if `init` is `None`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-bindinginitialization
+    pub(crate) fn run(
+        &self,
+        init: Option<Value>,
+        context: &mut Context,
+    ) -> Result<Vec<(Box<str>, Value)>> {
+        let value = match init {
+            Some(value) => value,
+            None => match self.init() {
+                Some(init) => init.run(context)?,
+                None => Value::undefined(),
+            },
+        };
+
+        if value.is_null_or_undefined() {
+            context.throw_type_error(format!("cannot destructure {}", value.display()))?;
+        }
+        let value: Value = value.to_object(context)?.into();
+
+        let mut results = Vec::new();
+        for binding in &self.bindings {
+            match binding {
+                BindingPatternTypeObject::SingleName {
+                    ident,
+                    property_name,
+                    default_init,
+                } => {
+                    let mut property = value.get_field(property_name.as_ref(), context)?;
+                    if let (true, Some(default_init)) = (property.is_undefined(), default_init) {
+                        property = default_init.run(context)?;
+                    }
+                    results.push((ident.clone(), property));
+                }
+                BindingPatternTypeObject::RestProperty {
+                    ident,
+                    excluded_keys,
+                } => {
+                    let source = value.to_object(context)?;
+                    let rest = Value::new_object(context);
+                    for key in source.own_property_keys() {
+                        let excluded = match key {
+                            PropertyKey::String(ref name) => {
+                                excluded_keys.iter().any(|k| k.as_ref() == &**name)
+                            }
+                            PropertyKey::Index(index) => excluded_keys
+                                .iter()
+                                .any(|k| k.parse::<u32>().ok() == Some(index)),
+                            PropertyKey::Symbol(_) => false,
+                        };
+                        if excluded {
+                            continue;
+                        }
+                        if let Some(desc) = source.get_own_property(&key) {
+                            if desc.enumerable() {
+                                let property = source.get(&key, source.clone().into(), context)?;
+                                rest.set_property(
+                                    key,
+                                    DataDescriptor::new(property, Attribute::all()),
+                                );
+                            }
+                        }
+                    }
+                    results.push((ident.clone(), rest));
+                }
+                BindingPatternTypeObject::BindingPattern {
+                    property_name,
+                    pattern,
+                    default_init,
+                } => {
+                    let mut property = value.get_field(property_name.as_ref(), context)?;
+                    if let (true, Some(default_init)) = (property.is_undefined(), default_init) {
+                        property = default_init.run(context)?;
+                    }
+                    results.append(&mut pattern.run(Some(property), context)?);
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Gets the names of all the variables bound by this pattern.
+    pub fn idents(&self) -> Vec<&str> {
+        let mut idents = Vec::new();
+        for binding in &self.bindings {
+            match binding {
+                BindingPatternTypeObject::SingleName { ident, .. }
+                | BindingPatternTypeObject::RestProperty { ident, .. } => {
+                    idents.push(ident.as_ref())
+                }
+                BindingPatternTypeObject::BindingPattern { pattern, .. } => {
+                    idents.append(&mut pattern.idents())
+                }
+            }
+        }
+        idents
+    }
+}
+
+/// A single binding of an object binding pattern.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum BindingPatternTypeObject {
+    /// A property bound to a variable, such as `a`, `b: c` or `d = 1`.
+    SingleName {
+        ident: Box<str>,
+        property_name: Box<str>,
+        default_init: Option<Node>,
+    },
+
+    /// The remaining own enumerable properties, such as `...rest`.
+    RestProperty {
+        ident: Box<str>,
+        excluded_keys: Vec<Box<str>>,
+    },
+
+    /// A property destructured by a nested pattern, such as `a: { b }`.
+    BindingPattern {
+        property_name: Box<str>,
+        pattern: DeclarationPattern,
+        default_init: Option<Node>,
+    },
+}
+
+impl fmt::Display for BindingPatternTypeObject {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            Self::SingleName {
+                ident,
+                property_name,
+                default_init,
+            } => {
+                if ident == property_name {
+                    write!(f, "{}", ident)?;
+                } else {
+                    write!(f, "{}: {}", property_name, ident)?;
+                }
+                if let Some(init) = default_init {
+                    write!(f, " = {}", init)?;
+                }
+            }
+            Self::RestProperty { ident, .. } => {
+                write!(f, "...{}", ident)?;
+            }
+            Self::BindingPattern {
+                property_name,
+                pattern,
+                default_init,
+            } => {
+                write!(f, "{}: {}", property_name, pattern)?;
+                if let Some(init) = default_init {
+                    write!(f, " = {}", init)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// An array binding pattern, such as `[a, , b = 1, ...rest]`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct DeclarationPatternArray {
+    bindings: Vec<BindingPatternTypeArray>,
+    init: Option<Node>,
+}
+
+impl fmt::Display for DeclarationPatternArray {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for (i, binding) in self.bindings.iter().enumerate() {
+            if i != 0 {
+                f.write_str(",")?;
+            }
+            match binding {
+                BindingPatternTypeArray::Elision => {}
+                _ if i == 0 => write!(f, "{}", binding)?,
+                _ => write!(f, " {}", binding)?,
+            }
+        }
+        if let Some(BindingPatternTypeArray::Elision) = self.bindings.last() {
+            f.write_str(",")?;
+        }
+        f.write_str("]")?;
+        if let Some(ref init) = self.init {
+            write!(f, " = {}", init)?;
+        }
+        Ok(())
+    }
+}
+
+impl DeclarationPatternArray {
+    /// Creates a new array binding pattern.
+    pub(in crate::syntax) fn new(
+        bindings: Vec<BindingPatternTypeArray>,
+        init: Option<Node>,
+    ) -> Self {
+        Self { bindings, init }
+    }
+
+    /// Gets the bindings of the pattern.
+    pub fn bindings(&self) -> &[BindingPatternTypeArray] {
+        &self.bindings
     }
 
-    /// Gets the initialization node for the variable, if any.
+    /// Gets the initialization node of the pattern, if any.
     pub fn init(&self) -> Option<&Node> {
         self.init.as_ref()
     }
+
+    /// Destructures the given value, or the value of the initializer if `init` is `None`.
+    ///
+    /// The values are pulled from the value using the iterator protocol, and the iterator is
+    /// closed if it is not exhausted by the pattern.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#sec-runtime-semantics-iteratorbindinginitialization
+    pub(crate) fn run(
+        &self,
+        init: Option<Value>,
+        context: &mut Context,
+    ) -> Result<Vec<(Box<str>, Value)>> {
+        let value = match init {
+            Some(value) => value,
+            None => match self.init() {
+                Some(init) => init.run(context)?,
+                None => Value::undefined(),
+            },
+        };
+
+        let iterator = iterable::get_iterator(context, value)?;
+        let mut done = false;
+
+        match self.bind_elements(&iterator, &mut done, context) {
+            Ok(results) => {
+                if !done {
+                    iterator.close(Ok(Value::undefined()), context)?;
+                }
+                Ok(results)
+            }
+            // Closing an iterator with an error completion always returns that error.
+            Err(e) if !done => iterator.close(Err(e), context).map(|_| Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Binds every element of the pattern to the values of the iterator.
+    ///
+    /// `done` is set once the iterator is exhausted, or when it threw an error.
+    fn bind_elements(
+        &self,
+        iterator: &IteratorRecord,
+        done: &mut bool,
+        context: &mut Context,
+    ) -> Result<Vec<(Box<str>, Value)>> {
+        let mut results = Vec::new();
+        for binding in &self.bindings {
+            match binding {
+                BindingPatternTypeArray::Elision => {
+                    step(iterator, done, context)?;
+                }
+                BindingPatternTypeArray::SingleName {
+                    ident,
+                    default_init,
+                } => {
+                    let mut value = step(iterator, done, context)?;
+                    if let (true, Some(default_init)) = (value.is_undefined(), default_init) {
+                        value = default_init.run(context)?;
+                    }
+                    results.push((ident.clone(), value));
+                }
+                BindingPatternTypeArray::BindingPattern {
+                    pattern,
+                    default_init,
+                } => {
+                    let mut value = step(iterator, done, context)?;
+                    if let (true, Some(default_init)) = (value.is_undefined(), default_init) {
+                        value = default_init.run(context)?;
+                    }
+                    results.append(&mut pattern.run(Some(value), context)?);
+                }
+                BindingPatternTypeArray::SingleNameRest { ident } => {
+                    let rest = collect_rest(iterator, done, context)?;
+                    results.push((ident.clone(), rest));
+                }
+                BindingPatternTypeArray::BindingPatternRest { pattern } => {
+                    let rest = collect_rest(iterator, done, context)?;
+                    results.append(&mut pattern.run(Some(rest), context)?);
+                }
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Gets the names of all the variables bound by this pattern.
+    pub fn idents(&self) -> Vec<&str> {
+        let mut idents = Vec::new();
+        for binding in &self.bindings {
+            match binding {
+                BindingPatternTypeArray::Elision => {}
+                BindingPatternTypeArray::SingleName { ident, .. }
+                | BindingPatternTypeArray::SingleNameRest { ident } => idents.push(ident.as_ref()),
+                BindingPatternTypeArray::BindingPattern { pattern, .. }
+                | BindingPatternTypeArray::BindingPatternRest { pattern } => {
+                    idents.append(&mut pattern.idents())
+                }
+            }
+        }
+        idents
+    }
+}
+
+/// Gets the next value of the iterator, or `undefined` if the iterator is done.
+fn step(iterator: &IteratorRecord, done: &mut bool, context: &mut Context) -> Result<Value> {
+    if *done {
+        return Ok(Value::undefined());
+    }
+
+    // If `next` throws, the iterator must not be closed.
+    *done = true;
+    let next = iterator.next(context)?;
+    if next.is_done() {
+        Ok(Value::undefined())
+    } else {
+        *done = false;
+        Ok(next.value())
+    }
+}
+
+/// Collects the remaining values of the iterator into a new array.
+fn collect_rest(
+    iterator: &IteratorRecord,
+    done: &mut bool,
+    context: &mut Context,
+) -> Result<Value> {
+    let mut values = Vec::new();
+    while !*done {
+        let value = step(iterator, done, context)?;
+        if !*done {
+            values.push(value);
+        }
+    }
+
+    let array = Array::new_array(context);
+    Array::add_to_array_object(&array, &values, context)?;
+    Ok(array)
+}
+
+/// A single element of an array binding pattern.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum BindingPatternTypeArray {
+    /// A hole in the pattern, which skips a value, such as the empty element in `[a, , b]`.
+    Elision,
+
+    /// A value bound to a variable, such as `a` or `b = 1`.
+    SingleName {
+        ident: Box<str>,
+        default_init: Option<Node>,
+    },
+
+    /// A value destructured by a nested pattern, such as `[a, b]` in `[[a, b], c]`.
+    BindingPattern {
+        pattern: DeclarationPattern,
+        default_init: Option<Node>,
+    },
+
+    /// The remaining values bound to a variable, such as `...rest`.
+    SingleNameRest { ident: Box<str> },
+
+    /// The remaining values destructured by a nested pattern, such as `...[a, b]`.
+    BindingPatternRest { pattern: DeclarationPattern },
+}
+
+impl fmt::Display for BindingPatternTypeArray {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self {
+            Self::Elision => {}
+            Self::SingleName {
+                ident,
+                default_init,
+            } => {
+                write!(f, "{}", ident)?;
+                if let Some(init) = default_init {
+                    write!(f, " = {}", init)?;
+                }
+            }
+            Self::BindingPattern {
+                pattern,
+                default_init,
+            } => {
+                write!(f, "{}", pattern)?;
+                if let Some(init) = default_init {
+                    write!(f, " = {}", init)?;
+                }
+            }
+            Self::SingleNameRest { ident } => write!(f, "...{}", ident)?,
+            Self::BindingPatternRest { pattern } => write!(f, "...{}", pattern)?,
+        }
+        Ok(())
+    }
 }
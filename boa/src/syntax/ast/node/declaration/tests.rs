@@ -10,3 +10,149 @@ fn duplicate_function_name() {
 
     assert_eq!(&exec(scenario), "12");
 }
+
+#[test]
+fn object_destructuring() {
+    let scenario = r#"
+        const obj = { a: 1, b: 2 };
+        const { a, b: c, d = 3 } = obj;
+        [a, c, d];
+    "#;
+
+    assert_eq!(&exec(scenario), "[ 1, 2, 3 ]");
+}
+
+#[test]
+fn object_destructuring_default_only_for_undefined() {
+    let scenario = r#"
+        let { a = 1, b = 2, c = 3 } = { a: null, b: undefined };
+        [a, b, c];
+    "#;
+
+    assert_eq!(&exec(scenario), "[ null, 2, 3 ]");
+}
+
+#[test]
+fn nested_object_destructuring() {
+    let scenario = r#"
+        const o = { a: { b: 1 } };
+        const { a: { b }, x: { y } = { y: 2 } } = o;
+        [b, y];
+    "#;
+
+    assert_eq!(&exec(scenario), "[ 1, 2 ]");
+}
+
+#[test]
+fn object_destructuring_rest() {
+    let scenario = r#"
+        var { a, ...rest } = { a: 1, b: 2, c: 3 };
+        [a, rest.a, rest.b, rest.c];
+    "#;
+
+    assert_eq!(&exec(scenario), "[ 1, undefined, 2, 3 ]");
+}
+
+#[test]
+fn object_destructuring_primitive() {
+    let scenario = r#"
+        const { toFixed } = 1;
+        typeof toFixed;
+    "#;
+
+    assert_eq!(&exec(scenario), "\"function\"");
+}
+
+#[test]
+fn object_destructuring_null_throws() {
+    let scenario = r#"
+        try {
+            const { a } = null;
+        } catch (e) {
+            e instanceof TypeError;
+        }
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn object_destructuring_in_for_of() {
+    let scenario = r#"
+        let sum = 0;
+        for (const { x, y } of [{ x: 1, y: 2 }, { x: 3, y: 4 }]) {
+            sum += x * y;
+        }
+        sum;
+    "#;
+
+    assert_eq!(&exec(scenario), "14");
+}
+
+#[test]
+fn array_destructuring_holes() {
+    let scenario = r#"
+        const [a, , b] = [1, 2, 3];
+        a + " " + b;
+    "#;
+
+    assert_eq!(&exec(scenario), r#""1 3""#);
+}
+
+#[test]
+fn array_destructuring_defaults() {
+    let scenario = r#"
+        let [a = 1, b = 2, c = 3] = [10, undefined, null];
+        a + " " + b + " " + c;
+    "#;
+
+    assert_eq!(&exec(scenario), r#""10 2 null""#);
+}
+
+#[test]
+fn array_destructuring_rest() {
+    let scenario = r#"
+        var [first, ...rest] = [1, 2, 3];
+        rest;
+    "#;
+
+    assert_eq!(&exec(scenario), "[ 2, 3 ]");
+}
+
+#[test]
+fn array_destructuring_rest_exhausted() {
+    let scenario = r#"
+        const [a, b, ...rest] = [1];
+        typeof b + " " + rest.length;
+    "#;
+
+    assert_eq!(&exec(scenario), r#""undefined 0""#);
+}
+
+#[test]
+fn array_destructuring_iterable() {
+    let scenario = r#"
+        const [a, [b, c], { d }] = ["xy", "zw", { d: 4 }];
+        a + b + c + d;
+    "#;
+
+    assert_eq!(&exec(scenario), r#""xyzw4""#);
+}
+
+#[test]
+fn array_destructuring_closes_iterator() {
+    let scenario = r#"
+        let closed = false;
+        let iterable = {};
+        iterable[Symbol.iterator] = function() {
+            return {
+                next: function() { return { value: 1, done: false }; },
+                return: function() { closed = true; return {}; }
+            };
+        };
+        const [a] = iterable;
+        closed;
+    "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
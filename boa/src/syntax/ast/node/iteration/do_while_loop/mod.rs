@@ -64,9 +64,12 @@ impl DoWhileLoop {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
-        write!(f, "do")?;
+        if let Some(label) = self.label() {
+            write!(f, "{}: ", label)?;
+        }
+        write!(f, "do ")?;
         self.body().display(f, indentation)?;
-        write!(f, "while ({})", self.cond())
+        write!(f, " while ({})", self.cond())
     }
 }
 
@@ -90,7 +93,10 @@ impl Executable for DoWhileLoop {
                     // Continue execution.
                 }
                 #[cfg(feature = "vm")]
-                InterpreterState::Error => {}
+                InterpreterState::Error => {
+                    // Leave the state as is, so that the enclosing statements stop as well.
+                    break;
+                }
             }
             if !self.cond().run(context)?.to_boolean() {
                 break;
@@ -6,7 +6,7 @@ use crate::{
     },
     exec::{Executable, InterpreterState},
     gc::{Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::node::{Declaration, Node},
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -59,9 +59,11 @@ impl ForInLoop {
     }
 
     pub fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
-        write!(f, "for ({} in {}) {{", self.variable, self.expr,)?;
-        self.body().display(f, indentation + 1)?;
-        f.write_str("}")
+        if let Some(label) = self.label() {
+            write!(f, "{}: ", label)?;
+        }
+        write!(f, "for ({} in {}) ", self.variable, self.expr)?;
+        self.body().display(f, indentation)
     }
 }
 
@@ -77,122 +79,170 @@ impl From<ForInLoop> for Node {
     }
 }
 
-impl Executable for ForInLoop {
-    fn run(&self, context: &mut Context) -> Result<Value> {
-        let _timer = BoaProfiler::global().start_event("ForIn", "exec");
-        let object = self.expr().run(context)?;
-        let mut result = Value::undefined();
-
-        if object.is_null_or_undefined() {
-            return Ok(result);
-        }
-        let object = object.to_object(context)?;
-        let for_in_iterator = ForInIterator::create_for_in_iterator(context, Value::from(object));
-        let next_function = for_in_iterator
-            .get_property("next")
-            .map(|p| p.as_data_descriptor().unwrap().value())
-            .ok_or_else(|| context.construct_type_error("Could not find property `next`"))?;
-        let iterator = IteratorRecord::new(for_in_iterator, next_function);
-
-        loop {
-            {
-                let env = context.get_current_environment();
-                context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
-            }
-            let iterator_result = iterator.next(context)?;
-            if iterator_result.is_done() {
-                context.pop_environment();
-                break;
+impl ForInLoop {
+    /// Binds the value produced by the iterator to the loop variable, in the environment of the
+    /// current iteration.
+    fn bind_variable(&self, next_result: Value, context: &mut Context) -> Result<()> {
+        match self.variable() {
+            Node::Identifier(ref name) => {
+                if context.has_binding(name.as_ref()) {
+                    // Binding already exists
+                    context.set_mutable_binding(name.as_ref(), next_result, true)?;
+                } else {
+                    context.create_mutable_binding(
+                        name.as_ref().to_owned(),
+                        true,
+                        VariableScope::Function,
+                    )?;
+                    context.initialize_binding(name.as_ref(), next_result)?;
+                }
             }
-            let next_result = iterator_result.value();
-
-            match self.variable() {
-                Node::Identifier(ref name) => {
-                    if context.has_binding(name.as_ref()) {
-                        // Binding already exists
-                        context.set_mutable_binding(name.as_ref(), next_result.clone(), true)?;
-                    } else {
-                        context.create_mutable_binding(
-                            name.as_ref().to_owned(),
-                            true,
-                            VariableScope::Function,
+            Node::VarDeclList(ref list) => match list.as_ref() {
+                [var] => {
+                    if var.init().is_some() {
+                        context.throw_syntax_error(
+                            "a declaration in the head of a for-in loop can't have an initializer",
                         )?;
-                        context.initialize_binding(name.as_ref(), next_result.clone())?;
                     }
-                }
-                Node::VarDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
+
+                    let bindings = match var {
+                        Declaration::Identifier { ident, .. } => {
+                            vec![(ident.as_ref().into(), next_result)]
                         }
+                        Declaration::Pattern(pattern) => pattern.run(Some(next_result), context)?,
+                    };
 
-                        if context.has_binding(var.name()) {
-                            context.set_mutable_binding(var.name(), next_result, true)?;
+                    for (name, value) in bindings {
+                        if context.has_binding(&name) {
+                            context.set_mutable_binding(&name, value, true)?;
                         } else {
                             context.create_mutable_binding(
-                                var.name().to_owned(),
+                                name.to_string(),
                                 false,
                                 VariableScope::Function,
                             )?;
-                            context.initialize_binding(var.name(), next_result)?;
+                            context.initialize_binding(&name, value)?;
                         }
                     }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-in loop",
-                        )
+                }
+                _ => {
+                    context.throw_syntax_error(
+                        "only one variable can be declared in the head of a for-in loop",
+                    )?;
+                }
+            },
+            Node::LetDeclList(ref list) => match list.as_ref() {
+                [var] => {
+                    if var.init().is_some() {
+                        context.throw_syntax_error(
+                            "a declaration in the head of a for-in loop can't have an initializer",
+                        )?;
                     }
-                },
-                Node::LetDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
+
+                    let bindings = match var {
+                        Declaration::Identifier { ident, .. } => {
+                            vec![(ident.as_ref().into(), next_result)]
                         }
+                        Declaration::Pattern(pattern) => pattern.run(Some(next_result), context)?,
+                    };
 
+                    for (name, value) in bindings {
                         context.create_mutable_binding(
-                            var.name().to_owned(),
+                            name.to_string(),
                             false,
                             VariableScope::Block,
                         )?;
-                        context.initialize_binding(var.name(), next_result)?;
+
+                        context.initialize_binding(&name, value)?;
                     }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-in loop",
-                        )
+                }
+                _ => {
+                    context.throw_syntax_error(
+                        "only one variable can be declared in the head of a for-in loop",
+                    )?;
+                }
+            },
+            Node::ConstDeclList(ref list) => match list.as_ref() {
+                [var] => {
+                    if var.init().is_some() {
+                        context.throw_syntax_error(
+                            "a declaration in the head of a for-in loop can't have an initializer",
+                        )?;
                     }
-                },
-                Node::ConstDeclList(ref list) => match list.as_ref() {
-                    [var] => {
-                        if var.init().is_some() {
-                            return context.throw_syntax_error("a declaration in the head of a for-in loop can't have an initializer");
+
+                    let bindings = match var {
+                        Declaration::Identifier { ident, .. } => {
+                            vec![(ident.as_ref().into(), next_result)]
                         }
+                        Declaration::Pattern(pattern) => pattern.run(Some(next_result), context)?,
+                    };
 
+                    for (name, value) in bindings {
                         context.create_immutable_binding(
-                            var.name().to_owned(),
+                            name.to_string(),
                             false,
                             VariableScope::Block,
                         )?;
-                        context.initialize_binding(var.name(), next_result)?;
-                    }
-                    _ => {
-                        return context.throw_syntax_error(
-                            "only one variable can be declared in the head of a for-in loop",
-                        )
+                        context.initialize_binding(&name, value)?;
                     }
-                },
-                Node::Assign(_) => {
-                    return context.throw_syntax_error(
-                        "a declaration in the head of a for-in loop can't have an initializer",
-                    );
                 }
                 _ => {
-                    return context
-                        .throw_syntax_error("unknown left hand side in head of for-in loop")
+                    context.throw_syntax_error(
+                        "only one variable can be declared in the head of a for-in loop",
+                    )?;
                 }
+            },
+            Node::Assign(_) => {
+                context.throw_syntax_error(
+                    "a declaration in the head of a for-in loop can't have an initializer",
+                )?;
+            }
+            _ => {
+                context.throw_syntax_error("unknown left hand side in head of for-in loop")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Executable for ForInLoop {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("ForIn", "exec");
+        let object = self.expr().run(context)?;
+        let mut result = Value::undefined();
+
+        if object.is_null_or_undefined() {
+            return Ok(result);
+        }
+        let object = object.to_object(context)?;
+        let for_in_iterator = ForInIterator::create_for_in_iterator(context, Value::from(object));
+        let next_function = for_in_iterator
+            .get_property("next")
+            .map(|p| p.as_data_descriptor().unwrap().value())
+            .ok_or_else(|| context.construct_type_error("Could not find property `next`"))?;
+        let iterator = IteratorRecord::new(for_in_iterator, next_function);
+
+        loop {
+            let iterator_result = iterator.next(context)?;
+            if iterator_result.is_done() {
+                break;
             }
+            let next_result = iterator_result.value();
+
+            {
+                let env = context.get_current_environment();
+                context.push_environment(DeclarativeEnvironmentRecord::new(Some(env)));
+            }
+
+            // The environment of the iteration is popped before handling the state of the
+            // interpreter, so that leaving the loop for an outer label doesn't leak it.
+            let iteration_result = self
+                .bind_variable(next_result, context)
+                .and_then(|_| self.body().run(context));
+            let _ = context.pop_environment();
+            result = iteration_result?;
 
-            result = self.body().run(context)?;
             match context.executor().get_current_state() {
                 InterpreterState::Break(label) => {
                     handle_state_with_labels!(self, label, context, break);
@@ -206,9 +256,11 @@ impl Executable for ForInLoop {
                     // Continue execution.
                 }
                 #[cfg(feature = "vm")]
-                InterpreterState::Error => {}
+                InterpreterState::Error => {
+                    // Leave the state as is, so that the enclosing statements stop as well.
+                    break;
+                }
             }
-            let _ = context.pop_environment();
         }
         Ok(result)
     }
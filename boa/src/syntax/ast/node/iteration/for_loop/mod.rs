@@ -69,23 +69,24 @@ impl ForLoop {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
+        if let Some(label) = self.label() {
+            write!(f, "{}: ", label)?;
+        }
         f.write_str("for (")?;
         if let Some(init) = self.init() {
             fmt::Display::fmt(init, f)?;
         }
         f.write_str(";")?;
         if let Some(condition) = self.condition() {
-            fmt::Display::fmt(condition, f)?;
+            write!(f, " {}", condition)?;
         }
         f.write_str(";")?;
         if let Some(final_expr) = self.final_expr() {
-            fmt::Display::fmt(final_expr, f)?;
+            write!(f, " {}", final_expr)?;
         }
-        writeln!(f, ") {{")?;
-
-        self.inner.body().display(f, indentation + 1)?;
+        f.write_str(") ")?;
 
-        write!(f, "}}")
+        self.inner.body().display(f, indentation)
     }
 
     pub fn label(&self) -> Option<&str> {
@@ -134,7 +135,10 @@ impl Executable for ForLoop {
                     // Continue execution.
                 }
                 #[cfg(feature = "vm")]
-                InterpreterState::Error => {}
+                InterpreterState::Error => {
+                    // Leave the state as is, so that the enclosing statements stop as well.
+                    break;
+                }
             }
 
             if let Some(final_expr) = self.final_expr() {
@@ -354,6 +354,139 @@ fn for_loop_continue_label() {
     assert_eq!(&exec(scenario), "10");
 }
 
+#[test]
+fn while_loop_break_label() {
+    let scenario = r#"
+        var str = "";
+
+        outer: while (true) {
+            var i = 0;
+            while (true) {
+                if (i === 2) {
+                    break outer;
+                }
+                str = str + i;
+                i++;
+            }
+            str = str + "unreachable";
+        }
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"01\"");
+}
+
+#[test]
+fn while_loop_continue_label() {
+    let scenario = r#"
+        var str = "";
+        var i = 0;
+
+        outer: while (i < 3) {
+            i++;
+            while (true) {
+                str = str + i;
+                continue outer;
+            }
+        }
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"123\"");
+}
+
+#[test]
+fn do_while_loop_break_label() {
+    let scenario = r#"
+        var count = 0;
+
+        outer: do {
+            do {
+                count++;
+                break outer;
+            } while (true);
+        } while (true);
+        count
+    "#;
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn do_while_loop_continue_label() {
+    let scenario = r#"
+        var str = "";
+        var i = 0;
+
+        outer: do {
+            i++;
+            do {
+                str = str + i;
+                continue outer;
+            } while (true);
+        } while (i < 3);
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"123\"");
+}
+
+#[test]
+fn for_of_loop_break_label() {
+    let scenario = r#"
+        var str = "";
+        outer: for (let i of [1, 2, 3]) {
+            for (let j of [1, 2, 3]) {
+                if (j === 2) {
+                    break outer;
+                }
+                str = str + i + j;
+            }
+        }
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"11\"");
+}
+
+#[test]
+fn for_of_loop_closes_iterator_on_break() {
+    let scenario = r#"
+        var closed = false;
+        var iterable = {};
+        iterable[Symbol.iterator] = function() {
+            var i = 0;
+            return {
+                next: function() { i++; return { value: i, done: false }; },
+                return: function() { closed = true; return {}; }
+            };
+        };
+        for (let x of iterable) {
+            if (x === 3) {
+                break;
+            }
+        }
+        closed
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn for_of_loop_does_not_close_exhausted_iterator() {
+    let scenario = r#"
+        var closed = false;
+        var iterable = {};
+        iterable[Symbol.iterator] = function() {
+            var i = 0;
+            return {
+                next: function() { i++; return { value: i, done: i > 3 }; },
+                return: function() { closed = true; return {}; }
+            };
+        };
+        var sum = 0;
+        for (let x of iterable) {
+            sum += x;
+        }
+        closed ? -1 : sum
+    "#;
+    assert_eq!(&exec(scenario), "6");
+}
+
 #[test]
 fn for_in_declaration() {
     let mut context = Context::new();
@@ -502,3 +635,121 @@ fn for_in_continue_label() {
     "#;
     assert_eq!(&exec(scenario), "\"00\"")
 }
+
+#[test]
+fn for_in_continue_outer_label_from_nested_loops() {
+    let scenario = r#"
+        var str = "";
+
+        outer: for (let i in [1, 2, 3]) {
+            inner: for (let j in [1, 2, 3]) {
+                if (j === "1") {
+                    continue outer;
+                }
+                if (j === i) {
+                    continue inner;
+                }
+                str = str + i + j;
+            }
+            str = str + "!";
+        }
+        str
+    "#;
+    assert_eq!(&exec(scenario), "\"1020\"")
+}
+
+#[test]
+fn for_in_continue_outer_label_restores_environment() {
+    let scenario = r#"
+        function kind() {
+            return typeof after;
+        }
+
+        outer: for (var i = 0; i < 2; i++) {
+            inner: for (let key in { a: 1, b: 2 }) {
+                continue outer;
+            }
+        }
+
+        let after = 1;
+        kind();
+    "#;
+    assert_eq!(&exec(scenario), "\"number\"")
+}
+
+#[test]
+fn for_in_throw_in_body_stops_loop() {
+    let scenario = r#"
+        let count = 0;
+        let message = "";
+        try {
+            for (let key in { a: 1, b: 2, c: 3 }) {
+                count++;
+                throw new Error("stop at " + key);
+            }
+        } catch (e) {
+            message = e.message;
+        }
+        count + " " + message;
+    "#;
+    assert_eq!(&exec(scenario), "\"1 stop at a\"")
+}
+
+#[test]
+fn for_in_skips_keys_deleted_during_iteration() {
+    let scenario = r#"
+        let obj = { a: 1, b: 2, c: 3 };
+        let visited = 0;
+        for (let key in obj) {
+            visited++;
+            for (let other of ["a", "b", "c"]) {
+                if (other !== key) {
+                    delete obj[other];
+                }
+            }
+        }
+        visited
+    "#;
+    assert_eq!(&exec(scenario), "1")
+}
+
+#[test]
+fn for_in_inherited_properties() {
+    let scenario = r#"
+        let proto = { a: 1, b: 2 };
+        let obj = Object.create(proto);
+        obj.c = 3;
+        Object.defineProperty(obj, "b", { value: 4, enumerable: false });
+        Object.defineProperty(proto, "d", { value: 5, enumerable: false });
+        let keys = [];
+        for (let key in obj) {
+            keys.push(key);
+        }
+        keys.sort().join()
+    "#;
+    assert_eq!(&exec(scenario), "\"a,c\"")
+}
+
+#[test]
+fn for_in_let_fresh_binding_per_iteration() {
+    let scenario = r#"
+        let closures = [];
+        for (let key in { a: 1, b: 2, c: 3 }) {
+            closures.push(() => key);
+        }
+        closures.map(f => f()).sort().join()
+    "#;
+    assert_eq!(&exec(scenario), "\"a,b,c\"")
+}
+
+#[test]
+fn for_in_const_fresh_binding_per_iteration() {
+    let scenario = r#"
+        let closures = [];
+        for (const key in { a: 1, b: 2, c: 3 }) {
+            closures.push(function () { return key; });
+        }
+        closures.map(f => f()).sort().join()
+    "#;
+    assert_eq!(&exec(scenario), "\"a,b,c\"")
+}
@@ -63,6 +63,9 @@ impl WhileLoop {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
+        if let Some(label) = self.label() {
+            write!(f, "{}: ", label)?;
+        }
         write!(f, "while ({}) ", self.cond())?;
         self.expr().display(f, indentation)
     }
@@ -88,7 +91,10 @@ impl Executable for WhileLoop {
                     // Continue execution.
                 }
                 #[cfg(feature = "vm")]
-                InterpreterState::Error => {}
+                InterpreterState::Error => {
+                    // Leave the state as is, so that the enclosing statements stop as well.
+                    break;
+                }
             }
         }
         Ok(result)
@@ -10,9 +10,11 @@ pub mod declaration;
 pub mod field;
 pub mod identifier;
 pub mod iteration;
+pub mod module;
 pub mod new;
 pub mod object;
 pub mod operator;
+pub mod optional;
 pub mod return_smt;
 pub mod spread;
 pub mod statement_list;
@@ -20,6 +22,7 @@ pub mod switch;
 pub mod template;
 pub mod throw;
 pub mod try_node;
+pub mod r#yield;
 
 pub use self::{
     array::ArrayDecl,
@@ -29,15 +32,20 @@ pub use self::{
     call::Call,
     conditional::{ConditionalOp, If},
     declaration::{
-        ArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, Declaration, DeclarationList,
-        FunctionDecl, FunctionExpr,
+        ArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, BindingPatternTypeArray,
+        BindingPatternTypeObject, Declaration, DeclarationList, DeclarationPattern,
+        DeclarationPatternArray, DeclarationPatternObject, FunctionDecl, FunctionExpr,
+        GeneratorDecl, GeneratorExpr,
     },
     field::{GetConstField, GetField},
     identifier::Identifier,
     iteration::{Continue, DoWhileLoop, ForInLoop, ForLoop, ForOfLoop, WhileLoop},
+    module::{ExportDecl, ExportSpecifier, ImportDecl, ImportSpecifier},
     new::New,
     object::Object,
     operator::{Assign, BinOp, UnaryOp},
+    optional::{Optional, OptionalOperation, OptionalOperationKind},
+    r#yield::Yield,
     return_smt::Return,
     spread::Spread,
     statement_list::{RcStatementList, StatementList},
@@ -117,12 +125,21 @@ pub enum Node {
     /// A do ... while statement. [More information](./iteration/struct.DoWhileLoop.html).
     DoWhileLoop(DoWhileLoop),
 
+    /// An `export` declaration. [More information](./module/enum.ExportDecl.html).
+    ExportDecl(ExportDecl),
+
     /// A function declaration node. [More information](./declaration/struct.FunctionDecl.html).
     FunctionDecl(FunctionDecl),
 
     /// A function expression node. [More information](./declaration/struct.FunctionExpr.html).
     FunctionExpr(FunctionExpr),
 
+    /// A generator function declaration node. [More information](./declaration/struct.GeneratorDecl.html).
+    GeneratorDecl(GeneratorDecl),
+
+    /// A generator function expression node. [More information](./declaration/struct.GeneratorExpr.html).
+    GeneratorExpr(GeneratorExpr),
+
     /// Provides access to an object types' constant properties. [More information](./declaration/struct.GetConstField.html).
     GetConstField(GetConstField),
 
@@ -147,12 +164,29 @@ pub enum Node {
     /// A local identifier node. [More information](./identifier/struct.Identifier.html).
     Identifier(Identifier),
 
+    /// An `import` declaration. [More information](./module/struct.ImportDecl.html).
+    ImportDecl(ImportDecl),
+
+    /// The `import.meta` meta property, which holds host-defined information about the current
+    /// module.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///  - [MDN documentation][mdn]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-ImportMeta
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import.meta
+    ImportMeta,
+
     /// A `new` expression. [More information](./expression/struct.New.html).
     New(New),
 
     /// An object. [More information](./object/struct.Object.html).
     Object(Object),
 
+    /// An optional chain. [More information](./optional/struct.Optional.html).
+    Optional(Optional),
+
     /// A return statement. [More information](./object/struct.Return.html).
     Return(Return),
 
@@ -197,6 +231,9 @@ pub enum Node {
     /// A 'while {...}' node. [More information](./iteration/struct.WhileLoop.html).
     WhileLoop(WhileLoop),
 
+    /// A `yield` or `yield*` expression. [More information](./yield/struct.Yield.html).
+    Yield(Yield),
+
     /// A empty node.
     ///
     /// Empty statement do nothing, just return undefined.
@@ -224,29 +261,100 @@ impl From<Const> for Node {
 
 impl Node {
     /// Returns a node ordering based on the hoistability of each node.
+    ///
+    /// Function declarations, exported or not, come first, then `import` declarations, so that
+    /// modules importing each other can already use the functions they export.
     pub(crate) fn hoistable_order(a: &Node, b: &Node) -> Ordering {
-        match (a, b) {
-            (Node::FunctionDecl(_), Node::FunctionDecl(_)) => Ordering::Equal,
-            (_, Node::FunctionDecl(_)) => Ordering::Greater,
-            (Node::FunctionDecl(_), _) => Ordering::Less,
+        let rank = |node: &Node| match node {
+            Node::FunctionDecl(_) | Node::GeneratorDecl(_) => 0,
+            Node::ExportDecl(ExportDecl::Declaration(decl))
+            | Node::ExportDecl(ExportDecl::Default(decl))
+                if matches!(**decl, Node::FunctionDecl(_) | Node::GeneratorDecl(_)) =>
+            {
+                0
+            }
+            Node::ImportDecl(_) => 1,
+            _ => 2,
+        };
+        rank(a).cmp(&rank(b))
+    }
 
-            (_, _) => Ordering::Equal,
+    /// Gets the name of the function declared by the node, if it is a named function declaration.
+    pub(crate) fn function_name(&self) -> Option<&str> {
+        match self {
+            Self::FunctionDecl(decl) => Some(decl.name()),
+            Self::GeneratorDecl(decl) => Some(decl.name()),
+            Self::AsyncFunctionDecl(decl) => decl.name(),
+            _ => None,
         }
     }
 
+    /// Returns `true` if the node is an expression, rather than a statement or a declaration.
+    pub(crate) fn is_expression(&self) -> bool {
+        matches!(
+            self,
+            Self::ArrayDecl(_)
+                | Self::ArrowFunctionDecl(_)
+                | Self::Assign(_)
+                | Self::AsyncFunctionExpr(_)
+                | Self::AwaitExpr(_)
+                | Self::BinOp(_)
+                | Self::Call(_)
+                | Self::ConditionalOp(_)
+                | Self::Const(_)
+                | Self::FunctionExpr(_)
+                | Self::GeneratorExpr(_)
+                | Self::GetConstField(_)
+                | Self::GetField(_)
+                | Self::Identifier(_)
+                | Self::ImportMeta
+                | Self::New(_)
+                | Self::Object(_)
+                | Self::Optional(_)
+                | Self::Spread(_)
+                | Self::TaggedTemplate(_)
+                | Self::TemplateLit(_)
+                | Self::This
+                | Self::UnaryOp(_)
+                | Self::Yield(_)
+        )
+    }
+
     /// Creates a `This` AST node.
     pub fn this() -> Self {
         Self::This
     }
 
-    /// Implements the display formatting with indentation.
-    fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
-        let indent = "    ".repeat(indentation);
-        match *self {
-            Self::Block(_) => {}
-            _ => write!(f, "{}", indent)?,
+    /// Checks if the formatting of a statement ends with a closing brace, in which case it doesn't
+    /// need to be terminated with a semicolon.
+    fn ends_with_block(&self) -> bool {
+        match self {
+            Self::Block(_)
+            | Self::Switch(_)
+            | Self::Try(_)
+            | Self::FunctionDecl(_)
+            | Self::GeneratorDecl(_)
+            | Self::AsyncFunctionDecl(_)
+            | Self::Empty => true,
+            Self::If(if_smt) => if_smt
+                .else_node()
+                .unwrap_or_else(|| if_smt.body())
+                .ends_with_block(),
+            Self::ExportDecl(ExportDecl::Declaration(decl))
+            | Self::ExportDecl(ExportDecl::Default(decl)) => decl.ends_with_block(),
+            Self::WhileLoop(while_loop) => while_loop.expr().ends_with_block(),
+            Self::ForLoop(for_loop) => for_loop.body().ends_with_block(),
+            Self::ForInLoop(for_in_loop) => for_in_loop.body().ends_with_block(),
+            Self::ForOfLoop(for_of_loop) => for_of_loop.body().ends_with_block(),
+            _ => false,
         }
+    }
 
+    /// Implements the display formatting with indentation.
+    ///
+    /// The node is written starting at the current position, and the lines following its first
+    /// one are indented `indentation` times.
+    fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         match *self {
             Self::Call(ref expr) => Display::fmt(expr, f),
             Self::Const(ref c) => write!(f, "{}", c),
@@ -261,6 +369,9 @@ impl Node {
             Self::Spread(ref spread) => Display::fmt(spread, f),
             Self::Block(ref block) => block.display(f, indentation),
             Self::Identifier(ref s) => Display::fmt(s, f),
+            Self::ImportDecl(ref decl) => Display::fmt(decl, f),
+            Self::ImportMeta => write!(f, "import.meta"),
+            Self::ExportDecl(ref decl) => decl.display(f, indentation),
             Self::New(ref expr) => Display::fmt(expr, f),
             Self::GetConstField(ref get_const_field) => Display::fmt(get_const_field, f),
             Self::GetField(ref get_field) => Display::fmt(get_field, f),
@@ -269,10 +380,13 @@ impl Node {
             Self::If(ref if_smt) => if_smt.display(f, indentation),
             Self::Switch(ref switch) => switch.display(f, indentation),
             Self::Object(ref obj) => obj.display(f, indentation),
+            Self::Optional(ref optional) => Display::fmt(optional, f),
             Self::ArrayDecl(ref arr) => Display::fmt(arr, f),
             Self::VarDeclList(ref list) => Display::fmt(list, f),
             Self::FunctionDecl(ref decl) => decl.display(f, indentation),
             Self::FunctionExpr(ref expr) => expr.display(f, indentation),
+            Self::GeneratorDecl(ref decl) => decl.display(f, indentation),
+            Self::GeneratorExpr(ref expr) => expr.display(f, indentation),
             Self::ArrowFunctionDecl(ref decl) => decl.display(f, indentation),
             Self::BinOp(ref op) => Display::fmt(op, f),
             Self::UnaryOp(ref op) => Display::fmt(op, f),
@@ -286,6 +400,7 @@ impl Node {
             Self::AsyncFunctionDecl(ref decl) => decl.display(f, indentation),
             Self::AsyncFunctionExpr(ref expr) => expr.display(f, indentation),
             Self::AwaitExpr(ref expr) => expr.display(f, indentation),
+            Self::Yield(ref y) => Display::fmt(y, f),
             Self::Empty => write!(f, ";"),
         }
     }
@@ -294,6 +409,8 @@ impl Node {
 impl Executable for Node {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("Executable", "exec");
+        context.consume_op()?;
+
         match *self {
             Node::AsyncFunctionDecl(ref decl) => decl.run(context),
             Node::AsyncFunctionExpr(ref function_expr) => function_expr.run(context),
@@ -311,6 +428,9 @@ impl Executable for Node {
             Node::Const(Const::Bool(value)) => Ok(Value::boolean(value)),
             Node::Block(ref block) => block.run(context),
             Node::Identifier(ref identifier) => identifier.run(context),
+            Node::ImportDecl(ref decl) => decl.run(context),
+            Node::ImportMeta => Ok(context.import_meta().into()),
+            Node::ExportDecl(ref decl) => decl.run(context),
             Node::GetConstField(ref get_const_field_node) => get_const_field_node.run(context),
             Node::GetField(ref get_field) => get_field.run(context),
             Node::WhileLoop(ref while_loop) => while_loop.run(context),
@@ -322,11 +442,14 @@ impl Executable for Node {
             Node::ConditionalOp(ref op) => op.run(context),
             Node::Switch(ref switch) => switch.run(context),
             Node::Object(ref obj) => obj.run(context),
+            Node::Optional(ref optional) => optional.run(context),
             Node::ArrayDecl(ref arr) => arr.run(context),
             // <https://tc39.es/ecma262/#sec-createdynamicfunction>
             Node::FunctionDecl(ref decl) => decl.run(context),
             // <https://tc39.es/ecma262/#sec-createdynamicfunction>
             Node::FunctionExpr(ref function_expr) => function_expr.run(context),
+            Node::GeneratorDecl(ref decl) => decl.run(context),
+            Node::GeneratorExpr(ref expr) => expr.run(context),
             Node::ArrowFunctionDecl(ref decl) => decl.run(context),
             Node::BinOp(ref op) => op.run(context),
             Node::UnaryOp(ref op) => op.run(context),
@@ -347,6 +470,7 @@ impl Executable for Node {
             Node::Try(ref try_node) => try_node.run(context),
             Node::Break(ref break_node) => break_node.run(context),
             Node::Continue(ref continue_node) => continue_node.run(context),
+            Node::Yield(ref y) => y.run(context),
             Node::Empty => Ok(Value::Undefined),
         }
     }
@@ -386,8 +510,7 @@ where
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Clone, Debug, PartialEq, Trace, Finalize)]
 pub struct FormalParameter {
-    name: Box<str>,
-    init: Option<Node>,
+    declaration: Declaration,
     is_rest_param: bool,
 }
 
@@ -395,29 +518,49 @@ impl FormalParameter {
     /// Creates a new formal parameter.
     pub(in crate::syntax) fn new<N>(name: N, init: Option<Node>, is_rest_param: bool) -> Self
     where
-        N: Into<Box<str>>,
+        N: Into<Identifier>,
     {
         Self {
-            name: name.into(),
-            init,
+            declaration: Declaration::new(name, init),
+            is_rest_param,
+        }
+    }
+
+    /// Creates a new formal parameter from a declaration, which may use a binding pattern.
+    pub(in crate::syntax) fn new_with_declaration(
+        declaration: Declaration,
+        is_rest_param: bool,
+    ) -> Self {
+        Self {
+            declaration,
             is_rest_param,
         }
     }
 
-    /// Gets the name of the formal parameter.
-    pub fn name(&self) -> &str {
-        &self.name
+    /// Gets the names of the variables bound by the formal parameter.
+    pub fn names(&self) -> Vec<&str> {
+        self.declaration.idents()
+    }
+
+    /// Gets the declaration of the formal parameter.
+    pub fn declaration(&self) -> &Declaration {
+        &self.declaration
     }
 
     /// Gets the initialization node of the formal parameter, if any.
     pub fn init(&self) -> Option<&Node> {
-        self.init.as_ref()
+        self.declaration.init()
     }
 
     /// Gets wether the parameter is a rest parameter.
     pub fn is_rest_param(&self) -> bool {
         self.is_rest_param
     }
+
+    /// Gets wether the parameter is a plain identifier, without a binding pattern.
+    pub fn is_identifier(&self) -> bool {
+        matches!(self.declaration, Declaration::Identifier { .. })
+    }
 }
 
 impl Display for FormalParameter {
@@ -425,11 +568,7 @@ impl Display for FormalParameter {
         if self.is_rest_param {
             write!(f, "...")?;
         }
-        write!(f, "{}", self.name)?;
-        if let Some(n) = self.init.as_ref() {
-            write!(f, " = {}", n)?;
-        }
-        Ok(())
+        write!(f, "{}", self.declaration)
     }
 }
 
@@ -467,7 +606,7 @@ pub enum PropertyDefinition {
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-PropertyDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Property_definitions
-    Property(Box<str>, Node),
+    Property(PropertyName, Node),
 
     /// A property of an object can also refer to a function or a getter or setter method.
     ///
@@ -477,7 +616,7 @@ pub enum PropertyDefinition {
     ///
     /// [spec]: https://tc39.es/ecma262/#prod-MethodDefinition
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Method_definitions
-    MethodDefinition(MethodDefinitionKind, Box<str>, FunctionExpr),
+    MethodDefinition(MethodDefinitionKind, PropertyName, FunctionExpr),
 
     /// The Rest/Spread Properties for ECMAScript proposal (stage 4) adds spread properties to object literals.
     /// It copies own enumerable properties from a provided object onto a new object.
@@ -505,7 +644,7 @@ impl PropertyDefinition {
     /// Creates a `Property` definition.
     pub fn property<N, V>(name: N, value: V) -> Self
     where
-        N: Into<Box<str>>,
+        N: Into<PropertyName>,
         V: Into<Node>,
     {
         Self::Property(name.into(), value.into())
@@ -514,7 +653,7 @@ impl PropertyDefinition {
     /// Creates a `MethodDefinition`.
     pub fn method_definition<N>(kind: MethodDefinitionKind, name: N, body: FunctionExpr) -> Self
     where
-        N: Into<Box<str>>,
+        N: Into<PropertyName>,
     {
         Self::MethodDefinition(kind, name.into(), body)
     }
@@ -588,3 +727,66 @@ pub enum MethodDefinitionKind {
 unsafe impl Trace for MethodDefinitionKind {
     empty_trace!();
 }
+
+/// The name of a property in an object literal.
+///
+/// It is either a literal name or an expression evaluated to get the property key.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-PropertyName
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Object_initializer#Computed_property_names
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, PartialEq, Trace, Finalize)]
+pub enum PropertyName {
+    /// A property name given as an identifier, string or numeric literal.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-LiteralPropertyName
+    Literal(Box<str>),
+
+    /// A property name given as an expression in square brackets, e.g. `{ [key]: value }`.
+    ///
+    /// More information:
+    ///  - [ECMAScript reference][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-ComputedPropertyName
+    Computed(Node),
+}
+
+impl fmt::Display for PropertyName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(name) => write!(f, "{}", name),
+            Self::Computed(node) => write!(f, "[{}]", node),
+        }
+    }
+}
+
+impl From<&str> for PropertyName {
+    fn from(name: &str) -> Self {
+        Self::Literal(name.into())
+    }
+}
+
+impl From<String> for PropertyName {
+    fn from(name: String) -> Self {
+        Self::Literal(name.into())
+    }
+}
+
+impl From<Box<str>> for PropertyName {
+    fn from(name: Box<str>) -> Self {
+        Self::Literal(name)
+    }
+}
+
+impl From<Node> for PropertyName {
+    fn from(node: Node) -> Self {
+        Self::Computed(node)
+    }
+}
@@ -0,0 +1,414 @@
+//! Module nodes: `import` and `export` declarations.
+
+use crate::{
+    environment::lexical_environment::VariableScope,
+    exec::Executable,
+    gc::{Finalize, Trace},
+    syntax::ast::node::{join_nodes, Node},
+    Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// The name of the local binding holding the value of an `export default` expression.
+///
+/// It is not a valid identifier, so it can't be referenced by the module code.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-exports-static-semantics-boundnames
+pub const DEFAULT_EXPORT_BINDING: &str = "*default*";
+
+/// An `import` declaration imports bindings exported by another module.
+///
+/// Syntax: `import "module";`, `import name, * as namespace from "module";` or
+/// `import name, { a, b as c } from "module";`.
+///
+/// Import declarations are only allowed at the top level of module code.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ImportDecl {
+    default: Option<Box<str>>,
+    namespace: Option<Box<str>>,
+    specifiers: Box<[ImportSpecifier]>,
+    module: Box<str>,
+}
+
+impl ImportDecl {
+    /// Creates a new `ImportDecl` AST node.
+    pub fn new<M, S>(
+        default: Option<Box<str>>,
+        namespace: Option<Box<str>>,
+        specifiers: S,
+        module: M,
+    ) -> Self
+    where
+        M: Into<Box<str>>,
+        S: Into<Box<[ImportSpecifier]>>,
+    {
+        Self {
+            default,
+            namespace,
+            specifiers: specifiers.into(),
+            module: module.into(),
+        }
+    }
+
+    /// Gets the local name of the default import, if any.
+    pub fn default(&self) -> Option<&str> {
+        self.default.as_deref()
+    }
+
+    /// Gets the local name of the namespace import (`* as name`), if any.
+    pub fn namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    /// Gets the named imports.
+    pub fn specifiers(&self) -> &[ImportSpecifier] {
+        &self.specifiers
+    }
+
+    /// Gets the specifier of the imported module.
+    pub fn module(&self) -> &str {
+        &self.module
+    }
+
+    /// Gets the names of the local bindings created by the declaration.
+    pub fn local_names(&self) -> impl Iterator<Item = &str> {
+        self.default()
+            .into_iter()
+            .chain(self.namespace())
+            .chain(self.specifiers.iter().map(ImportSpecifier::local))
+    }
+}
+
+impl Executable for ImportDecl {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let record = match context.import_module(self.module())? {
+            Some(record) => record,
+            None => {
+                // Without a module loader, the imported bindings are only recorded in the
+                // environment.
+                for name in self.local_names() {
+                    context.create_immutable_binding(
+                        name.to_owned(),
+                        true,
+                        VariableScope::Block,
+                    )?;
+                    context.initialize_binding(name, Value::undefined())?;
+                }
+                return Ok(Value::undefined());
+            }
+        };
+
+        // The imported bindings always read the current value of the exported ones, but a
+        // missing export is an error even if the binding is never used.
+        let imports = self
+            .default()
+            .map(|local| ("default", local))
+            .into_iter()
+            .chain(
+                self.specifiers()
+                    .iter()
+                    .map(|specifier| (specifier.imported(), specifier.local())),
+            );
+        for (imported, local) in imports {
+            if context.resolve_export(self.module(), imported)?.is_none() {
+                return context.throw_syntax_error(format!(
+                    "module `{}` has no export named `{}`",
+                    self.module(),
+                    imported
+                ));
+            }
+            context.create_import_binding(local.to_owned(), self.module(), imported)?;
+        }
+
+        if let Some(namespace) = self.namespace() {
+            context.create_immutable_binding(namespace.to_owned(), true, VariableScope::Block)?;
+            context.initialize_binding(namespace, record.namespace().clone().into())?;
+        }
+        Ok(Value::undefined())
+    }
+}
+
+impl fmt::Display for ImportDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "import ")?;
+        if self.default.is_none() && self.namespace.is_none() && self.specifiers.is_empty() {
+            return write!(f, "\"{}\"", self.module);
+        }
+
+        if let Some(ref default) = self.default {
+            write!(f, "{}", default)?;
+            if self.namespace.is_some() || !self.specifiers.is_empty() {
+                write!(f, ", ")?;
+            }
+        }
+        if let Some(ref namespace) = self.namespace {
+            write!(f, "* as {}", namespace)?;
+        } else if !self.specifiers.is_empty() {
+            write!(f, "{{ ")?;
+            join_nodes(f, &self.specifiers)?;
+            write!(f, " }}")?;
+        }
+        write!(f, " from \"{}\"", self.module)
+    }
+}
+
+impl From<ImportDecl> for Node {
+    fn from(decl: ImportDecl) -> Self {
+        Self::ImportDecl(decl)
+    }
+}
+
+/// A named import, in the braces of an `import` declaration: `name` or `name as local`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ImportSpecifier
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ImportSpecifier {
+    imported: Box<str>,
+    local: Box<str>,
+}
+
+impl ImportSpecifier {
+    /// Creates a new `ImportSpecifier`, importing `imported` under the name `local`.
+    pub fn new<I, L>(imported: I, local: L) -> Self
+    where
+        I: Into<Box<str>>,
+        L: Into<Box<str>>,
+    {
+        Self {
+            imported: imported.into(),
+            local: local.into(),
+        }
+    }
+
+    /// Gets the name exported by the imported module.
+    pub fn imported(&self) -> &str {
+        &self.imported
+    }
+
+    /// Gets the name of the local binding.
+    pub fn local(&self) -> &str {
+        &self.local
+    }
+}
+
+impl fmt::Display for ImportSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.imported == self.local {
+            write!(f, "{}", self.local)
+        } else {
+            write!(f, "{} as {}", self.imported, self.local)
+        }
+    }
+}
+
+/// An `export` declaration makes bindings of a module available to the modules importing it.
+///
+/// Export declarations are only allowed at the top level of module code.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum ExportDecl {
+    /// Exports the bindings of a declaration: `export const a = 1;` or `export function f() {}`.
+    Declaration(Box<Node>),
+
+    /// Exports a value as the default export: `export default expression;`.
+    ///
+    /// If the value is a named function declaration, its binding is exported.
+    Default(Box<Node>),
+
+    /// Exports local bindings, or bindings of another module if `module` is set:
+    /// `export { a, b as c };` or `export { a } from "module";`.
+    Named {
+        specifiers: Box<[ExportSpecifier]>,
+        module: Option<Box<str>>,
+    },
+
+    /// Exports all the bindings of another module: `export * from "module";`.
+    All { module: Box<str> },
+}
+
+impl ExportDecl {
+    /// Gets the specifier of the module the bindings are exported from, if they are not local.
+    pub fn module(&self) -> Option<&str> {
+        match self {
+            Self::Named { module, .. } => module.as_deref(),
+            Self::All { module } => Some(module.as_ref()),
+            Self::Declaration(_) | Self::Default(_) => None,
+        }
+    }
+
+    /// Gets the local bindings exported by the declaration, as pairs of the exported name and the
+    /// name of the local binding.
+    ///
+    /// Re-exports of the bindings of other modules are not included.
+    pub fn local_exports(&self) -> Vec<(&str, &str)> {
+        match self {
+            Self::Declaration(decl) => match decl.as_ref() {
+                Node::VarDeclList(list) | Node::LetDeclList(list) | Node::ConstDeclList(list) => {
+                    list.as_ref()
+                        .iter()
+                        .flat_map(|decl| decl.idents())
+                        .map(|name| (name, name))
+                        .collect()
+                }
+                node => node
+                    .function_name()
+                    .map(|name| vec![(name, name)])
+                    .unwrap_or_default(),
+            },
+            Self::Default(decl) => {
+                let local = decl.function_name().unwrap_or(DEFAULT_EXPORT_BINDING);
+                vec![("default", local)]
+            }
+            Self::Named {
+                specifiers,
+                module: None,
+            } => specifiers
+                .iter()
+                .map(|spec| (spec.exported(), spec.local()))
+                .collect(),
+            Self::Named { .. } | Self::All { .. } => Vec::new(),
+        }
+    }
+}
+
+impl Executable for ExportDecl {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let value = match self {
+            Self::Declaration(decl) => decl.run(context)?,
+            Self::Default(decl) if decl.function_name().is_some() => decl.run(context)?,
+            Self::Default(expr) => {
+                let value = expr.run(context)?;
+                context.create_immutable_binding(
+                    DEFAULT_EXPORT_BINDING.to_owned(),
+                    true,
+                    VariableScope::Block,
+                )?;
+                context.initialize_binding(DEFAULT_EXPORT_BINDING, value)?;
+                Value::undefined()
+            }
+            // The exports are recorded before the module is evaluated, so only the module they
+            // are re-exported from is imported here.
+            Self::Named { .. } | Self::All { .. } => {
+                if let Some(module) = self.module() {
+                    context.import_module(module)?;
+                }
+                Value::undefined()
+            }
+        };
+        Ok(value)
+    }
+}
+
+impl ExportDecl {
+    /// Implements the display formatting with indentation.
+    pub(in crate::syntax::ast::node) fn display(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        indentation: usize,
+    ) -> fmt::Result {
+        match self {
+            Self::Declaration(decl) => {
+                write!(f, "export ")?;
+                decl.display(f, indentation)
+            }
+            Self::Default(decl) => {
+                write!(f, "export default ")?;
+                decl.display(f, indentation)
+            }
+            Self::Named { specifiers, module } => {
+                write!(f, "export {{ ")?;
+                join_nodes(f, specifiers)?;
+                write!(f, " }}")?;
+                if let Some(module) = module {
+                    write!(f, " from \"{}\"", module)?;
+                }
+                Ok(())
+            }
+            Self::All { module } => write!(f, "export * from \"{}\"", module),
+        }
+    }
+}
+
+impl fmt::Display for ExportDecl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(f, 0)
+    }
+}
+
+impl From<ExportDecl> for Node {
+    fn from(decl: ExportDecl) -> Self {
+        Self::ExportDecl(decl)
+    }
+}
+
+/// A named export, in the braces of an `export` declaration: `name` or `name as exported`.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportSpecifier
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct ExportSpecifier {
+    local: Box<str>,
+    exported: Box<str>,
+}
+
+impl ExportSpecifier {
+    /// Creates a new `ExportSpecifier`, exporting the binding `local` under the name `exported`.
+    pub fn new<L, E>(local: L, exported: E) -> Self
+    where
+        L: Into<Box<str>>,
+        E: Into<Box<str>>,
+    {
+        Self {
+            local: local.into(),
+            exported: exported.into(),
+        }
+    }
+
+    /// Gets the name of the exported binding, in the module it is exported from.
+    pub fn local(&self) -> &str {
+        &self.local
+    }
+
+    /// Gets the name the binding is exported as.
+    pub fn exported(&self) -> &str {
+        &self.exported
+    }
+}
+
+impl fmt::Display for ExportSpecifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.local == self.exported {
+            write!(f, "{}", self.local)
+        } else {
+            write!(f, "{} as {}", self.local, self.exported)
+        }
+    }
+}
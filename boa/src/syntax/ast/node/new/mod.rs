@@ -59,10 +59,8 @@ impl Executable for New {
                     if next.is_done() {
                         break;
                     }
-                    let next_value = next.value();
-                    v_args.push(next_value.clone());
+                    v_args.push(next.value());
                 }
-                break; // after spread we don't accept any new arguments
             } else {
                 v_args.push(arg.run(context)?);
             }
@@ -3,8 +3,8 @@
 use crate::{
     exec::Executable,
     gc::{Finalize, Trace},
-    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor},
-    syntax::ast::node::{MethodDefinitionKind, Node, PropertyDefinition},
+    property::{AccessorDescriptor, Attribute, DataDescriptor, PropertyDescriptor, PropertyKey},
+    syntax::ast::node::{MethodDefinitionKind, Node, PropertyDefinition, PropertyName},
     BoaProfiler, Context, Result, Value,
 };
 use std::fmt;
@@ -15,6 +15,9 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "vm")]
 use crate::vm::{compilation::CodeGen, Compiler, Instruction};
 
+#[cfg(test)]
+mod tests;
+
 /// Objects in JavaScript may be defined as an unordered collection of related data, of
 /// primitive or reference types, in the form of “key: value” pairs.
 ///
@@ -50,27 +53,39 @@ impl Object {
     pub(in crate::syntax::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
-        indent: usize,
+        indentation: usize,
     ) -> fmt::Result {
+        if self.properties.is_empty() {
+            return f.write_str("{}");
+        }
+
+        let indent = "    ".repeat(indentation + 1);
         f.write_str("{\n")?;
         for property in self.properties().iter() {
+            f.write_str(&indent)?;
             match property {
                 PropertyDefinition::IdentifierReference(key) => {
-                    write!(f, "{}    {},", indent, key)?;
+                    write!(f, "{}", key)?;
                 }
                 PropertyDefinition::Property(key, value) => {
-                    write!(f, "{}    {}: {},", indent, key, value)?;
+                    write!(f, "{}: ", key)?;
+                    value.display(f, indentation + 1)?;
                 }
                 PropertyDefinition::SpreadObject(key) => {
-                    write!(f, "{}    ...{},", indent, key)?;
+                    write!(f, "...{}", key)?;
                 }
-                PropertyDefinition::MethodDefinition(_kind, _key, _node) => {
-                    // TODO: Implement display for PropertyDefinition::MethodDefinition.
-                    unimplemented!("Display for PropertyDefinition::MethodDefinition");
+                PropertyDefinition::MethodDefinition(kind, key, func) => {
+                    match kind {
+                        MethodDefinitionKind::Get => write!(f, "get {}", key)?,
+                        MethodDefinitionKind::Set => write!(f, "set {}", key)?,
+                        MethodDefinitionKind::Ordinary => write!(f, "{}", key)?,
+                    }
+                    func.display_parameters_and_body(f, indentation + 1)?;
                 }
             }
+            f.write_str(",\n")?;
         }
-        f.write_str("}")
+        write!(f, "{}}}", "    ".repeat(indentation))
     }
 }
 
@@ -93,64 +108,99 @@ impl Executable for Object {
         let _timer = BoaProfiler::global().start_event("object", "exec");
         let obj = Value::new_object(context);
 
-        // TODO: Implement the rest of the property types.
         for property in self.properties().iter() {
             match property {
-                PropertyDefinition::Property(key, value) => {
+                PropertyDefinition::IdentifierReference(key) => {
                     obj.set_property(
                         key.clone(),
+                        PropertyDescriptor::Data(DataDescriptor::new(
+                            context.get_binding_value(key)?,
+                            Attribute::all(),
+                        )),
+                    );
+                }
+                PropertyDefinition::Property(key, value) => {
+                    let key = property_key(key, context)?;
+                    obj.set_property(
+                        key,
                         PropertyDescriptor::Data(DataDescriptor::new(
                             value.run(context)?,
                             Attribute::all(),
                         )),
                     );
                 }
-                PropertyDefinition::MethodDefinition(kind, name, func) => match kind {
-                    MethodDefinitionKind::Ordinary => {
-                        obj.set_property(
-                            name.clone(),
-                            PropertyDescriptor::Data(DataDescriptor::new(
-                                func.run(context)?,
-                                Attribute::all(),
-                            )),
-                        );
+                PropertyDefinition::MethodDefinition(kind, name, func) => {
+                    let name = property_key(name, context)?;
+                    match kind {
+                        MethodDefinitionKind::Ordinary => {
+                            obj.set_property(
+                                name,
+                                PropertyDescriptor::Data(DataDescriptor::new(
+                                    func.run(context)?,
+                                    Attribute::all(),
+                                )),
+                            );
+                        }
+                        MethodDefinitionKind::Get => {
+                            let set = obj
+                                .get_property(name.clone())
+                                .as_ref()
+                                .and_then(|p| p.as_accessor_descriptor())
+                                .and_then(|a| a.setter().cloned());
+                            obj.set_property(
+                                name,
+                                PropertyDescriptor::Accessor(AccessorDescriptor {
+                                    get: func.run(context)?.as_object(),
+                                    set,
+                                    attributes: Attribute::WRITABLE
+                                        | Attribute::ENUMERABLE
+                                        | Attribute::CONFIGURABLE,
+                                }),
+                            )
+                        }
+                        MethodDefinitionKind::Set => {
+                            let get = obj
+                                .get_property(name.clone())
+                                .as_ref()
+                                .and_then(|p| p.as_accessor_descriptor())
+                                .and_then(|a| a.getter().cloned());
+                            obj.set_property(
+                                name,
+                                PropertyDescriptor::Accessor(AccessorDescriptor {
+                                    get,
+                                    set: func.run(context)?.as_object(),
+                                    attributes: Attribute::WRITABLE
+                                        | Attribute::ENUMERABLE
+                                        | Attribute::CONFIGURABLE,
+                                }),
+                            )
+                        }
                     }
-                    MethodDefinitionKind::Get => {
-                        let set = obj
-                            .get_property(name.clone())
-                            .as_ref()
-                            .and_then(|p| p.as_accessor_descriptor())
-                            .and_then(|a| a.setter().cloned());
-                        obj.set_property(
-                            name.clone(),
-                            PropertyDescriptor::Accessor(AccessorDescriptor {
-                                get: func.run(context)?.as_object(),
-                                set,
-                                attributes: Attribute::WRITABLE
-                                    | Attribute::ENUMERABLE
-                                    | Attribute::CONFIGURABLE,
-                            }),
-                        )
+                }
+                PropertyDefinition::SpreadObject(node) => {
+                    let source = node.run(context)?;
+
+                    // Spreading `null` or `undefined` doesn't add any property.
+                    if source.is_null_or_undefined() {
+                        continue;
                     }
-                    MethodDefinitionKind::Set => {
-                        let get = obj
-                            .get_property(name.clone())
-                            .as_ref()
-                            .and_then(|p| p.as_accessor_descriptor())
-                            .and_then(|a| a.getter().cloned());
-                        obj.set_property(
-                            name.clone(),
-                            PropertyDescriptor::Accessor(AccessorDescriptor {
-                                get,
-                                set: func.run(context)?.as_object(),
-                                attributes: Attribute::WRITABLE
-                                    | Attribute::ENUMERABLE
-                                    | Attribute::CONFIGURABLE,
-                            }),
-                        )
+
+                    let source = source.to_object(context)?;
+                    for key in source.own_property_keys() {
+                        if let Some(desc) = source.get_own_property(&key) {
+                            if desc.enumerable() {
+                                let value = source.get(&key, source.clone().into(), context)?;
+                                obj.set_property(
+                                    key,
+                                    PropertyDescriptor::Data(DataDescriptor::new(
+                                        value,
+                                        Attribute::all(),
+                                    )),
+                                );
+                            }
+                        }
                     }
-                },
-                _ => {} //unimplemented!("{:?} type of property", i),
+                }
             }
         }
 
@@ -158,6 +208,19 @@ impl Executable for Object {
     }
 }
 
+/// Evaluates the name of a property to the key of the property it defines.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-object-initializer-runtime-semantics-evaluation
+fn property_key(name: &PropertyName, context: &mut Context) -> Result<PropertyKey> {
+    match name {
+        PropertyName::Literal(name) => Ok(name.clone().into()),
+        PropertyName::Computed(node) => node.run(context)?.to_property_key(context),
+    }
+}
+
 impl fmt::Display for Object {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.display(f, 0)
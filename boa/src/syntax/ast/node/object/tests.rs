@@ -0,0 +1,124 @@
+use crate::exec;
+
+#[test]
+fn spread_shallow_clone() {
+    let scenario = r#"
+        var a = { x: {} };
+        var aClone = { ...a };
+
+        a.x === aClone.x
+        "#;
+
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn spread_override_order() {
+    let scenario = r#"
+        var source = { a: 1, b: 2 };
+        var before = { a: 0, ...source };
+        var after = { ...source, a: 3 };
+
+        [before.a, before.b, after.a, after.b]
+        "#;
+
+    assert_eq!(&exec(scenario), "[ 1, 2, 3, 2 ]");
+}
+
+#[test]
+fn spread_only_own_enumerable_properties() {
+    let scenario = r#"
+        var proto = { inherited: 1 };
+        var source = Object.create(proto);
+        source.own = 2;
+        Object.defineProperty(source, "hidden", { value: 3, enumerable: false });
+        var copy = { ...source };
+
+        [copy.own, copy.inherited, copy.hidden]
+        "#;
+
+    assert_eq!(&exec(scenario), "[ 2, undefined, undefined ]");
+}
+
+#[test]
+fn spread_calls_getters() {
+    let scenario = r#"
+        var source = { get value() { return 42; } };
+        var copy = { ...source };
+
+        Object.getOwnPropertyDescriptor(copy, "value").value
+        "#;
+
+    assert_eq!(&exec(scenario), "42");
+}
+
+#[test]
+fn spread_null_undefined_and_primitives() {
+    let scenario = r#"
+        var copy = { ...null, ...undefined, ...1, ...true, a: 1 };
+        var count = 0;
+        for (var key in copy) {
+            count++;
+        }
+
+        count
+        "#;
+
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn computed_property_names() {
+    let scenario = r#"
+        var key = "dyn";
+        var i = 0;
+        var obj = {
+            [key + "amic"]: 1,
+            [i++]: "zero",
+            [i++]: "one",
+            [key]() { return "method"; },
+            get [key + "Getter"]() { return "getter"; },
+        };
+        var count = 0;
+        for (var name in obj) {
+            count++;
+        }
+
+        [obj.dynamic, obj[0], obj[1], obj.dyn(), obj.dynGetter, count]
+        "#;
+
+    assert_eq!(
+        &exec(scenario),
+        r#"[ 1, "zero", "one", "method", "getter", 5 ]"#
+    );
+}
+
+#[test]
+fn shorthand_properties() {
+    let scenario = r#"
+        var x = 1;
+        var y = "two";
+        var obj = { x, y };
+
+        [obj.x, obj.y]
+        "#;
+
+    assert_eq!(&exec(scenario), r#"[ 1, "two" ]"#);
+}
+
+#[test]
+fn shorthand_methods() {
+    let scenario = r#"
+        var obj = {
+            value: 1,
+            add(n) { return this.value + n; },
+            get double() { return this.value * 2; },
+            set double(v) { this.value = v / 2; },
+        };
+        obj.double = 10;
+
+        [obj.add(1), obj.double, obj.value]
+        "#;
+
+    assert_eq!(&exec(scenario), "[ 6, 10, 5 ]");
+}
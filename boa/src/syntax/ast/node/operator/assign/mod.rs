@@ -26,6 +26,7 @@ use serde::{Deserialize, Serialize};
 pub struct Assign {
     lhs: Box<Node>,
     rhs: Box<Node>,
+    strict: bool,
 }
 
 impl Assign {
@@ -38,9 +39,22 @@ impl Assign {
         Self {
             lhs: Box::new(lhs.into()),
             rhs: Box::new(rhs.into()),
+            strict: false,
         }
     }
 
+    /// Creates an `Assign` AST node which, if it was parsed in strict mode, throws a
+    /// `ReferenceError` when assigning to an undeclared variable.
+    pub(in crate::syntax) fn with_strict_mode<L, R>(lhs: L, rhs: R, strict: bool) -> Self
+    where
+        L: Into<Node>,
+        R: Into<Node>,
+    {
+        let mut node = Self::new(lhs, rhs);
+        node.strict = strict;
+        node
+    }
+
     /// Gets the left hand side of the assignment operation.
     pub fn lhs(&self) -> &Node {
         &self.lhs
@@ -61,6 +75,8 @@ impl Executable for Assign {
                 if context.has_binding(name.as_ref()) {
                     // Binding already exists
                     context.set_mutable_binding(name.as_ref(), val.clone(), true)?;
+                } else if self.strict {
+                    return context.throw_reference_error(format!("{} is not defined", name));
                 } else {
                     context.create_mutable_binding(
                         name.as_ref().to_owned(),
@@ -150,7 +150,7 @@ impl Executable for BinOp {
                             ));
                         }
                         let key = x.to_property_key(context)?;
-                        context.has_property(&y, &key)
+                        context.has_property(&y, &key)?
                     }
                     CompOp::InstanceOf => {
                         if let Some(object) = y.as_object() {
@@ -281,7 +281,11 @@ impl CodeGen for BinOp {
 
 impl fmt::Display for BinOp {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} {} {}", self.lhs, self.op, self.rhs)
+        match self.op {
+            // Parenthesized, so that it isn't confused with an argument list or array elements.
+            op::BinOp::Comma => write!(f, "({}, {})", self.lhs, self.rhs),
+            _ => write!(f, "{} {} {}", self.lhs, self.op, self.rhs),
+        }
     }
 }
 
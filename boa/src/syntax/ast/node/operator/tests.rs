@@ -61,6 +61,47 @@ fn instanceofoperator_rhs_not_callable() {
     );
 }
 
+#[test]
+fn instanceofoperator_walks_prototype_chain() {
+    let scenario = r#"
+        function Animal() {}
+        function Dog() {}
+        Dog.prototype = Object.create(Animal.prototype);
+
+        let d = new Dog();
+        [d instanceof Dog, d instanceof Animal, d instanceof Object].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,true,true\"");
+}
+
+#[test]
+fn instanceofoperator_negative() {
+    let scenario = r#"
+        function Animal() {}
+        function Dog() {}
+
+        let d = new Dog();
+        [d instanceof Animal, 1 instanceof Number, Object.create(null) instanceof Object].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"false,false,false\"");
+}
+
+#[test]
+fn instanceofoperator_has_instance() {
+    let scenario = r#"
+        let Even = {
+          [Symbol.hasInstance]: function(value) {
+            return value % 2 === 0;
+          }
+        };
+        [2 instanceof Even, 3 instanceof Even].join()
+        "#;
+
+    assert_eq!(&exec(scenario), "\"true,false\"");
+}
+
 #[test]
 fn logical_nullish_assignment() {
     let scenario = r#"
@@ -28,6 +28,7 @@ use crate::{
 pub struct UnaryOp {
     op: op::UnaryOp,
     target: Box<Node>,
+    strict: bool,
 }
 
 impl UnaryOp {
@@ -39,9 +40,21 @@ impl UnaryOp {
         Self {
             op,
             target: Box::new(target.into()),
+            strict: false,
         }
     }
 
+    /// Creates a new `delete` operation, which throws a `TypeError` instead of returning `false`
+    /// if it was parsed in strict mode.
+    pub(in crate::syntax) fn new_delete<V>(target: V, strict: bool) -> Self
+    where
+        V: Into<Node>,
+    {
+        let mut node = Self::new(op::UnaryOp::Delete, target);
+        node.strict = strict;
+        node
+    }
+
     /// Gets the unary operation of the node.
     pub fn op(&self) -> op::UnaryOp {
         self.op
@@ -94,34 +107,39 @@ impl Executable for UnaryOp {
                 self.target().run(context)?;
                 Value::undefined()
             }
-            op::UnaryOp::Delete => match *self.target() {
-                Node::GetConstField(ref get_const_field) => Value::boolean(
-                    get_const_field
-                        .obj()
-                        .run(context)?
-                        .to_object(context)?
-                        .delete(&get_const_field.field().into()),
-                ),
-                Node::GetField(ref get_field) => {
-                    let obj = get_field.obj().run(context)?;
-                    let field = &get_field.field().run(context)?;
-                    let res = obj
-                        .to_object(context)?
-                        .delete(&field.to_property_key(context)?);
-                    return Ok(Value::boolean(res));
+            op::UnaryOp::Delete => {
+                let deleted = match *self.target() {
+                    Node::GetConstField(ref get_const_field) => {
+                        let mut obj = get_const_field.obj().run(context)?.to_object(context)?;
+                        obj.delete(&get_const_field.field().into(), context)?
+                    }
+                    Node::GetField(ref get_field) => {
+                        // The key is evaluated before the base is converted to an object.
+                        let obj = get_field.obj().run(context)?;
+                        let key = get_field.field().run(context)?.to_property_key(context)?;
+                        let mut obj = obj.to_object(context)?;
+                        obj.delete(&key, context)?
+                    }
+                    Node::Identifier(_) => false,
+                    // Deleting anything that isn't a reference only evaluates it.
+                    ref target => {
+                        target.run(context)?;
+                        true
+                    }
+                };
+
+                if !deleted && self.strict {
+                    return context.throw_type_error(format!("cannot delete {}", self.target()));
+                }
+                Value::boolean(deleted)
+            }
+            op::UnaryOp::TypeOf => match *self.target() {
+                // Unresolvable references are `undefined` instead of throwing a `ReferenceError`.
+                Node::Identifier(ref name) if !context.has_binding(name.as_ref()) => {
+                    Value::from("undefined")
                 }
-                Node::Identifier(_) => Value::boolean(false),
-                Node::ArrayDecl(_)
-                | Node::Block(_)
-                | Node::Const(_)
-                | Node::FunctionDecl(_)
-                | Node::FunctionExpr(_)
-                | Node::New(_)
-                | Node::Object(_)
-                | Node::UnaryOp(_) => Value::boolean(true),
-                _ => return context.throw_syntax_error(format!("wrong delete argument {}", self)),
+                ref target => Value::from(target.run(context)?.get_type().as_str()),
             },
-            op::UnaryOp::TypeOf => Value::from(self.target().run(context)?.get_type().as_str()),
         })
     }
 }
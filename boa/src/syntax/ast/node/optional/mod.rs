@@ -0,0 +1,224 @@
+//! Optional chaining AST node.
+
+use crate::{
+    builtins::iterable,
+    exec::{Executable, InterpreterState},
+    gc::{Finalize, Trace},
+    syntax::ast::node::{join_nodes, Node},
+    value::Type,
+    BoaProfiler, Context, Result, Value,
+};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(test)]
+mod tests;
+
+/// The optional chaining operator (`?.`) accesses an object's property or calls a function,
+/// short-circuiting to `undefined` if the object or function is `undefined` or `null`.
+///
+/// Once the chain is short-circuited, none of the remaining operations of the chain are
+/// evaluated, so `a?.b.c` evaluates to `undefined` if `a` is `null`, instead of throwing.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Optional {
+    target: Box<Node>,
+    chain: Box<[OptionalOperation]>,
+}
+
+impl Optional {
+    /// Creates a new `Optional` AST node.
+    pub fn new<T, C>(target: T, chain: C) -> Self
+    where
+        T: Into<Node>,
+        C: Into<Box<[OptionalOperation]>>,
+    {
+        Self {
+            target: Box::new(target.into()),
+            chain: chain.into(),
+        }
+    }
+
+    /// Gets the expression the chain is applied to.
+    pub fn target(&self) -> &Node {
+        &self.target
+    }
+
+    /// Gets the operations of the chain.
+    pub fn chain(&self) -> &[OptionalOperation] {
+        &self.chain
+    }
+}
+
+/// Converts the base of a property access to an object, the same way `GetConstField` does.
+fn to_base_object(base: Value, context: &mut Context) -> Result<Value> {
+    if base.get_type() != Type::Object {
+        Ok(Value::Object(base.to_object(context)?))
+    } else {
+        Ok(base)
+    }
+}
+
+impl Executable for Optional {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Optional", "exec");
+
+        // The base object of the last property access is kept around, so that a call at the end
+        // of the chain gets the right `this` value.
+        let (mut this, mut value) = match self.target() {
+            Node::GetConstField(ref get_const_field) => {
+                let obj = get_const_field.obj().run(context)?;
+                let obj = to_base_object(obj, context)?;
+                let value = obj.get_field(get_const_field.field(), context)?;
+                (obj, value)
+            }
+            Node::GetField(ref get_field) => {
+                let obj = get_field.obj().run(context)?;
+                let obj = to_base_object(obj, context)?;
+                let field = get_field.field().run(context)?;
+                let value = obj.get_field(field.to_property_key(context)?, context)?;
+                (obj, value)
+            }
+            target => (context.global_object().into(), target.run(context)?),
+        };
+
+        for operation in self.chain().iter() {
+            if operation.is_optional() && value.is_null_or_undefined() {
+                return Ok(Value::undefined());
+            }
+
+            match operation.kind() {
+                OptionalOperationKind::SimplePropertyAccess { field } => {
+                    this = to_base_object(value, context)?;
+                    value = this.get_field(field.as_ref(), context)?;
+                }
+                OptionalOperationKind::PropertyAccess { field } => {
+                    this = to_base_object(value, context)?;
+                    let field = field.run(context)?;
+                    value = this.get_field(field.to_property_key(context)?, context)?;
+                }
+                OptionalOperationKind::Call { args } => {
+                    let mut arguments = Vec::with_capacity(args.len());
+                    for arg in args.iter() {
+                        if let Node::Spread(ref spread) = arg {
+                            let iterable = spread.val().run(context)?;
+                            let iterator_record = iterable::get_iterator(context, iterable)?;
+                            loop {
+                                let next = iterator_record.next(context)?;
+                                if next.is_done() {
+                                    break;
+                                }
+                                arguments.push(next.value());
+                            }
+                        } else {
+                            arguments.push(arg.run(context)?);
+                        }
+                    }
+
+                    let result = context.call(&value, &this, &arguments);
+
+                    // unset the early return flag
+                    context
+                        .executor()
+                        .set_current_state(InterpreterState::Executing);
+
+                    this = context.global_object().into();
+                    value = result?;
+                }
+            }
+        }
+
+        Ok(value)
+    }
+}
+
+impl fmt::Display for Optional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.target)?;
+        for operation in self.chain.iter() {
+            write!(f, "{}", operation)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<Optional> for Node {
+    fn from(optional: Optional) -> Self {
+        Self::Optional(optional)
+    }
+}
+
+/// A single operation of an optional chain.
+///
+/// An operation is optional if it is directly preceded by the `?.` punctuator, in which case the
+/// chain is short-circuited when the value it is applied to is `undefined` or `null`.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct OptionalOperation {
+    kind: OptionalOperationKind,
+    optional: bool,
+}
+
+impl OptionalOperation {
+    /// Creates a new `OptionalOperation`.
+    pub fn new(kind: OptionalOperationKind, optional: bool) -> Self {
+        Self { kind, optional }
+    }
+
+    /// Gets the kind of this operation.
+    pub fn kind(&self) -> &OptionalOperationKind {
+        &self.kind
+    }
+
+    /// Returns `true` if this operation is preceded by the `?.` punctuator.
+    pub fn is_optional(&self) -> bool {
+        self.optional
+    }
+}
+
+impl fmt::Display for OptionalOperation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.optional {
+            f.write_str("?.")?;
+        }
+
+        match self.kind {
+            OptionalOperationKind::SimplePropertyAccess { ref field } => {
+                if self.optional {
+                    write!(f, "{}", field)
+                } else {
+                    write!(f, ".{}", field)
+                }
+            }
+            OptionalOperationKind::PropertyAccess { ref field } => write!(f, "[{}]", field),
+            OptionalOperationKind::Call { ref args } => {
+                f.write_str("(")?;
+                join_nodes(f, args)?;
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+/// The kind of operation of an optional chain.
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub enum OptionalOperationKind {
+    /// A property access with a constant name, such as `a?.b`.
+    SimplePropertyAccess { field: Box<str> },
+
+    /// A property access with a computed name, such as `a?.[b]`.
+    PropertyAccess { field: Node },
+
+    /// A function call, such as `a?.()`.
+    Call { args: Box<[Node]> },
+}
@@ -0,0 +1,78 @@
+use crate::exec;
+
+#[test]
+fn optional_property_access() {
+    let scenario = r#"
+        const a = { b: { c: 5 } };
+        a?.b?.c;
+    "#;
+    assert_eq!(&exec(scenario), "5");
+}
+
+#[test]
+fn optional_short_circuits_nested_chain() {
+    let scenario = r#"
+        const a = null;
+        a?.b.c.d === undefined;
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
+
+#[test]
+fn optional_nested_chain_with_missing_link() {
+    let scenario = r#"
+        const a = { b: null };
+        a?.b?.c;
+    "#;
+    assert_eq!(&exec(scenario), "undefined");
+}
+
+#[test]
+fn optional_computed_access() {
+    let scenario = r#"
+        const a = { foo: 1 };
+        const k = "foo";
+        const u = undefined;
+        [a?.[k], u?.[k]];
+    "#;
+    assert_eq!(&exec(scenario), "[ 1, undefined ]");
+}
+
+#[test]
+fn optional_call() {
+    let scenario = r#"
+        const f = () => 10;
+        const g = null;
+        [f?.(), g?.()];
+    "#;
+    assert_eq!(&exec(scenario), "[ 10, undefined ]");
+}
+
+#[test]
+fn optional_method_call_keeps_this() {
+    let scenario = r#"
+        const o = { v: 3, get() { return this.v; } };
+        o.get?.();
+    "#;
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn optional_does_not_evaluate_arguments_when_short_circuited() {
+    let scenario = r#"
+        let count = 0;
+        const f = undefined;
+        f?.(count++);
+        count;
+    "#;
+    assert_eq!(&exec(scenario), "0");
+}
+
+#[test]
+fn conditional_followed_by_number() {
+    let scenario = r#"
+        const a = true;
+        a?.5:0;
+    "#;
+    assert_eq!(&exec(scenario), "0.5");
+}
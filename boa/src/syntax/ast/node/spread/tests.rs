@@ -29,3 +29,56 @@ fn spread_with_call() {
     "#;
     assert_eq!(&exec(scenario), r#""message""#);
 }
+
+#[test]
+fn spread_with_call_and_trailing_arguments() {
+    let scenario = r#"
+    function f(...args) {
+        return args;
+    }
+    let a = [2, 3];
+    let b = [5];
+    f(1, ...a, 4, ...b, 6);
+    "#;
+    assert_eq!(&exec(scenario), "[ 1, 2, 3, 4, 5, 6 ]");
+}
+
+#[test]
+fn spread_with_new_and_trailing_arguments() {
+    let scenario = r#"
+    function F(a, b, c) {
+        this.sum = a + b + c;
+    }
+    let args = [2];
+    new F(1, ...args, 3).sum;
+    "#;
+    assert_eq!(&exec(scenario), "6");
+}
+
+#[test]
+fn spread_in_array_literal() {
+    let scenario = r#"
+    let a = [2, 3];
+    let b = [4];
+    [1, ...a, ...b, 5];
+    "#;
+    assert_eq!(&exec(scenario), "[ 1, 2, 3, 4, 5 ]");
+}
+
+#[test]
+fn spread_iterable_into_array_literal() {
+    let scenario = r#"
+    [..."ab", ...new Set([1, 2])];
+    "#;
+    assert_eq!(&exec(scenario), r#"[ "a", "b", 1, 2 ]"#);
+}
+
+#[test]
+fn spread_array_with_holes() {
+    let scenario = r#"
+    let a = [1, , 3];
+    let b = [...a];
+    b.length + " " + b[1];
+    "#;
+    assert_eq!(&exec(scenario), r#""3 undefined""#);
+}
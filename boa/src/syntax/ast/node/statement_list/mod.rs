@@ -3,10 +3,15 @@
 use crate::{
     exec::{Executable, InterpreterState},
     gc::{empty_trace, Finalize, Trace},
-    syntax::ast::node::Node,
+    syntax::ast::{node::Node, Span},
     BoaProfiler, Context, Result, Value,
 };
-use std::{collections::HashSet, fmt, ops::Deref, rc::Rc};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    ops::Deref,
+    rc::Rc,
+};
 
 #[cfg(feature = "deser")]
 use serde::{Deserialize, Serialize};
@@ -14,6 +19,9 @@ use serde::{Deserialize, Serialize};
 #[cfg(feature = "vm")]
 use crate::vm::{compilation::CodeGen, Compiler};
 
+#[cfg(test)]
+mod tests;
+
 /// List of statements.
 ///
 /// Similar to `Node::Block` but without the braces.
@@ -23,19 +31,143 @@ use crate::vm::{compilation::CodeGen, Compiler};
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-StatementList
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
-#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+#[derive(Debug, Trace, Finalize)]
 pub struct StatementList {
     #[cfg_attr(feature = "deser", serde(flatten))]
     items: Box<[Node]>,
+
+    /// The spans of the items in the source code, if the list was parsed.
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    spans: Box<[Span]>,
+
+    /// The spans of the expressions of the list in the source code, keyed by the address of their
+    /// node, if the list is a parsed script or module.
+    #[unsafe_ignore_trace]
+    #[cfg_attr(feature = "deser", serde(skip))]
+    expression_spans: HashMap<*const Node, Span>,
 }
 
 impl StatementList {
+    /// Creates a statement list of parsed statements, with the span of every statement in the
+    /// source code.
+    pub(in crate::syntax) fn with_spans<I, S>(items: I, spans: S) -> Self
+    where
+        I: Into<Box<[Node]>>,
+        S: Into<Box<[Span]>>,
+    {
+        let items = items.into();
+        let spans = spans.into();
+        assert_eq!(items.len(), spans.len(), "every item must have a span");
+        Self {
+            items,
+            spans,
+            expression_spans: HashMap::new(),
+        }
+    }
+
+    /// Sets the spans of the expressions of a parsed script or module.
+    pub(in crate::syntax) fn set_expression_spans(&mut self, spans: HashMap<*const Node, Span>) {
+        self.expression_spans = spans;
+    }
+
     /// Gets the list of items.
     pub fn items(&self) -> &[Node] {
         &self.items
     }
 
+    /// Gets the number of statements in the list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use boa::syntax::Parser;
+    ///
+    /// let list = Parser::new("a; b; c;".as_bytes(), false)
+    ///     .parse_all()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(list.len(), 3);
+    /// assert_eq!((&list).into_iter().count(), 3);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if the list contains no statements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Gets the span in the source code of the statement at `index`, including its terminating
+    /// semicolon if any.
+    ///
+    /// Returns `None` if there is no statement at `index`, or if the list was not parsed from
+    /// source code.
+    ///
+    /// Spans are only recorded for the statements of a list. Nested statement lists, such as the
+    /// statements of a block, hold the spans of their own statements, while the spans of
+    /// expressions are given by [`expression_span()`](Self::expression_span).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa::{parse, syntax::ast::{Position, Span}};
+    /// let list = parse("let a = 1;\n  a + 2;", false).unwrap();
+    ///
+    /// assert_eq!(
+    ///     list.span(1),
+    ///     Some(Span::new(Position::new(2, 3), Position::new(2, 9)))
+    /// );
+    /// ```
+    #[inline]
+    pub fn span(&self, index: usize) -> Option<Span> {
+        self.spans.get(index).copied()
+    }
+
+    /// Gets the span in the source code of an expression of the list, at any depth, such as the
+    /// operand of an operator or an argument of a call in the body of a function.
+    ///
+    /// Returns `None` if `expression` is not an expression of the list, or if the list is not a
+    /// script or a module parsed from source code. The span of a parenthesized expression includes
+    /// its parentheses, and the parameters of arrow functions have no span.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa::{parse, syntax::ast::{node::Node, Position, Span}};
+    /// let list = parse("let a = 1;\nb + c * 2;", false).unwrap();
+    /// let sum = &list.items()[1];
+    ///
+    /// assert_eq!(
+    ///     list.expression_span(sum),
+    ///     Some(Span::new(Position::new(2, 1), Position::new(2, 10)))
+    /// );
+    /// if let Node::BinOp(sum) = sum {
+    ///     assert_eq!(
+    ///         list.expression_span(sum.rhs()),
+    ///         Some(Span::new(Position::new(2, 5), Position::new(2, 10)))
+    ///     );
+    /// }
+    /// ```
+    #[inline]
+    pub fn expression_span(&self, expression: &Node) -> Option<Span> {
+        self.expression_spans
+            .get(&(expression as *const Node))
+            .copied()
+    }
+
+    /// Returns an iterator over the statements of the list.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, Node> {
+        self.items.iter()
+    }
+
     /// Implements the display formatting with indentation.
+    ///
+    /// Every statement is written on its own line, indented `indentation` times.
     pub(in crate::syntax::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
@@ -45,28 +177,52 @@ impl StatementList {
         // Print statements
         for node in self.items.iter() {
             f.write_str(&indent)?;
-            node.display(f, indentation + 1)?;
+            node.display(f, indentation)?;
 
-            match node {
-                Node::Block(_) | Node::If(_) | Node::Switch(_) | Node::WhileLoop(_) => {}
-                _ => write!(f, ";")?,
+            if !node.ends_with_block() {
+                write!(f, ";")?;
             }
             writeln!(f)?;
         }
         Ok(())
     }
 
+    /// Formats the statements as source code, with every statement indented `indentation`
+    /// times, and the bodies of nested statements indented one more time than the statement
+    /// they belong to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa::parse;
+    /// let list = parse("if (a) { b(); } else { while (c) d(); }", false).unwrap();
+    ///
+    /// assert_eq!(
+    ///     list.to_source_pretty(0),
+    ///     "if (a) {\n    b();\n} else {\n    while (c) d();\n}\n"
+    /// );
+    /// ```
+    pub fn to_source_pretty(&self, indentation: usize) -> String {
+        struct Pretty<'a>(&'a StatementList, usize);
+
+        impl fmt::Display for Pretty<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.0.display(f, self.1)
+            }
+        }
+
+        Pretty(self, indentation).to_string()
+    }
+
     pub fn lexically_declared_names(&self) -> HashSet<&str> {
         let mut set = HashSet::new();
         for stmt in self.items() {
             if let Node::LetDeclList(decl_list) | Node::ConstDeclList(decl_list) = stmt {
-                for decl in decl_list.as_ref() {
-                    if !set.insert(decl.name()) {
-                        // It is a Syntax Error if the LexicallyDeclaredNames of StatementList contains any duplicate entries.
-                        // https://tc39.es/ecma262/#sec-block-static-semantics-early-errors
-                        unreachable!("Redeclaration of {}", decl.name());
-                    }
-                }
+                // It is a Syntax Error if the LexicallyDeclaredNames of StatementList contains any
+                // duplicate entries, which the parser reports. A statement list that wasn't
+                // produced by the parser may still contain them, so they are not a reason to panic.
+                // https://tc39.es/ecma262/#sec-block-static-semantics-early-errors
+                set.extend(decl_list.as_ref().iter().flat_map(|decl| decl.idents()));
             }
         }
         set
@@ -75,8 +231,14 @@ impl StatementList {
     pub fn function_declared_names(&self) -> HashSet<&str> {
         let mut set = HashSet::new();
         for stmt in self.items() {
-            if let Node::FunctionDecl(decl) = stmt {
-                set.insert(decl.name());
+            match stmt {
+                Node::FunctionDecl(decl) => {
+                    set.insert(decl.name());
+                }
+                Node::GeneratorDecl(decl) => {
+                    set.insert(decl.name());
+                }
+                _ => {}
             }
         }
         set
@@ -87,7 +249,7 @@ impl StatementList {
         for stmt in self.items() {
             if let Node::VarDeclList(decl_list) = stmt {
                 for decl in decl_list.as_ref() {
-                    set.insert(decl.name());
+                    set.extend(decl.idents());
                 }
             }
         }
@@ -124,7 +286,10 @@ impl Executable for StatementList {
                     // Continue execution
                 }
                 #[cfg(feature = "vm")]
-                InterpreterState::Error => {}
+                InterpreterState::Error => {
+                    // Leave the state as is, so that the enclosing statements stop as well.
+                    break;
+                }
             }
             if i + 1 == self.items().len() {
                 obj = val;
@@ -151,7 +316,41 @@ where
     T: Into<Box<[Node]>>,
 {
     fn from(stm: T) -> Self {
-        Self { items: stm.into() }
+        Self {
+            items: stm.into(),
+            spans: Box::new([]),
+            expression_spans: HashMap::new(),
+        }
+    }
+}
+
+/// The spans of the expressions are keyed by the nodes of the list, so they are not kept by its
+/// clones.
+impl Clone for StatementList {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+            spans: self.spans.clone(),
+            expression_spans: HashMap::new(),
+        }
+    }
+}
+
+/// Statement lists are compared by their statements only, so that a parsed list is equal to a
+/// list of the same statements created without spans.
+impl PartialEq for StatementList {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+    }
+}
+
+impl<'a> IntoIterator for &'a StatementList {
+    type Item = &'a Node;
+    type IntoIter = std::slice::Iter<'a, Node>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
     }
 }
 
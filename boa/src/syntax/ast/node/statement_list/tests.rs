@@ -0,0 +1,196 @@
+use crate::syntax::{
+    ast::{
+        node::{Declaration, DeclarationList, Node, StatementList},
+        Const, Position, Span,
+    },
+    Parser,
+};
+
+#[test]
+fn lexically_declared_names_with_duplicates() {
+    let declaration = |name| -> Node {
+        DeclarationList::Let(vec![Declaration::new(name, Some(Const::from(1).into()))].into())
+            .into()
+    };
+    let list = StatementList::from(vec![declaration("a"), declaration("b"), declaration("a")]);
+
+    let names = list.lexically_declared_names();
+    assert_eq!(names.len(), 2);
+    assert!(names.contains("a"));
+    assert!(names.contains("b"));
+}
+
+#[test]
+fn iterate_statements() {
+    let list = StatementList::from(vec![Node::from(Const::from(1)), Node::from(Const::from(2))]);
+
+    assert_eq!(list.len(), 2);
+    assert!(!list.is_empty());
+    assert!(StatementList::from(Vec::new()).is_empty());
+
+    let items: Vec<&Node> = (&list).into_iter().collect();
+    assert_eq!(
+        items,
+        vec![&Node::from(Const::from(1)), &Node::from(Const::from(2))]
+    );
+}
+
+#[test]
+fn parsed_statement_spans() {
+    let src = "let a = 1;\nfor (let k in a) {\n  a;\n}\na + 2";
+    let list = Parser::new(src.as_bytes(), false)
+        .parse_all()
+        .expect("failed to parse source");
+
+    assert_eq!(
+        list.span(0),
+        Some(Span::new(Position::new(1, 1), Position::new(1, 11)))
+    );
+    assert_eq!(
+        list.span(1),
+        Some(Span::new(Position::new(2, 1), Position::new(4, 2)))
+    );
+    assert_eq!(
+        list.span(2),
+        Some(Span::new(Position::new(5, 1), Position::new(5, 6)))
+    );
+    assert_eq!(list.span(3), None);
+
+    // The statements of nested statement lists have their own spans.
+    let body = match list.items() {
+        [_, Node::ForInLoop(for_in_loop), _] => for_in_loop.body(),
+        items => panic!("unexpected statements: {:?}", items),
+    };
+    let block = match body {
+        Node::Block(block) => block,
+        body => panic!("unexpected for-in body: {:?}", body),
+    };
+    assert_eq!(
+        block.statements().span(0),
+        Some(Span::new(Position::new(3, 3), Position::new(3, 5)))
+    );
+
+    let list = StatementList::from(vec![Node::from(Const::from(1))]);
+    assert_eq!(list.span(0), None);
+}
+
+#[test]
+fn parsed_expression_spans() {
+    let src = "let a = (1 + b) * c;\nfunction f() {\n  return a.x;\n}";
+    let list = Parser::new(src.as_bytes(), false)
+        .parse_all()
+        .expect("failed to parse source");
+
+    let (product, body) = match list.items() {
+        [Node::LetDeclList(declarations), Node::FunctionDecl(function)] => (
+            declarations.as_ref()[0]
+                .init()
+                .expect("missing initializer"),
+            function.body(),
+        ),
+        items => panic!("unexpected statements: {:?}", items),
+    };
+    assert_eq!(
+        list.expression_span(product),
+        Some(Span::new(Position::new(1, 9), Position::new(1, 20)))
+    );
+    let sum = match product {
+        Node::BinOp(product) => {
+            assert_eq!(
+                list.expression_span(product.rhs()),
+                Some(Span::new(Position::new(1, 19), Position::new(1, 20)))
+            );
+            product.lhs()
+        }
+        product => panic!("unexpected initializer: {:?}", product),
+    };
+    assert_eq!(
+        list.expression_span(sum),
+        Some(Span::new(Position::new(1, 9), Position::new(1, 16)))
+    );
+
+    // The expressions of function bodies are spanned by the outermost list.
+    let field = match body {
+        [Node::Return(ret)] => ret.expr().expect("missing return value"),
+        body => panic!("unexpected function body: {:?}", body),
+    };
+    assert_eq!(
+        list.expression_span(field),
+        Some(Span::new(Position::new(3, 10), Position::new(3, 13)))
+    );
+
+    assert_eq!(list.expression_span(&Node::from(Const::from(1))), None);
+}
+
+/// Checks that `src` is formatted as `expected`, and that the formatted source parses back into
+/// the same statements.
+#[track_caller]
+fn check_pretty(src: &str, expected: &str) {
+    let list = Parser::new(src.as_bytes(), false)
+        .parse_all()
+        .expect("failed to parse source");
+    let pretty = list.to_source_pretty(0);
+    assert_eq!(pretty, expected);
+
+    let reparsed = Parser::new(pretty.as_bytes(), false)
+        .parse_all()
+        .expect("failed to parse formatted source");
+    assert_eq!(reparsed, list);
+}
+
+#[test]
+fn pretty_function_and_conditionals() {
+    check_pretty(
+        "function f(a) { if (a) { return 1; } else return 2; }",
+        "function f(a) {\n    if (a) {\n        return 1;\n    } else return 2;\n}\n",
+    );
+}
+
+#[test]
+fn pretty_loops() {
+    check_pretty(
+        "for (let i = 0; i < 3; i = i + 1) { while (i) break; } do { a(); } while (b)",
+        "for (let i = 0; i < 3; i = i + 1) {\n    while (i) break;\n}\ndo {\n    a();\n} while (b);\n",
+    );
+}
+
+#[test]
+fn pretty_switch() {
+    check_pretty(
+        "switch (a) { case 1: b(); break; default: c(); }",
+        "switch (a) {\n    case 1:\n        b();\n        break;\n    default:\n        c();\n}\n",
+    );
+}
+
+#[test]
+fn pretty_labels() {
+    check_pretty(
+        "outer: while (x) { inner: do { continue outer; } while (y) } blk: { break blk; } sw: switch (a) { case 1: break sw; }",
+        "outer: while (x) {\n    inner: do {\n        continue outer;\n    } while (y);\n}\nblk: {\n    break blk;\n}\nsw: switch (a) {\n    case 1:\n        break sw;\n}\n",
+    );
+}
+
+#[test]
+fn pretty_try() {
+    check_pretty(
+        "try { a(); } catch (e) { b(); } finally { c(); }",
+        "try {\n    a();\n} catch (e) {\n    b();\n} finally {\n    c();\n}\n",
+    );
+}
+
+#[test]
+fn pretty_object() {
+    check_pretty(
+        "let o = { a: 1, get b() { return 2; }, c };",
+        "let o = {\n    a: 1,\n    get b() {\n        return 2;\n    },\n    c,\n};\n",
+    );
+}
+
+#[test]
+fn pretty_indentation() {
+    let list = Parser::new("if (a) b();".as_bytes(), false)
+        .parse_all()
+        .expect("failed to parse source");
+
+    assert_eq!(list.to_source_pretty(2), "        if (a) b();\n");
+}
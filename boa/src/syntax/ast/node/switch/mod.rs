@@ -69,20 +69,52 @@ pub struct Switch {
     val: Box<Node>,
     cases: Box<[Case]>,
     default: Option<StatementList>,
+    default_position: usize,
+    label: Option<Box<str>>,
 }
 
 impl Switch {
-    /// Creates a `Switch` AST node.
+    /// Creates a `Switch` AST node, with the default clause, if any, after all the cases.
     pub fn new<V, C, D>(val: V, cases: C, default: Option<D>) -> Self
     where
         V: Into<Node>,
         C: Into<Box<[Case]>>,
         D: Into<StatementList>,
     {
+        let cases = cases.into();
+        let default_position = cases.len();
+        Self::with_default_position(val, cases, default, default_position)
+    }
+
+    /// Creates a `Switch` AST node, with the default clause, if any, after the first
+    /// `default_position` cases.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `default_position` is greater than the number of cases.
+    pub fn with_default_position<V, C, D>(
+        val: V,
+        cases: C,
+        default: Option<D>,
+        default_position: usize,
+    ) -> Self
+    where
+        V: Into<Node>,
+        C: Into<Box<[Case]>>,
+        D: Into<StatementList>,
+    {
+        let cases = cases.into();
+        assert!(
+            default_position <= cases.len(),
+            "the default clause must come before, between or after the cases"
+        );
+
         Self {
             val: Box::new(val.into()),
-            cases: cases.into(),
+            cases,
             default: default.map(D::into),
+            default_position,
+            label: None,
         }
     }
 
@@ -101,98 +133,125 @@ impl Switch {
         self.default.as_ref().map(StatementList::items)
     }
 
+    /// Gets the number of cases that come before the default clause.
+    pub fn default_position(&self) -> usize {
+        self.default_position
+    }
+
+    /// Gets the label of this switch statement, if any.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_ref().map(Box::as_ref)
+    }
+
+    /// Sets the label of this switch statement.
+    pub fn set_label(&mut self, label: Box<str>) {
+        self.label = Some(label);
+    }
+
+    /// Gets the bodies of the case and default clauses, in the order they appear in the source.
+    fn clauses(&self) -> Vec<&StatementList> {
+        let mut clauses: Vec<_> = self.cases.iter().map(Case::body).collect();
+        if let Some(ref default) = self.default {
+            clauses.insert(self.default_position, default);
+        }
+        clauses
+    }
+
     /// Implements the display formatting with indentation.
     pub(in crate::syntax::ast::node) fn display(
         &self,
         f: &mut fmt::Formatter<'_>,
-        indent: usize,
+        indentation: usize,
     ) -> fmt::Result {
+        let indent = "    ".repeat(indentation + 1);
+        if let Some(label) = self.label() {
+            write!(f, "{}: ", label)?;
+        }
         writeln!(f, "switch ({}) {{", self.val())?;
-        for e in self.cases().iter() {
+        for (i, e) in self.cases().iter().enumerate() {
+            if i == self.default_position {
+                self.display_default(f, indentation + 1)?;
+            }
             writeln!(f, "{}case {}:", indent, e.condition())?;
-            e.body().display(f, indent)?;
+            e.body().display(f, indentation + 2)?;
+        }
+
+        if self.default_position == self.cases.len() {
+            self.display_default(f, indentation + 1)?;
         }
+        write!(f, "{}}}", "    ".repeat(indentation))
+    }
 
+    /// Displays the default clause, if any.
+    fn display_default(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         if let Some(ref default) = self.default {
-            writeln!(f, "{}default:", indent)?;
-            default.display(f, indent + 1)?;
+            writeln!(f, "{}default:", "    ".repeat(indentation))?;
+            default.display(f, indentation + 1)?;
         }
-        writeln!(f, "{}}}", indent)
+        Ok(())
     }
 }
 
 impl Executable for Switch {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let val = self.val().run(context)?;
-        let mut result = Value::null();
-        let mut matched = false;
         context
             .executor()
             .set_current_state(InterpreterState::Executing);
 
-        // If a case block does not end with a break statement then subsequent cases will be run without
-        // checking their conditions until a break is encountered.
-        let mut fall_through: bool = false;
-
-        for case in self.cases().iter() {
-            let cond = case.condition();
-            let block = case.body();
-            if fall_through || val.strict_equals(&cond.run(context)?) {
-                matched = true;
-                let result = block.run(context)?;
-                match context.executor().get_current_state() {
-                    InterpreterState::Return => {
-                        // Early return.
-                        return Ok(result);
-                    }
-                    InterpreterState::Break(_label) => {
-                        // TODO, break to a label.
-                        // Break statement encountered so therefore end switch statement.
-                        context
-                            .executor()
-                            .set_current_state(InterpreterState::Executing);
-                        break;
-                    }
-                    InterpreterState::Continue(_label) => {
-                        // TODO, continue to a label.
-                        break;
-                    }
-                    InterpreterState::Executing => {
-                        // Continuing execution / falling through to next case statement(s).
-                        fall_through = true;
-                    }
-                    #[cfg(feature = "vm")]
-                    InterpreterState::Error => {}
-                }
+        // The cases are matched in the order they appear in, using the strict comparison (`===`).
+        // The default clause is only taken if no case matches, wherever it appears.
+        // <https://tc39.es/ecma262/#sec-runtime-semantics-caseblockevaluation>
+        let mut matched = None;
+        for (i, case) in self.cases().iter().enumerate() {
+            if val.strict_equals(&case.condition().run(context)?) {
+                matched = Some(i);
+                break;
             }
         }
 
-        if !matched {
-            if let Some(default) = self.default() {
-                context
-                    .executor()
-                    .set_current_state(InterpreterState::Executing);
-                for (i, item) in default.iter().enumerate() {
-                    let val = item.run(context)?;
-                    match context.executor().get_current_state() {
-                        InterpreterState::Return => {
-                            // Early return.
-                            result = val;
-                            break;
-                        }
-                        InterpreterState::Break(_label) => {
-                            // TODO, break to a label.
-
-                            // Early break.
-                            break;
-                        }
-                        _ => {
-                            // Continue execution
-                        }
-                    }
-                    if i == default.len() - 1 {
-                        result = val;
-                    }
+        let start = match matched {
+            Some(i) if self.default.is_some() && i >= self.default_position => i + 1,
+            Some(i) => i,
+            None if self.default.is_some() => self.default_position,
+            None => return Ok(Value::undefined()),
+        };
+
+        // If a clause does not end with a break statement then the following clauses are run
+        // without checking their conditions until a break is encountered.
+        let mut result = Value::undefined();
+        for body in &self.clauses()[start..] {
+            result = body.run(context)?;
+            match context.executor().get_current_state() {
+                InterpreterState::Return => {
+                    // Early return.
+                    return Ok(result);
+                }
+                InterpreterState::Break(None) => {
+                    // Break statement encountered so therefore end switch statement.
+                    context
+                        .executor()
+                        .set_current_state(InterpreterState::Executing);
+                    break;
+                }
+                InterpreterState::Break(Some(label)) if Some(&**label) == self.label() => {
+                    // A labelled break targeting this switch statement completes it normally.
+                    context
+                        .executor()
+                        .set_current_state(InterpreterState::Executing);
+                    break;
+                }
+                InterpreterState::Break(Some(_)) | InterpreterState::Continue(_) => {
+                    // Leave the state as is, for the enclosing labelled statement or loop.
+                    break;
+                }
+                InterpreterState::Executing => {
+                    // Continuing execution / falling through to next clause.
+                }
+                #[cfg(feature = "vm")]
+                InterpreterState::Error => {
+                    // Leave the state as is, so that the enclosing statements stop as well.
+                    break;
                 }
             }
         }
@@ -206,3 +206,130 @@ fn bigger_switch_example() {
         assert_eq!(&exec(&scenario), val);
     }
 }
+
+#[test]
+fn default_between_cases_switch() {
+    let scenario = r#"
+        function run(a) {
+            let log = "";
+            switch (a) {
+                case 1:
+                    log += "one ";
+                default:
+                    log += "default ";
+                case 2:
+                    log += "two ";
+                    break;
+                case 3:
+                    log += "three ";
+            }
+            return log;
+        }
+
+        [run(1), run(2), run(3), run(4)].join("|");
+    "#;
+    assert_eq!(
+        &exec(scenario),
+        "\"one default two |two |three |default two \""
+    );
+}
+
+#[test]
+fn fall_through_switch() {
+    let scenario = r#"
+        let log = [];
+        switch (2) {
+            case 1:
+                log.push(1);
+            case 2:
+                log.push(2);
+            case 3:
+                log.push(3);
+            case 4:
+                log.push(4);
+                break;
+            case 5:
+                log.push(5);
+        }
+
+        log.join();
+    "#;
+    assert_eq!(&exec(scenario), "\"2,3,4\"");
+}
+
+#[test]
+fn strict_equality_switch() {
+    let scenario = r#"
+        let a = "none";
+        switch (0) {
+            case false:
+                a = "false";
+                break;
+            case "0":
+                a = "string";
+                break;
+            case 0:
+                a = "zero";
+                break;
+        }
+
+        a;
+    "#;
+    assert_eq!(&exec(scenario), "\"zero\"");
+}
+
+#[test]
+fn break_in_default_switch() {
+    let scenario = r#"
+        let a = 0;
+        for (let i = 0; i < 3; i++) {
+            switch (i) {
+                default:
+                    a++;
+                    break;
+            }
+        }
+
+        a;
+    "#;
+    assert_eq!(&exec(scenario), "3");
+}
+
+#[test]
+fn labelled_break_out_of_switch() {
+    let scenario = r#"
+        let a = 0;
+        outer: for (let i = 0; i < 3; i++) {
+            switch (i) {
+                case 1:
+                    break outer;
+            }
+            a++;
+        }
+
+        a;
+    "#;
+    assert_eq!(&exec(scenario), "1");
+}
+
+#[test]
+fn labelled_break_targeting_switch() {
+    let scenario = r#"
+        let a = 0;
+        function after() {
+            a += 10;
+        }
+
+        lbl: switch (1) {
+            case 1:
+                a++;
+                break lbl;
+            case 2:
+                a = 100;
+        }
+        after();
+
+        a;
+    "#;
+    assert_eq!(&exec(scenario), "11");
+}
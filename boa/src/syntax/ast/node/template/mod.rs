@@ -29,6 +29,11 @@ impl TemplateLit {
     pub fn new(elements: Vec<TemplateElement>) -> Self {
         TemplateLit { elements }
     }
+
+    /// Gets the strings and expressions of the template literal, in order.
+    pub fn elements(&self) -> &[TemplateElement] {
+        &self.elements
+    }
 }
 
 impl Executable for TemplateLit {
@@ -87,28 +92,22 @@ impl TaggedTemplate {
             exprs,
         }
     }
+
+    /// Gets the tag function of the tagged template.
+    pub fn tag(&self) -> &Node {
+        &self.tag
+    }
+
+    /// Gets the substitution expressions of the tagged template.
+    pub fn exprs(&self) -> &[Node] {
+        &self.exprs
+    }
 }
 
 impl Executable for TaggedTemplate {
     fn run(&self, context: &mut Context) -> Result<Value> {
         let _timer = BoaProfiler::global().start_event("TaggedTemplate", "exec");
 
-        let template_object = Array::new_array(context);
-        let raw_array = Array::new_array(context);
-
-        for (i, raw) in self.raws.iter().enumerate() {
-            raw_array.set_field(i, Value::from(raw), context)?;
-        }
-
-        for (i, cooked) in self.cookeds.iter().enumerate() {
-            if let Some(cooked) = cooked {
-                template_object.set_field(i, Value::from(cooked), context)?;
-            } else {
-                template_object.set_field(i, Value::undefined(), context)?;
-            }
-        }
-        template_object.set_field("raw", raw_array, context)?;
-
         let (this, func) = match *self.tag {
             Node::GetConstField(ref get_const_field) => {
                 let mut obj = get_const_field.obj().run(context)?;
@@ -121,7 +120,10 @@ impl Executable for TaggedTemplate {
                 )
             }
             Node::GetField(ref get_field) => {
-                let obj = get_field.obj().run(context)?;
+                let mut obj = get_field.obj().run(context)?;
+                if obj.get_type() != Type::Object {
+                    obj = Value::Object(obj.to_object(context)?);
+                }
                 let field = get_field.field().run(context)?;
                 (
                     obj.clone(),
@@ -131,6 +133,22 @@ impl Executable for TaggedTemplate {
             _ => (context.global_object().into(), self.tag.run(context)?),
         };
 
+        let template_object = Array::new_array(context);
+        let raw_array = Array::new_array(context);
+
+        for (i, raw) in self.raws.iter().enumerate() {
+            raw_array.set_field(i, Value::from(raw), context)?;
+        }
+
+        for (i, cooked) in self.cookeds.iter().enumerate() {
+            if let Some(cooked) = cooked {
+                template_object.set_field(i, Value::from(cooked), context)?;
+            } else {
+                template_object.set_field(i, Value::undefined(), context)?;
+            }
+        }
+        template_object.set_field("raw", raw_array, context)?;
+
         let mut args = vec![template_object];
         for expr in self.exprs.iter() {
             args.push(expr.run(context)?);
@@ -146,6 +164,9 @@ impl fmt::Display for TaggedTemplate {
         for (raw, expr) in self.raws.iter().zip(self.exprs.iter()) {
             write!(f, "{}${{{}}}", raw, expr)?;
         }
+        if let Some(last) = self.raws.last() {
+            write!(f, "{}", last)?;
+        }
         write!(f, "`")
     }
 }
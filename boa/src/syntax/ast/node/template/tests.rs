@@ -29,3 +29,47 @@ fn tagged_template() {
         r#"[ "result: ", " & ", "", "result: ", " \x26 ", "", 10, 20 ]"#
     );
 }
+
+#[test]
+fn tagged_template_concatenation() {
+    let scenario = r#"
+        function tag(strings, ...values) {
+            let result = strings[0];
+            for (let i = 0; i < values.length; i++) {
+                result += "[" + values[i] + "]" + strings[i + 1];
+            }
+            return result;
+        }
+        let x = 1;
+        tag`a${x}b${x + 1}c`;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"a[1]b[2]c\"");
+}
+
+#[test]
+fn tagged_template_cooked_and_raw() {
+    let scenario = r#"
+        function tag(strings) {
+            return strings[0].length + " " + strings.raw[0].length;
+        }
+        tag`\nA`;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"2 3\"");
+}
+
+#[test]
+fn tagged_template_method_this() {
+    let scenario = r#"
+        let obj = {
+            prefix: "p",
+            tag(strings, value) {
+                return this.prefix + strings[0] + value;
+            }
+        };
+        obj.tag`-${1}`;
+        "#;
+
+    assert_eq!(&exec(scenario), "\"p-1\"");
+}
@@ -80,7 +80,7 @@ impl Try {
         f: &mut fmt::Formatter<'_>,
         indentation: usize,
     ) -> fmt::Result {
-        write!(f, "{}try ", "    ".repeat(indentation))?;
+        f.write_str("try ")?;
         self.block.display(f, indentation)?;
 
         if let Some(ref catch) = self.catch {
@@ -183,7 +183,7 @@ impl Catch {
     pub(super) fn display(&self, f: &mut fmt::Formatter<'_>, indentation: usize) -> fmt::Result {
         f.write_str(" catch")?;
         if let Some(ref param) = self.parameter {
-            write!(f, "({})", param)?;
+            write!(f, " ({})", param)?;
         }
         f.write_str(" ")?;
         self.block.display(f, indentation)
@@ -93,3 +93,17 @@ fn catch_binding_finally() {
     "#;
     assert_eq!(&exec(scenario), "30");
 }
+
+#[test]
+fn catch_without_binding_assigns_global() {
+    let scenario = r#"
+        try {
+            throw 1;
+        } catch {
+            done = true;
+        }
+
+        done;
+    "#;
+    assert_eq!(&exec(scenario), "true");
+}
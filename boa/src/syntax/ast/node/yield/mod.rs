@@ -0,0 +1,74 @@
+//! Yield expression node.
+
+use super::Node;
+use crate::{exec::Executable, BoaProfiler, Context, Result, Value};
+use gc::{Finalize, Trace};
+use std::fmt;
+
+#[cfg(feature = "deser")]
+use serde::{Deserialize, Serialize};
+
+/// The `yield` keyword is used to pause and resume a generator function.
+///
+/// A `yield*` expression delegates to another iterable, yielding each of its values in turn.
+///
+/// More information:
+///  - [ECMAScript reference][spec]
+///  - [MDN documentation][mdn]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+#[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
+#[derive(Clone, Debug, Trace, Finalize, PartialEq)]
+pub struct Yield {
+    expr: Option<Box<Node>>,
+    delegate: bool,
+}
+
+impl Yield {
+    /// Creates a new `Yield` AST node.
+    pub fn new<E, OE>(expr: OE, delegate: bool) -> Self
+    where
+        E: Into<Node>,
+        OE: Into<Option<E>>,
+    {
+        Self {
+            expr: expr.into().map(E::into).map(Box::new),
+            delegate,
+        }
+    }
+
+    /// Gets the expression of this `Yield` statement.
+    pub fn expr(&self) -> Option<&Node> {
+        self.expr.as_ref().map(Box::as_ref)
+    }
+
+    /// Returns `true` if this is a `yield*` expression.
+    pub fn delegate(&self) -> bool {
+        self.delegate
+    }
+}
+
+impl Executable for Yield {
+    fn run(&self, context: &mut Context) -> Result<Value> {
+        let _timer = BoaProfiler::global().start_event("Yield", "exec");
+        // Generator bodies never run, since calling a generator function throws.
+        context.throw_syntax_error("yield is only valid in generator functions")
+    }
+}
+
+impl From<Yield> for Node {
+    fn from(r#yield: Yield) -> Node {
+        Node::Yield(r#yield)
+    }
+}
+
+impl fmt::Display for Yield {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let y = if self.delegate { "yield*" } else { "yield" };
+        match self.expr() {
+            Some(ex) => write!(f, "{} {}", y, ex),
+            None => write!(f, "{}", y),
+        }
+    }
+}
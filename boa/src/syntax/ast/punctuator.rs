@@ -113,6 +113,8 @@ pub enum Punctuator {
     OpenBracket,
     /// `(`
     OpenParen,
+    /// `?.`
+    Optional,
     /// `|`
     Or,
     /// `**`
@@ -245,6 +247,7 @@ impl Display for Punctuator {
                 Self::OpenBlock => "{",
                 Self::OpenBracket => "[",
                 Self::OpenParen => "(",
+                Self::Optional => "?.",
                 Self::Or => "|",
                 Self::Exp => "**",
                 Self::Question => "?",
@@ -0,0 +1,916 @@
+//! Traversal of the Javascript Abstract Syntax Tree.
+//!
+//! The [`Visitor`] trait has a method for every kind of node of the AST. By default, every method
+//! visits the children of the node it is given, by calling the `walk_*` function of the same name,
+//! so a visitor only needs to implement the methods for the nodes it is interested in. When
+//! overriding a method, the corresponding `walk_*` function can be called to keep visiting the
+//! children of the node.
+//!
+//! ```
+//! use boa::syntax::ast::{node::Identifier, visitor::Visitor};
+//!
+//! #[derive(Default)]
+//! struct IdentifierCounter(usize);
+//!
+//! impl<'ast> Visitor<'ast> for IdentifierCounter {
+//!     fn visit_identifier(&mut self, _: &'ast Identifier) {
+//!         self.0 += 1;
+//!     }
+//! }
+//!
+//! let list = boa::parse("let a = b + c;", false).unwrap();
+//! let mut counter = IdentifierCounter::default();
+//! counter.visit_statement_list(&list);
+//!
+//! assert_eq!(counter.0, 3);
+//! ```
+
+use crate::syntax::ast::{
+    node::{
+        template::TemplateElement, ArrayDecl, ArrowFunctionDecl, Assign, AsyncFunctionDecl,
+        AsyncFunctionExpr, AwaitExpr, BinOp, BindingPatternTypeArray, BindingPatternTypeObject,
+        Block, Call, Case, Catch, ConditionalOp, Declaration, DeclarationList, DeclarationPattern,
+        DoWhileLoop, ExportDecl, ForInLoop, ForLoop, ForOfLoop, FormalParameter, FunctionDecl,
+        FunctionExpr, GeneratorDecl, GeneratorExpr, GetConstField, GetField, Identifier, If,
+        ImportDecl, New, Node, Object, Optional, OptionalOperationKind, PropertyDefinition,
+        PropertyName, Return, Spread, StatementList, Switch, TaggedTemplate, TemplateLit, Throw,
+        Try, UnaryOp, WhileLoop, Yield,
+    },
+    Const,
+};
+
+/// A visitor of the nodes of the AST.
+///
+/// `'ast` is the lifetime of the AST being visited, which allows visitors to keep references to
+/// the nodes they visit.
+pub trait Visitor<'ast> {
+    /// Visits a list of statements.
+    fn visit_statement_list(&mut self, list: &'ast StatementList) {
+        walk_statement_list(self, list)
+    }
+
+    /// Visits a node, dispatching to the method for the kind of the node.
+    fn visit_node(&mut self, node: &'ast Node) {
+        walk_node(self, node)
+    }
+
+    /// Visits an identifier.
+    fn visit_identifier(&mut self, _ident: &'ast Identifier) {}
+
+    /// Visits a literal.
+    fn visit_const(&mut self, _value: &'ast Const) {}
+
+    /// Visits an array literal.
+    fn visit_array_decl(&mut self, array: &'ast ArrayDecl) {
+        walk_array_decl(self, array)
+    }
+
+    /// Visits an arrow function.
+    fn visit_arrow_function_decl(&mut self, decl: &'ast ArrowFunctionDecl) {
+        walk_arrow_function_decl(self, decl)
+    }
+
+    /// Visits an assignment.
+    fn visit_assign(&mut self, assign: &'ast Assign) {
+        walk_assign(self, assign)
+    }
+
+    /// Visits an async function declaration.
+    fn visit_async_function_decl(&mut self, decl: &'ast AsyncFunctionDecl) {
+        walk_async_function_decl(self, decl)
+    }
+
+    /// Visits an async function expression.
+    fn visit_async_function_expr(&mut self, expr: &'ast AsyncFunctionExpr) {
+        walk_async_function_expr(self, expr)
+    }
+
+    /// Visits an await expression.
+    fn visit_await_expr(&mut self, expr: &'ast AwaitExpr) {
+        walk_await_expr(self, expr)
+    }
+
+    /// Visits a binary operation.
+    fn visit_bin_op(&mut self, op: &'ast BinOp) {
+        walk_bin_op(self, op)
+    }
+
+    /// Visits a block statement.
+    fn visit_block(&mut self, block: &'ast Block) {
+        walk_block(self, block)
+    }
+
+    /// Visits a call.
+    fn visit_call(&mut self, call: &'ast Call) {
+        walk_call(self, call)
+    }
+
+    /// Visits a conditional (ternary) operation.
+    fn visit_conditional_op(&mut self, op: &'ast ConditionalOp) {
+        walk_conditional_op(self, op)
+    }
+
+    /// Visits a `var`, `let` or `const` declaration list.
+    fn visit_declaration_list(&mut self, list: &'ast DeclarationList) {
+        walk_declaration_list(self, list)
+    }
+
+    /// Visits a single declaration.
+    fn visit_declaration(&mut self, decl: &'ast Declaration) {
+        walk_declaration(self, decl)
+    }
+
+    /// Visits a destructuring pattern.
+    fn visit_declaration_pattern(&mut self, pattern: &'ast DeclarationPattern) {
+        walk_declaration_pattern(self, pattern)
+    }
+
+    /// Visits a do-while loop.
+    fn visit_do_while_loop(&mut self, do_while: &'ast DoWhileLoop) {
+        walk_do_while_loop(self, do_while)
+    }
+
+    /// Visits an `export` declaration.
+    fn visit_export_decl(&mut self, decl: &'ast ExportDecl) {
+        walk_export_decl(self, decl)
+    }
+
+    /// Visits a for loop.
+    fn visit_for_loop(&mut self, for_loop: &'ast ForLoop) {
+        walk_for_loop(self, for_loop)
+    }
+
+    /// Visits a for-in loop.
+    fn visit_for_in_loop(&mut self, for_in: &'ast ForInLoop) {
+        walk_for_in_loop(self, for_in)
+    }
+
+    /// Visits a for-of loop.
+    fn visit_for_of_loop(&mut self, for_of: &'ast ForOfLoop) {
+        walk_for_of_loop(self, for_of)
+    }
+
+    /// Visits a formal parameter of a function.
+    fn visit_formal_parameter(&mut self, param: &'ast FormalParameter) {
+        walk_formal_parameter(self, param)
+    }
+
+    /// Visits a function declaration.
+    fn visit_function_decl(&mut self, decl: &'ast FunctionDecl) {
+        walk_function_decl(self, decl)
+    }
+
+    /// Visits a function expression.
+    fn visit_function_expr(&mut self, expr: &'ast FunctionExpr) {
+        walk_function_expr(self, expr)
+    }
+
+    /// Visits a generator declaration.
+    fn visit_generator_decl(&mut self, decl: &'ast GeneratorDecl) {
+        walk_generator_decl(self, decl)
+    }
+
+    /// Visits a generator expression.
+    fn visit_generator_expr(&mut self, expr: &'ast GeneratorExpr) {
+        walk_generator_expr(self, expr)
+    }
+
+    /// Visits a property access with a constant name, such as `a.b`.
+    fn visit_get_const_field(&mut self, get_field: &'ast GetConstField) {
+        walk_get_const_field(self, get_field)
+    }
+
+    /// Visits a property access with a computed name, such as `a[b]`.
+    fn visit_get_field(&mut self, get_field: &'ast GetField) {
+        walk_get_field(self, get_field)
+    }
+
+    /// Visits an if statement.
+    fn visit_if(&mut self, if_smt: &'ast If) {
+        walk_if(self, if_smt)
+    }
+
+    /// Visits an `import` declaration.
+    fn visit_import_decl(&mut self, _decl: &'ast ImportDecl) {}
+
+    /// Visits a `new` expression.
+    fn visit_new(&mut self, new: &'ast New) {
+        walk_new(self, new)
+    }
+
+    /// Visits an object literal.
+    fn visit_object(&mut self, object: &'ast Object) {
+        walk_object(self, object)
+    }
+
+    /// Visits a property definition of an object literal.
+    fn visit_property_definition(&mut self, property: &'ast PropertyDefinition) {
+        walk_property_definition(self, property)
+    }
+
+    /// Visits an optional chain.
+    fn visit_optional(&mut self, optional: &'ast Optional) {
+        walk_optional(self, optional)
+    }
+
+    /// Visits a return statement.
+    fn visit_return(&mut self, ret: &'ast Return) {
+        walk_return(self, ret)
+    }
+
+    /// Visits a spread element.
+    fn visit_spread(&mut self, spread: &'ast Spread) {
+        walk_spread(self, spread)
+    }
+
+    /// Visits a switch statement.
+    fn visit_switch(&mut self, switch: &'ast Switch) {
+        walk_switch(self, switch)
+    }
+
+    /// Visits a case clause of a switch statement.
+    fn visit_case(&mut self, case: &'ast Case) {
+        walk_case(self, case)
+    }
+
+    /// Visits a tagged template.
+    fn visit_tagged_template(&mut self, template: &'ast TaggedTemplate) {
+        walk_tagged_template(self, template)
+    }
+
+    /// Visits a template literal.
+    fn visit_template_lit(&mut self, template: &'ast TemplateLit) {
+        walk_template_lit(self, template)
+    }
+
+    /// Visits a throw statement.
+    fn visit_throw(&mut self, throw: &'ast Throw) {
+        walk_throw(self, throw)
+    }
+
+    /// Visits a try statement.
+    fn visit_try(&mut self, try_smt: &'ast Try) {
+        walk_try(self, try_smt)
+    }
+
+    /// Visits the catch clause of a try statement.
+    fn visit_catch(&mut self, catch: &'ast Catch) {
+        walk_catch(self, catch)
+    }
+
+    /// Visits a unary operation.
+    fn visit_unary_op(&mut self, op: &'ast UnaryOp) {
+        walk_unary_op(self, op)
+    }
+
+    /// Visits a while loop.
+    fn visit_while_loop(&mut self, while_loop: &'ast WhileLoop) {
+        walk_while_loop(self, while_loop)
+    }
+
+    /// Visits a yield expression.
+    fn visit_yield(&mut self, yield_expr: &'ast Yield) {
+        walk_yield(self, yield_expr)
+    }
+}
+
+/// Visits the statements of a list.
+pub fn walk_statement_list<'ast, V>(visitor: &mut V, list: &'ast StatementList)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    walk_nodes(visitor, list.items())
+}
+
+/// Visits a node with the method for the kind of the node.
+pub fn walk_node<'ast, V>(visitor: &mut V, node: &'ast Node)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    match node {
+        Node::ArrayDecl(array) => visitor.visit_array_decl(array),
+        Node::ArrowFunctionDecl(decl) => visitor.visit_arrow_function_decl(decl),
+        Node::Assign(assign) => visitor.visit_assign(assign),
+        Node::AsyncFunctionDecl(decl) => visitor.visit_async_function_decl(decl),
+        Node::AsyncFunctionExpr(expr) => visitor.visit_async_function_expr(expr),
+        Node::AwaitExpr(expr) => visitor.visit_await_expr(expr),
+        Node::BinOp(op) => visitor.visit_bin_op(op),
+        Node::Block(block) => visitor.visit_block(block),
+        Node::Call(call) => visitor.visit_call(call),
+        Node::ConditionalOp(op) => visitor.visit_conditional_op(op),
+        Node::Const(value) => visitor.visit_const(value),
+        Node::ConstDeclList(list) | Node::LetDeclList(list) | Node::VarDeclList(list) => {
+            visitor.visit_declaration_list(list)
+        }
+        Node::DoWhileLoop(do_while) => visitor.visit_do_while_loop(do_while),
+        Node::ExportDecl(decl) => visitor.visit_export_decl(decl),
+        Node::FunctionDecl(decl) => visitor.visit_function_decl(decl),
+        Node::FunctionExpr(expr) => visitor.visit_function_expr(expr),
+        Node::GeneratorDecl(decl) => visitor.visit_generator_decl(decl),
+        Node::GeneratorExpr(expr) => visitor.visit_generator_expr(expr),
+        Node::GetConstField(get_field) => visitor.visit_get_const_field(get_field),
+        Node::GetField(get_field) => visitor.visit_get_field(get_field),
+        Node::ForLoop(for_loop) => visitor.visit_for_loop(for_loop),
+        Node::ForInLoop(for_in) => visitor.visit_for_in_loop(for_in),
+        Node::ForOfLoop(for_of) => visitor.visit_for_of_loop(for_of),
+        Node::If(if_smt) => visitor.visit_if(if_smt),
+        Node::Identifier(ident) => visitor.visit_identifier(ident),
+        Node::ImportDecl(decl) => visitor.visit_import_decl(decl),
+        Node::New(new) => visitor.visit_new(new),
+        Node::Object(object) => visitor.visit_object(object),
+        Node::Optional(optional) => visitor.visit_optional(optional),
+        Node::Return(ret) => visitor.visit_return(ret),
+        Node::Switch(switch) => visitor.visit_switch(switch),
+        Node::Spread(spread) => visitor.visit_spread(spread),
+        Node::TaggedTemplate(template) => visitor.visit_tagged_template(template),
+        Node::TemplateLit(template) => visitor.visit_template_lit(template),
+        Node::Throw(throw) => visitor.visit_throw(throw),
+        Node::Try(try_smt) => visitor.visit_try(try_smt),
+        Node::UnaryOp(op) => visitor.visit_unary_op(op),
+        Node::WhileLoop(while_loop) => visitor.visit_while_loop(while_loop),
+        Node::Yield(yield_expr) => visitor.visit_yield(yield_expr),
+        Node::Break(_) | Node::Continue(_) | Node::ImportMeta | Node::This | Node::Empty => {}
+    }
+}
+
+/// Visits every node of a slice, in order.
+fn walk_nodes<'ast, V>(visitor: &mut V, nodes: &'ast [Node])
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    for node in nodes {
+        visitor.visit_node(node);
+    }
+}
+
+/// Visits the parameters and the body of a function.
+fn walk_function<'ast, V>(visitor: &mut V, parameters: &'ast [FormalParameter], body: &'ast [Node])
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    for param in parameters {
+        visitor.visit_formal_parameter(param);
+    }
+    walk_nodes(visitor, body)
+}
+
+/// Visits the elements of an array literal.
+pub fn walk_array_decl<'ast, V>(visitor: &mut V, array: &'ast ArrayDecl)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    walk_nodes(visitor, array.as_ref())
+}
+
+/// Visits the parameters and the body of an arrow function.
+pub fn walk_arrow_function_decl<'ast, V>(visitor: &mut V, decl: &'ast ArrowFunctionDecl)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    walk_function(visitor, decl.params(), decl.body())
+}
+
+/// Visits the target and the value of an assignment.
+pub fn walk_assign<'ast, V>(visitor: &mut V, assign: &'ast Assign)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(assign.lhs());
+    visitor.visit_node(assign.rhs());
+}
+
+/// Visits the parameters and the body of an async function declaration.
+pub fn walk_async_function_decl<'ast, V>(visitor: &mut V, decl: &'ast AsyncFunctionDecl)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    walk_function(visitor, decl.parameters(), decl.body())
+}
+
+/// Visits the parameters and the body of an async function expression.
+pub fn walk_async_function_expr<'ast, V>(visitor: &mut V, expr: &'ast AsyncFunctionExpr)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    walk_function(visitor, expr.parameters(), expr.body())
+}
+
+/// Visits the awaited expression.
+pub fn walk_await_expr<'ast, V>(visitor: &mut V, expr: &'ast AwaitExpr)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(expr.expr())
+}
+
+/// Visits the operands of a binary operation.
+pub fn walk_bin_op<'ast, V>(visitor: &mut V, op: &'ast BinOp)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(op.lhs());
+    visitor.visit_node(op.rhs());
+}
+
+/// Visits the statements of a block.
+pub fn walk_block<'ast, V>(visitor: &mut V, block: &'ast Block)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    walk_nodes(visitor, block.items())
+}
+
+/// Visits the callee and the arguments of a call.
+pub fn walk_call<'ast, V>(visitor: &mut V, call: &'ast Call)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(call.expr());
+    walk_nodes(visitor, call.args())
+}
+
+/// Visits the condition and the branches of a conditional operation.
+pub fn walk_conditional_op<'ast, V>(visitor: &mut V, op: &'ast ConditionalOp)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(op.cond());
+    visitor.visit_node(op.if_true());
+    visitor.visit_node(op.if_false());
+}
+
+/// Visits the declarations of a declaration list.
+pub fn walk_declaration_list<'ast, V>(visitor: &mut V, list: &'ast DeclarationList)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    for decl in list.as_ref() {
+        visitor.visit_declaration(decl);
+    }
+}
+
+/// Visits the declared identifier or pattern, and the initializer of a declaration.
+pub fn walk_declaration<'ast, V>(visitor: &mut V, decl: &'ast Declaration)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    match decl {
+        Declaration::Identifier { ident, init } => {
+            visitor.visit_identifier(ident);
+            if let Some(init) = init {
+                visitor.visit_node(init);
+            }
+        }
+        Declaration::Pattern(pattern) => visitor.visit_declaration_pattern(pattern),
+    }
+}
+
+/// Visits the default initializers and nested patterns of a destructuring pattern, and its
+/// initializer.
+pub fn walk_declaration_pattern<'ast, V>(visitor: &mut V, pattern: &'ast DeclarationPattern)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    match pattern {
+        DeclarationPattern::Object(object) => {
+            for binding in object.bindings() {
+                match binding {
+                    BindingPatternTypeObject::SingleName { default_init, .. } => {
+                        if let Some(init) = default_init {
+                            visitor.visit_node(init);
+                        }
+                    }
+                    BindingPatternTypeObject::BindingPattern {
+                        pattern,
+                        default_init,
+                        ..
+                    } => {
+                        visitor.visit_declaration_pattern(pattern);
+                        if let Some(init) = default_init {
+                            visitor.visit_node(init);
+                        }
+                    }
+                    BindingPatternTypeObject::RestProperty { .. } => {}
+                }
+            }
+        }
+        DeclarationPattern::Array(array) => {
+            for binding in array.bindings() {
+                match binding {
+                    BindingPatternTypeArray::SingleName { default_init, .. } => {
+                        if let Some(init) = default_init {
+                            visitor.visit_node(init);
+                        }
+                    }
+                    BindingPatternTypeArray::BindingPattern {
+                        pattern,
+                        default_init,
+                    } => {
+                        visitor.visit_declaration_pattern(pattern);
+                        if let Some(init) = default_init {
+                            visitor.visit_node(init);
+                        }
+                    }
+                    BindingPatternTypeArray::BindingPatternRest { pattern } => {
+                        visitor.visit_declaration_pattern(pattern)
+                    }
+                    BindingPatternTypeArray::Elision
+                    | BindingPatternTypeArray::SingleNameRest { .. } => {}
+                }
+            }
+        }
+    }
+
+    if let Some(init) = pattern.init() {
+        visitor.visit_node(init);
+    }
+}
+
+/// Visits the body and the condition of a do-while loop.
+pub fn walk_do_while_loop<'ast, V>(visitor: &mut V, do_while: &'ast DoWhileLoop)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(do_while.body());
+    visitor.visit_node(do_while.cond());
+}
+
+/// Visits the exported declaration or expression of an `export` declaration, if any.
+pub fn walk_export_decl<'ast, V>(visitor: &mut V, decl: &'ast ExportDecl)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    match decl {
+        ExportDecl::Declaration(node) | ExportDecl::Default(node) => visitor.visit_node(node),
+        ExportDecl::Named { .. } | ExportDecl::All { .. } => {}
+    }
+}
+
+/// Visits the initializer, the condition, the final expression and the body of a for loop.
+pub fn walk_for_loop<'ast, V>(visitor: &mut V, for_loop: &'ast ForLoop)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    if let Some(init) = for_loop.init() {
+        visitor.visit_node(init);
+    }
+    if let Some(condition) = for_loop.condition() {
+        visitor.visit_node(condition);
+    }
+    if let Some(final_expr) = for_loop.final_expr() {
+        visitor.visit_node(final_expr);
+    }
+    visitor.visit_node(for_loop.body());
+}
+
+/// Visits the variable, the object and the body of a for-in loop.
+pub fn walk_for_in_loop<'ast, V>(visitor: &mut V, for_in: &'ast ForInLoop)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(for_in.variable());
+    visitor.visit_node(for_in.expr());
+    visitor.visit_node(for_in.body());
+}
+
+/// Visits the variable, the iterable and the body of a for-of loop.
+pub fn walk_for_of_loop<'ast, V>(visitor: &mut V, for_of: &'ast ForOfLoop)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(for_of.variable());
+    visitor.visit_node(for_of.iterable());
+    visitor.visit_node(for_of.body());
+}
+
+/// Visits the declaration of a formal parameter.
+pub fn walk_formal_parameter<'ast, V>(visitor: &mut V, param: &'ast FormalParameter)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_declaration(param.declaration())
+}
+
+/// Visits the parameters and the body of a function declaration.
+pub fn walk_function_decl<'ast, V>(visitor: &mut V, decl: &'ast FunctionDecl)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    walk_function(visitor, decl.parameters(), decl.body())
+}
+
+/// Visits the parameters and the body of a function expression.
+pub fn walk_function_expr<'ast, V>(visitor: &mut V, expr: &'ast FunctionExpr)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    walk_function(visitor, expr.parameters(), expr.body())
+}
+
+/// Visits the parameters and the body of a generator declaration.
+pub fn walk_generator_decl<'ast, V>(visitor: &mut V, decl: &'ast GeneratorDecl)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    walk_function(visitor, decl.parameters(), decl.body())
+}
+
+/// Visits the parameters and the body of a generator expression.
+pub fn walk_generator_expr<'ast, V>(visitor: &mut V, expr: &'ast GeneratorExpr)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    walk_function(visitor, expr.parameters(), expr.body())
+}
+
+/// Visits the object of a property access with a constant name.
+pub fn walk_get_const_field<'ast, V>(visitor: &mut V, get_field: &'ast GetConstField)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(get_field.obj())
+}
+
+/// Visits the object and the property name of a property access with a computed name.
+pub fn walk_get_field<'ast, V>(visitor: &mut V, get_field: &'ast GetField)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(get_field.obj());
+    visitor.visit_node(get_field.field());
+}
+
+/// Visits the condition and the branches of an if statement.
+pub fn walk_if<'ast, V>(visitor: &mut V, if_smt: &'ast If)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(if_smt.cond());
+    visitor.visit_node(if_smt.body());
+    if let Some(else_node) = if_smt.else_node() {
+        visitor.visit_node(else_node);
+    }
+}
+
+/// Visits the constructor and the arguments of a `new` expression.
+pub fn walk_new<'ast, V>(visitor: &mut V, new: &'ast New)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(new.expr());
+    walk_nodes(visitor, new.args())
+}
+
+/// Visits the property definitions of an object literal.
+pub fn walk_object<'ast, V>(visitor: &mut V, object: &'ast Object)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    for property in object.properties() {
+        visitor.visit_property_definition(property);
+    }
+}
+
+/// Visits the computed name and the value of a property definition.
+pub fn walk_property_definition<'ast, V>(visitor: &mut V, property: &'ast PropertyDefinition)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    match property {
+        PropertyDefinition::IdentifierReference(_) => {}
+        PropertyDefinition::Property(name, value) => {
+            if let PropertyName::Computed(name) = name {
+                visitor.visit_node(name);
+            }
+            visitor.visit_node(value);
+        }
+        PropertyDefinition::MethodDefinition(_, name, func) => {
+            if let PropertyName::Computed(name) = name {
+                visitor.visit_node(name);
+            }
+            visitor.visit_function_expr(func);
+        }
+        PropertyDefinition::SpreadObject(node) => visitor.visit_node(node),
+    }
+}
+
+/// Visits the target and the operations of an optional chain.
+pub fn walk_optional<'ast, V>(visitor: &mut V, optional: &'ast Optional)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(optional.target());
+    for operation in optional.chain() {
+        match operation.kind() {
+            OptionalOperationKind::SimplePropertyAccess { .. } => {}
+            OptionalOperationKind::PropertyAccess { field } => visitor.visit_node(field),
+            OptionalOperationKind::Call { args } => walk_nodes(visitor, args),
+        }
+    }
+}
+
+/// Visits the returned expression.
+pub fn walk_return<'ast, V>(visitor: &mut V, ret: &'ast Return)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    if let Some(expr) = ret.expr() {
+        visitor.visit_node(expr);
+    }
+}
+
+/// Visits the spread expression.
+pub fn walk_spread<'ast, V>(visitor: &mut V, spread: &'ast Spread)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(spread.val())
+}
+
+/// Visits the discriminant and the clauses of a switch statement, in source order.
+pub fn walk_switch<'ast, V>(visitor: &mut V, switch: &'ast Switch)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(switch.val());
+    for (i, case) in switch.cases().iter().enumerate() {
+        if i == switch.default_position() {
+            walk_nodes(visitor, switch.default().unwrap_or_default());
+        }
+        visitor.visit_case(case);
+    }
+    if switch.default_position() == switch.cases().len() {
+        walk_nodes(visitor, switch.default().unwrap_or_default());
+    }
+}
+
+/// Visits the condition and the body of a case clause.
+pub fn walk_case<'ast, V>(visitor: &mut V, case: &'ast Case)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(case.condition());
+    visitor.visit_statement_list(case.body());
+}
+
+/// Visits the tag and the substitutions of a tagged template.
+pub fn walk_tagged_template<'ast, V>(visitor: &mut V, template: &'ast TaggedTemplate)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(template.tag());
+    walk_nodes(visitor, template.exprs())
+}
+
+/// Visits the substitutions of a template literal.
+pub fn walk_template_lit<'ast, V>(visitor: &mut V, template: &'ast TemplateLit)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    for element in template.elements() {
+        if let TemplateElement::Expr(expr) = element {
+            visitor.visit_node(expr);
+        }
+    }
+}
+
+/// Visits the thrown expression.
+pub fn walk_throw<'ast, V>(visitor: &mut V, throw: &'ast Throw)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(throw.expr())
+}
+
+/// Visits the blocks of a try statement.
+pub fn walk_try<'ast, V>(visitor: &mut V, try_smt: &'ast Try)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_block(try_smt.block());
+    if let Some(catch) = try_smt.catch() {
+        visitor.visit_catch(catch);
+    }
+    if let Some(finally) = try_smt.finally() {
+        visitor.visit_block(finally);
+    }
+}
+
+/// Visits the block of a catch clause.
+pub fn walk_catch<'ast, V>(visitor: &mut V, catch: &'ast Catch)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_block(catch.block())
+}
+
+/// Visits the operand of a unary operation.
+pub fn walk_unary_op<'ast, V>(visitor: &mut V, op: &'ast UnaryOp)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(op.target())
+}
+
+/// Visits the condition and the body of a while loop.
+pub fn walk_while_loop<'ast, V>(visitor: &mut V, while_loop: &'ast WhileLoop)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    visitor.visit_node(while_loop.cond());
+    visitor.visit_node(while_loop.expr());
+}
+
+/// Visits the yielded expression.
+pub fn walk_yield<'ast, V>(visitor: &mut V, yield_expr: &'ast Yield)
+where
+    V: Visitor<'ast> + ?Sized,
+{
+    if let Some(expr) = yield_expr.expr() {
+        visitor.visit_node(expr);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{walk_function_decl, Visitor};
+    use crate::syntax::{
+        ast::node::{FunctionDecl, Identifier},
+        Parser,
+    };
+
+    /// Counts the identifiers of a script, and the functions declared in it.
+    #[derive(Debug, Default)]
+    struct Counter<'ast> {
+        identifiers: Vec<&'ast str>,
+        functions: usize,
+    }
+
+    impl<'ast> Visitor<'ast> for Counter<'ast> {
+        fn visit_identifier(&mut self, ident: &'ast Identifier) {
+            self.identifiers.push(ident.as_ref());
+        }
+
+        fn visit_function_decl(&mut self, decl: &'ast FunctionDecl) {
+            self.functions += 1;
+            walk_function_decl(self, decl)
+        }
+    }
+
+    /// Checks that identifiers are visited in source order, in every kind of node.
+    #[test]
+    fn count_identifiers() {
+        let list = Parser::new(
+            r#"
+            function add(a, b = c) {
+                return a + b;
+            }
+            for (let i = 0; i < n; i = add(i, 1)) {
+                if (o.x) { obj[key](...rest); } else throw err;
+            }
+            switch (s) {
+                case t: u;
+                default: v;
+            }
+            let { p = q } = { [k]: `${z}` };
+            "#
+            .as_bytes(),
+            false,
+        )
+        .parse_all()
+        .expect("failed to parse source");
+        let mut counter = Counter::default();
+        counter.visit_statement_list(&list);
+
+        assert_eq!(
+            counter.identifiers,
+            vec![
+                "a", "b", "c", "a", "b", "i", "i", "n", "i", "add", "i", "o", "obj", "key", "rest",
+                "err", "s", "t", "u", "v", "q", "k", "z",
+            ]
+        );
+        assert_eq!(counter.functions, 1);
+    }
+
+    /// Checks that overriding a method without walking the node skips its children.
+    #[test]
+    fn skip_children() {
+        struct SkipFunctions(usize);
+
+        impl<'ast> Visitor<'ast> for SkipFunctions {
+            fn visit_identifier(&mut self, _: &'ast Identifier) {
+                self.0 += 1;
+            }
+
+            fn visit_function_decl(&mut self, _: &'ast FunctionDecl) {}
+        }
+
+        let list = Parser::new("function f(a) { return a; } b;".as_bytes(), false)
+            .parse_all()
+            .expect("failed to parse source");
+        let mut visitor = SkipFunctions(0);
+        visitor.visit_statement_list(&list);
+
+        assert_eq!(visitor.0, 1);
+    }
+}
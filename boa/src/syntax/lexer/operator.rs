@@ -124,21 +124,33 @@ impl<R> Tokenizer<R> for Operator {
             b'&' => op!(cursor, start_pos, Ok(Punctuator::AssignAnd), Ok(Punctuator::And), {
                 Some(b'&') => vop!(cursor, Ok(Punctuator::AssignBoolAnd), Ok(Punctuator::BoolAnd))
             }),
-            b'?' => match cursor.peek()? {
-                Some(b'?') => {
-                    let _ = cursor.next_byte()?.expect("? vanished");
-                    op!(
-                        cursor,
-                        start_pos,
-                        Ok(Punctuator::AssignCoalesce),
-                        Ok(Punctuator::Coalesce)
-                    )
+            b'?' => {
+                let next = cursor.peek_n(2)?;
+                match next as u8 {
+                    b'?' => {
+                        let _ = cursor.next_byte()?.expect("? vanished");
+                        op!(
+                            cursor,
+                            start_pos,
+                            Ok(Punctuator::AssignCoalesce),
+                            Ok(Punctuator::Coalesce)
+                        )
+                    }
+                    // `?.` followed by a decimal digit is a conditional operator followed by a
+                    // number, as in `a?.5:0`.
+                    b'.' if !((next >> 8) as u8).is_ascii_digit() => {
+                        let _ = cursor.next_byte()?.expect(". vanished");
+                        Ok(Token::new(
+                            TokenKind::Punctuator(Punctuator::Optional),
+                            Span::new(start_pos, cursor.pos()),
+                        ))
+                    }
+                    _ => Ok(Token::new(
+                        TokenKind::Punctuator(Punctuator::Question),
+                        Span::new(start_pos, cursor.pos()),
+                    )),
                 }
-                _ => Ok(Token::new(
-                    TokenKind::Punctuator(Punctuator::Question),
-                    Span::new(start_pos, cursor.pos()),
-                )),
-            },
+            }
             b'^' => op!(
                 cursor,
                 start_pos,
@@ -17,7 +17,8 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature = "deser", derive(Serialize, Deserialize))]
 #[derive(Clone, PartialEq, Debug)]
 pub struct TemplateString {
-    /// The start position of the template string. Used to make lexer error if `to_owned_cooked` failed.
+    /// The position of the first character of the raw template string. Used to make lexer error
+    /// if `to_owned_cooked` failed.
     start_pos: Position,
     /// The template string of template literal with argument `raw` true.
     raw: Box<str>,
@@ -73,8 +74,8 @@ impl TemplateString {
                 }
                 Some(ch) => {
                     // The caller guarantees that sequences '`' and '${' never appear
-                    // LineTerminatorSequence <CR> <LF> is consumed by `cursor.next_char()` and returns <LF>,
-                    // which matches the TV of <CR> <LF>
+                    // LineTerminatorSequences <CR> <LF> and <CR> are normalized to <LF> by the lexer,
+                    // which matches their TV
                     buf.push_code_point(ch);
                 }
                 None => break,
@@ -87,7 +88,7 @@ impl TemplateString {
 
 /// Template literal lexing.
 ///
-/// Expects: Initial ` to already be consumed by cursor.
+/// Expects: Initial ` (or the closing } of a substitution) to already be consumed by cursor.
 ///
 /// More information:
 ///  - [ECMAScript reference][spec]
@@ -105,6 +106,7 @@ impl<R> Tokenizer<R> for TemplateLiteral {
     {
         let _timer = BoaProfiler::global().start_event("TemplateLiteral", "Lexing");
 
+        let raw_start_pos = cursor.pos();
         let mut buf = Vec::new();
         loop {
             let ch = cursor.next_char()?.ok_or_else(|| {
@@ -117,7 +119,7 @@ impl<R> Tokenizer<R> for TemplateLiteral {
             match ch {
                 0x0060 /* ` */ => {
                     let raw = buf.to_string_lossy();
-                    let template_string = TemplateString::new(raw, start_pos);
+                    let template_string = TemplateString::new(raw, raw_start_pos);
 
                     return Ok(Token::new(
                         TokenKind::template_no_substitution(template_string),
@@ -126,7 +128,7 @@ impl<R> Tokenizer<R> for TemplateLiteral {
                 }
                 0x0024 /* $ */ if cursor.next_is(b'{')? => {
                     let raw = buf.to_string_lossy();
-                    let template_string = TemplateString::new(raw, start_pos);
+                    let template_string = TemplateString::new(raw, raw_start_pos);
 
                     return Ok(Token::new(
                         TokenKind::template_middle(template_string),
@@ -147,6 +149,11 @@ impl<R> Tokenizer<R> for TemplateLiteral {
                         _ => continue,
                     }
                 }
+                // The TRV of the LineTerminatorSequences <CR> <LF> and <CR> is <LF>.
+                // `cursor.next_char()` already consumed the <LF> of a <CR> <LF> sequence.
+                0x000D /* <CR> */ => {
+                    buf.push(0x000A);
+                }
                 ch => {
                     buf.push_code_point(ch);
                 }
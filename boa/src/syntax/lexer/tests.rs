@@ -142,7 +142,7 @@ fn check_template_literal_simple() {
         lexer.next().unwrap().unwrap().kind(),
         &TokenKind::template_no_substitution(TemplateString::new(
             "I'm a template literal",
-            Position::new(1, 1)
+            Position::new(1, 2)
         ))
     );
 }
@@ -157,6 +157,38 @@ fn check_template_literal_unterminated() {
         .expect_err("Lexer did not handle unterminated literal with error");
 }
 
+#[test]
+fn check_template_literal_line_numbers() {
+    let s = "`a\nb${\nc}\r\nd`\ne";
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let token = lexer.next().unwrap().unwrap();
+    assert_eq!(
+        token.kind(),
+        &TokenKind::template_middle(TemplateString::new("a\nb", Position::new(1, 2)))
+    );
+    assert_eq!(token.span(), span((1, 1), (2, 4)));
+
+    // The line terminator and the identifier in the substitution.
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((2, 4), (3, 1)));
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((3, 1), (3, 2)));
+
+    let token = lexer.next().unwrap().unwrap();
+    assert_eq!(token.kind(), &TokenKind::Punctuator(Punctuator::CloseBlock));
+    assert_eq!(token.span(), span((3, 2), (3, 3)));
+
+    // <CR> <LF> is normalized to <LF> in the template string.
+    let token = lexer.lex_template(token.span().start()).unwrap();
+    assert_eq!(
+        token.kind(),
+        &TokenKind::template_no_substitution(TemplateString::new("\nd", Position::new(3, 3)))
+    );
+    assert_eq!(token.span(), span((3, 2), (4, 3)));
+
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((4, 3), (5, 1)));
+    assert_eq!(lexer.next().unwrap().unwrap().span(), span((5, 1), (5, 2)));
+}
+
 #[test]
 fn check_punctuators() {
     // https://tc39.es/ecma262/#sec-punctuators
@@ -224,6 +256,33 @@ fn check_punctuators() {
     expect_tokens(&mut lexer, &expected);
 }
 
+#[test]
+fn check_optional_chaining() {
+    let mut lexer = Lexer::new(&b"a?.b a?.[0] a?.() a?.5:0"[..]);
+
+    let expected = [
+        TokenKind::identifier("a"),
+        TokenKind::Punctuator(Punctuator::Optional),
+        TokenKind::identifier("b"),
+        TokenKind::identifier("a"),
+        TokenKind::Punctuator(Punctuator::Optional),
+        TokenKind::Punctuator(Punctuator::OpenBracket),
+        TokenKind::numeric_literal(0),
+        TokenKind::Punctuator(Punctuator::CloseBracket),
+        TokenKind::identifier("a"),
+        TokenKind::Punctuator(Punctuator::Optional),
+        TokenKind::Punctuator(Punctuator::OpenParen),
+        TokenKind::Punctuator(Punctuator::CloseParen),
+        TokenKind::identifier("a"),
+        TokenKind::Punctuator(Punctuator::Question),
+        TokenKind::numeric_literal(0.5),
+        TokenKind::Punctuator(Punctuator::Colon),
+        TokenKind::numeric_literal(0),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
 #[test]
 fn check_keywords() {
     // https://tc39.es/ecma262/#sec-keywords
@@ -472,12 +531,53 @@ fn numbers_with_separators() {
     expect_tokens(&mut lexer, &expected);
 }
 
+#[test]
+fn numbers_with_separators_of_every_base() {
+    let mut lexer = Lexer::new(&b"1_000_000 0xFF_FF 0b1010_0001 0o7_7 3.141_592"[..]);
+
+    let expected = [
+        TokenKind::numeric_literal(1_000_000),
+        TokenKind::numeric_literal(65535),
+        TokenKind::numeric_literal(161),
+        TokenKind::numeric_literal(63),
+        TokenKind::numeric_literal(3.141_592),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
 #[test]
 fn numbers_with_bad_separators() {
     let numbers = [
-        "0b_10", "0x_10", "10_", "1._10", "1e+_10", "1E_10", "10__00",
+        "0b_10", "0x_10", "10_", "1._10", "1e+_10", "1E_10", "10__00", "1_.5", "1_e5", "0x_FF",
+        "0_1", "0xFF__FF",
+    ];
+
+    for n in numbers.iter() {
+        let mut lexer = Lexer::new(n.as_bytes());
+        assert!(lexer.next().is_err());
+    }
+}
+
+#[test]
+fn big_int_literals() {
+    let mut lexer = Lexer::new(&b"0n 123n 0xFFn 0b101n 0o17n"[..]);
+
+    let expected = [
+        TokenKind::NumericLiteral(Numeric::BigInt(0.into())),
+        TokenKind::NumericLiteral(Numeric::BigInt(123.into())),
+        TokenKind::NumericLiteral(Numeric::BigInt(255.into())),
+        TokenKind::NumericLiteral(Numeric::BigInt(5.into())),
+        TokenKind::NumericLiteral(Numeric::BigInt(15.into())),
     ];
 
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn big_int_literals_invalid() {
+    let numbers = ["1.5n", "1e3n", "017n"];
+
     for n in numbers.iter() {
         let mut lexer = Lexer::new(n.as_bytes());
         assert!(lexer.next().is_err());
@@ -875,6 +975,35 @@ fn string_unicode_escape_with_braces_2() {
     expect_tokens(&mut lexer, &expected);
 }
 
+#[test]
+fn string_unicode_escape_above_bmp() {
+    let s = r#"'\u{1F600}' '\uD83D\uDE00' '\u{10FFFF}'"#;
+
+    let mut lexer = Lexer::new(s.as_bytes());
+
+    let expected = [
+        TokenKind::StringLiteral("\u{1F600}".into()),
+        TokenKind::StringLiteral("\u{1F600}".into()),
+        TokenKind::StringLiteral("\u{10FFFF}".into()),
+    ];
+
+    expect_tokens(&mut lexer, &expected);
+}
+
+#[test]
+fn string_unicode_escape_out_of_range() {
+    let mut lexer = Lexer::new(&br#"'\u{110000}'"#[..]);
+
+    if let Error::Syntax(_, pos) = lexer
+        .next()
+        .expect_err("out of range Unicode code point expected")
+    {
+        assert_eq!(pos, Position::new(1, 2));
+    } else {
+        panic!("invalid error type");
+    }
+}
+
 #[test]
 fn string_with_single_escape() {
     let s = r#"'\Б'"#;
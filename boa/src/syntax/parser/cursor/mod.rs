@@ -3,11 +3,22 @@ mod buffered_lexer;
 
 use super::ParseError;
 use crate::syntax::{
-    ast::Punctuator,
+    ast::{
+        node::{
+            ArrowFunctionDecl, AsyncFunctionDecl, AsyncFunctionExpr, Block, FormalParameter,
+            FunctionDecl, FunctionExpr, GeneratorDecl, GeneratorExpr, Node, StatementList, Switch,
+        },
+        visitor::{walk_node, Visitor},
+        Punctuator, Span,
+    },
     lexer::{InputElement, Lexer, Position, Token, TokenKind},
 };
 use buffered_lexer::BufferedLexer;
-use std::io::Read;
+use std::{
+    collections::HashMap,
+    io::Read,
+    mem::{self, Discriminant},
+};
 
 /// The result of a peek for a semicolon.
 #[derive(Debug)]
@@ -22,6 +33,21 @@ pub(super) enum SemicolonResult<'s> {
 #[derive(Debug)]
 pub(super) struct Cursor<R> {
     buffered_lexer: BufferedLexer<R>,
+    module: bool,
+
+    /// The labels of the enclosing labelled statements, and whether they label an iteration
+    /// statement, if it is already known.
+    labels: Vec<(Box<str>, Option<bool>)>,
+
+    /// The end position of the last consumed token.
+    last_token_end: Position,
+
+    /// The kind and the span of the expressions parsed since the start of the innermost statement
+    /// being parsed, in the order in which they were completed.
+    expressions: Vec<(Discriminant<Node>, Span)>,
+
+    /// The spans of the expressions of the parsed statements, keyed by the address of their node.
+    expression_spans: HashMap<*const Node, Span>,
 }
 
 impl<R> Cursor<R>
@@ -33,6 +59,11 @@ where
     pub(super) fn new(reader: R) -> Self {
         Self {
             buffered_lexer: Lexer::new(reader).into(),
+            module: false,
+            labels: Vec::new(),
+            last_token_end: Position::new(1, 1),
+            expressions: Vec::new(),
+            expression_spans: HashMap::new(),
         }
     }
 
@@ -43,17 +74,132 @@ where
 
     #[inline]
     pub(super) fn lex_regex(&mut self, start: Position) -> Result<Token, ParseError> {
-        self.buffered_lexer.lex_regex(start)
+        let token = self.buffered_lexer.lex_regex(start)?;
+        self.last_token_end = token.span().end();
+        Ok(token)
     }
 
     #[inline]
     pub(super) fn lex_template(&mut self, start: Position) -> Result<Token, ParseError> {
-        self.buffered_lexer.lex_template(start)
+        let token = self.buffered_lexer.lex_template(start)?;
+        self.last_token_end = token.span().end();
+        Ok(token)
     }
 
     #[inline]
     pub(super) fn next(&mut self) -> Result<Option<Token>, ParseError> {
-        self.buffered_lexer.next(true)
+        let token = self.buffered_lexer.next(true)?;
+        if let Some(ref token) = token {
+            self.last_token_end = token.span().end();
+        }
+        Ok(token)
+    }
+
+    /// Gets the end position of the last consumed token.
+    #[inline]
+    pub(super) fn last_token_end(&self) -> Position {
+        self.last_token_end
+    }
+
+    /// Records the span of an expression, from `start` to the end of the last consumed token.
+    ///
+    /// An expression is recorded once it is complete, after all of its sub-expressions.
+    #[inline]
+    pub(super) fn record_expression<N>(&mut self, node: N, start: Position) -> Node
+    where
+        N: Into<Node>,
+    {
+        let node = node.into();
+        self.expressions.push((
+            mem::discriminant(&node),
+            Span::new(start, self.last_token_end),
+        ));
+        node
+    }
+
+    /// Records the span of an expression at `index` in the recorded expressions, for expressions
+    /// that are only built after the expressions that follow them were recorded.
+    #[inline]
+    pub(super) fn insert_expression(&mut self, index: usize, node: &Node, span: Span) {
+        self.expressions
+            .insert(index, (mem::discriminant(node), span));
+    }
+
+    /// Extends the span of the last recorded expression from `start` to the end of the last
+    /// consumed token, such as to the parentheses around it.
+    #[inline]
+    pub(super) fn extend_last_expression(&mut self, start: Position) {
+        if let Some((_, span)) = self.expressions.last_mut() {
+            *span = Span::new(start, self.last_token_end);
+        }
+    }
+
+    /// Gets the start of the last recorded expression, which is where an expression using it as
+    /// its first operand starts.
+    #[inline]
+    pub(super) fn last_expression_start(&self) -> Position {
+        self.expressions
+            .last()
+            .map_or(self.last_token_end, |(_, span)| span.start())
+    }
+
+    /// Gets the number of recorded expressions, to roll back to with `truncate_expressions()`.
+    #[inline]
+    pub(super) fn expression_mark(&self) -> usize {
+        self.expressions.len()
+    }
+
+    /// Forgets the expressions recorded after `mark`, such as the expressions of a parenthesized
+    /// expression that turn out to be the parameters of an arrow function.
+    #[inline]
+    pub(super) fn truncate_expressions(&mut self, mark: usize) {
+        self.expressions.truncate(mark);
+    }
+
+    /// Keys the spans of the expressions recorded after `mark` by the nodes of `item`, a parsed
+    /// statement, and forgets them.
+    ///
+    /// If `item` is itself an expression, its span is returned instead, since the item is only at
+    /// its final address once it is in its statement list. Spans that can't be matched with the
+    /// expressions of the statement are dropped.
+    pub(super) fn register_expressions(&mut self, mark: usize, item: &Node) -> Option<Span> {
+        let mut recorded = self.expressions.split_off(mark);
+        let own_span = if item.is_expression() {
+            match recorded.pop() {
+                Some((kind, span)) if kind == mem::discriminant(item) => Some(span),
+                _ => return None,
+            }
+        } else {
+            None
+        };
+
+        let mut collector = ExpressionCollector::default();
+        walk_node(&mut collector, item);
+        let matches = collector.expressions.len() == recorded.len()
+            && collector
+                .expressions
+                .iter()
+                .zip(&recorded)
+                .all(|(node, (kind, _))| mem::discriminant(*node) == *kind);
+        if matches {
+            for (node, (_, span)) in collector.expressions.into_iter().zip(recorded) {
+                self.expression_spans.insert(node, span);
+            }
+        }
+
+        own_span
+    }
+
+    /// Keys the span of an expression that is a statement by its node.
+    #[inline]
+    pub(super) fn register_expression(&mut self, node: &Node, span: Span) {
+        self.expression_spans.insert(node, span);
+    }
+
+    /// Takes the spans of the expressions of the parsed statements.
+    #[inline]
+    pub(super) fn take_expression_spans(&mut self) -> HashMap<*const Node, Span> {
+        mem::take(&mut self.expression_spans)
     }
 
     #[inline]
@@ -71,6 +217,66 @@ where
         self.buffered_lexer.set_strict_mode(strict_mode)
     }
 
+    /// Returns `true` if the source is parsed as module code.
+    #[inline]
+    pub(super) fn module(&self) -> bool {
+        self.module
+    }
+
+    #[inline]
+    pub(super) fn set_module(&mut self, module: bool) {
+        self.module = module
+    }
+
+    /// Looks up the label of an enclosing labelled statement.
+    ///
+    /// Returns `None` if no enclosing statement has this label, or whether the statement it
+    /// labels is an iteration statement otherwise.
+    #[inline]
+    pub(super) fn label(&self, name: &str) -> Option<bool> {
+        self.labels
+            .iter()
+            .rev()
+            .find(|(label, _)| label.as_ref() == name)
+            .map(|&(_, iteration)| iteration.unwrap_or(false))
+    }
+
+    /// Adds the label of a labelled statement being parsed.
+    ///
+    /// `iteration` is `None` if the labelled statement labels another labelled statement, in which
+    /// case it is set once the label of the innermost one is added.
+    #[inline]
+    pub(super) fn push_label(&mut self, name: Box<str>, iteration: Option<bool>) {
+        if iteration.is_some() {
+            for (_, pending) in self
+                .labels
+                .iter_mut()
+                .rev()
+                .take_while(|(_, pending)| pending.is_none())
+            {
+                *pending = iteration;
+            }
+        }
+        self.labels.push((name, iteration));
+    }
+
+    /// Removes the label of the innermost labelled statement being parsed.
+    #[inline]
+    pub(super) fn pop_label(&mut self) {
+        self.labels.pop();
+    }
+
+    /// Replaces the labels of the enclosing labelled statements, returning the previous ones.
+    ///
+    /// Labels are not visible inside of function bodies, which start with no labels.
+    #[inline]
+    pub(super) fn replace_labels(
+        &mut self,
+        labels: Vec<(Box<str>, Option<bool>)>,
+    ) -> Vec<(Box<str>, Option<bool>)> {
+        std::mem::replace(&mut self.labels, labels)
+    }
+
     /// Returns an error if the next token is not of kind `kind`.
     #[inline]
     pub(super) fn expect<K>(&mut self, kind: K, context: &'static str) -> Result<Token, ParseError>
@@ -116,7 +322,11 @@ where
     pub(super) fn expect_semicolon(&mut self, context: &'static str) -> Result<(), ParseError> {
         match self.peek_semicolon()? {
             SemicolonResult::Found(Some(tk)) => match *tk.kind() {
-                TokenKind::Punctuator(Punctuator::Semicolon) | TokenKind::LineTerminator => {
+                TokenKind::Punctuator(Punctuator::Semicolon) => {
+                    let _ = self.next()?;
+                    Ok(())
+                }
+                TokenKind::LineTerminator => {
                     let _ = self.buffered_lexer.next(false)?;
                     Ok(())
                 }
@@ -153,6 +363,26 @@ where
         }
     }
 
+    /// Skips tokens until the end of the current statement, so that parsing can resume after an
+    /// error.
+    ///
+    /// The statement is considered finished after a `;` or a `}` token, which are consumed, or at
+    /// the end of the input. Lexing errors found while skipping are ignored.
+    pub(super) fn synchronize(&mut self) {
+        loop {
+            match self.next() {
+                Ok(Some(token))
+                    if token.kind() == &TokenKind::Punctuator(Punctuator::Semicolon)
+                        || token.kind() == &TokenKind::Punctuator(Punctuator::CloseBlock) =>
+                {
+                    break
+                }
+                Ok(None) => break,
+                Ok(Some(_)) | Err(_) => {}
+            }
+        }
+    }
+
     /// Advance the cursor to the next token and retrieve it, only if it's of `kind` type.
     ///
     /// When the next token is a `kind` token, get the token, otherwise return `None`.
@@ -174,3 +404,68 @@ where
         })
     }
 }
+
+/// Collects the expressions of a statement, in the order in which the parser completes them.
+///
+/// The expressions of nested statement lists, and of the bodies of functions, are keyed when
+/// their own statements are parsed, and the parameters of arrow functions have no span, so they
+/// are skipped.
+#[derive(Debug, Default)]
+struct ExpressionCollector<'ast> {
+    expressions: Vec<&'ast Node>,
+}
+
+impl<'ast> ExpressionCollector<'ast> {
+    /// Visits the parameters of a function, but not its body.
+    fn visit_parameters(&mut self, parameters: &'ast [FormalParameter]) {
+        for param in parameters {
+            self.visit_formal_parameter(param);
+        }
+    }
+}
+
+impl<'ast> Visitor<'ast> for ExpressionCollector<'ast> {
+    fn visit_statement_list(&mut self, _list: &'ast StatementList) {}
+
+    fn visit_node(&mut self, node: &'ast Node) {
+        walk_node(self, node);
+        if node.is_expression() {
+            self.expressions.push(node);
+        }
+    }
+
+    fn visit_arrow_function_decl(&mut self, _decl: &'ast ArrowFunctionDecl) {}
+
+    fn visit_async_function_decl(&mut self, decl: &'ast AsyncFunctionDecl) {
+        self.visit_parameters(decl.parameters());
+    }
+
+    fn visit_async_function_expr(&mut self, expr: &'ast AsyncFunctionExpr) {
+        self.visit_parameters(expr.parameters());
+    }
+
+    fn visit_block(&mut self, _block: &'ast Block) {}
+
+    fn visit_function_decl(&mut self, decl: &'ast FunctionDecl) {
+        self.visit_parameters(decl.parameters());
+    }
+
+    fn visit_function_expr(&mut self, expr: &'ast FunctionExpr) {
+        self.visit_parameters(expr.parameters());
+    }
+
+    fn visit_generator_decl(&mut self, decl: &'ast GeneratorDecl) {
+        self.visit_parameters(decl.parameters());
+    }
+
+    fn visit_generator_expr(&mut self, expr: &'ast GeneratorExpr) {
+        self.visit_parameters(expr.parameters());
+    }
+
+    fn visit_switch(&mut self, switch: &'ast Switch) {
+        self.visit_node(switch.val());
+        for case in switch.cases() {
+            self.visit_node(case.condition());
+        }
+    }
+}
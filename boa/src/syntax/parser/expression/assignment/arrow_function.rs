@@ -11,8 +11,13 @@ use super::AssignmentExpression;
 use crate::{
     syntax::{
         ast::{
-            node::{ArrowFunctionDecl, FormalParameter, Node, Return, StatementList},
-            Punctuator,
+            node::{
+                ArrayDecl, ArrowFunctionDecl, BindingPatternTypeArray, BindingPatternTypeObject,
+                Declaration, DeclarationPattern, DeclarationPatternArray, DeclarationPatternObject,
+                FormalParameter, Node, Object, PropertyDefinition, PropertyName, Return,
+                StatementList,
+            },
+            Const, Punctuator,
         },
         lexer::{Error as LexError, Position, TokenKind},
         parser::{
@@ -25,7 +30,7 @@ use crate::{
     BoaProfiler,
 };
 
-use std::io::Read;
+use std::{collections::HashSet, io::Read};
 
 /// Arrow function parsing.
 ///
@@ -76,7 +81,10 @@ where
             // CoverParenthesizedExpressionAndArrowParameterList
             cursor.expect(Punctuator::OpenParen, "arrow function")?;
 
+            // Arrow function parameters have no span, whether or not they are parsed as a cover.
+            let mark = cursor.expression_mark();
             let params = FormalParameters::new(self.allow_yield, self.allow_await).parse(cursor)?;
+            cursor.truncate_expressions(mark);
             cursor.expect(Punctuator::CloseParen, "arrow function")?;
             params
         } else {
@@ -86,6 +94,60 @@ where
             Box::new([FormalParameter::new(param, None, false)])
         };
 
+        self.parse_body(params, cursor)
+    }
+}
+
+impl ArrowFunction {
+    /// Parses an arrow function whose parameters were already parsed as the expressions of a
+    /// parenthesized expression, and an optional rest parameter.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-CoverParenthesizedExpressionAndArrowParameterList
+    pub(in crate::syntax::parser) fn parse_with_cover<R>(
+        self,
+        expressions: &[Node],
+        rest: Option<FormalParameter>,
+        position: Position,
+        cursor: &mut Cursor<R>,
+    ) -> Result<ArrowFunctionDecl, ParseError>
+    where
+        R: Read,
+    {
+        let mut params = Vec::with_capacity(expressions.len() + 1);
+        for expression in expressions {
+            let declaration = expression_to_declaration(expression)
+                .ok_or_else(|| ParseError::general("invalid arrow function parameter", position))?;
+            params.push(FormalParameter::new_with_declaration(declaration, false));
+        }
+        params.extend(rest);
+
+        // Arrow functions never allow duplicate parameter names.
+        // https://tc39.es/ecma262/#sec-arrow-function-definitions-static-semantics-early-errors
+        let mut names = HashSet::new();
+        for name in params.iter().flat_map(|param| param.names()) {
+            if !names.insert(name) {
+                return Err(ParseError::lex(LexError::Syntax(
+                    format!("Duplicate parameter name `{}`", name).into(),
+                    position,
+                )));
+            }
+        }
+
+        self.parse_body(params.into_boxed_slice(), cursor)
+    }
+
+    /// Parses the arrow and the body of an arrow function with the given parameters.
+    fn parse_body<R>(
+        self,
+        params: Box<[FormalParameter]>,
+        cursor: &mut Cursor<R>,
+    ) -> Result<ArrowFunctionDecl, ParseError>
+    where
+        R: Read,
+    {
         cursor.peek_expect_no_lineterminator(0, "arrow function")?;
 
         cursor.expect(TokenKind::Punctuator(Punctuator::Arrow), "arrow function")?;
@@ -96,10 +158,10 @@ where
         // https://tc39.es/ecma262/#sec-arrow-function-definitions-static-semantics-early-errors
         {
             let lexically_declared_names = body.lexically_declared_names();
-            for param in params.as_ref() {
-                if lexically_declared_names.contains(param.name()) {
+            for param in params.iter().flat_map(|param| param.names()) {
+                if lexically_declared_names.contains(param) {
                     return Err(ParseError::lex(LexError::Syntax(
-                        format!("Redeclaration of formal parameter `{}`", param.name()).into(),
+                        format!("Redeclaration of formal parameter `{}`", param).into(),
                         match cursor.peek(0)? {
                             Some(token) => token.span().end(),
                             None => Position::new(1, 1),
@@ -145,11 +207,17 @@ where
                 cursor.expect(Punctuator::CloseBlock, "arrow function")?;
                 Ok(body)
             }
-            _ => Ok(StatementList::from(vec![Return::new(
-                ExpressionBody::new(self.allow_in, false).parse(cursor)?,
-                None,
-            )
-            .into()])),
+            _ => {
+                let mark = cursor.expression_mark();
+                let body = StatementList::from(vec![Return::new(
+                    ExpressionBody::new(self.allow_in, false).parse(cursor)?,
+                    None,
+                )
+                .into()]);
+                // The body is the statement of its own statement list.
+                cursor.register_expressions(mark, &body.items()[0]);
+                Ok(body)
+            }
         }
     }
 }
@@ -185,3 +253,136 @@ where
         AssignmentExpression::new(self.allow_in, false, self.allow_await).parse(cursor)
     }
 }
+
+/// Reinterprets an expression of a parenthesized expression as the binding of an arrow function
+/// parameter, returning `None` if it isn't a valid binding.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-arrow-function-definitions-static-semantics-early-errors
+fn expression_to_declaration(expression: &Node) -> Option<Declaration> {
+    let (target, init) = split_initializer(expression);
+    match target {
+        Node::Identifier(ident) => Some(Declaration::new(ident.clone(), init)),
+        Node::Object(object) => Some(Declaration::new_with_object_pattern(
+            object_to_bindings(object)?,
+            init,
+        )),
+        Node::ArrayDecl(array) => Some(Declaration::new_with_array_pattern(
+            array_to_bindings(array)?,
+            init,
+        )),
+        _ => None,
+    }
+}
+
+/// Splits an `a = b` expression into its target and its initializer.
+fn split_initializer(expression: &Node) -> (&Node, Option<Node>) {
+    match expression {
+        Node::Assign(assign) => (assign.lhs(), Some(assign.rhs().clone())),
+        expression => (expression, None),
+    }
+}
+
+/// Reinterprets an object or array literal as a nested binding pattern.
+fn expression_to_pattern(expression: &Node) -> Option<DeclarationPattern> {
+    match expression {
+        Node::Object(object) => Some(DeclarationPattern::Object(DeclarationPatternObject::new(
+            object_to_bindings(object)?,
+            None,
+        ))),
+        Node::ArrayDecl(array) => Some(DeclarationPattern::Array(DeclarationPatternArray::new(
+            array_to_bindings(array)?,
+            None,
+        ))),
+        _ => None,
+    }
+}
+
+/// Reinterprets the properties of an object literal as an object binding pattern.
+fn object_to_bindings(object: &Object) -> Option<Vec<BindingPatternTypeObject>> {
+    let properties = object.properties();
+    let mut bindings = Vec::with_capacity(properties.len());
+    let mut property_names = Vec::new();
+
+    for (i, property) in properties.iter().enumerate() {
+        let binding = match property {
+            PropertyDefinition::IdentifierReference(name) => BindingPatternTypeObject::SingleName {
+                ident: name.clone(),
+                property_name: name.clone(),
+                default_init: None,
+            },
+            PropertyDefinition::Property(PropertyName::Literal(property_name), value) => {
+                let (target, default_init) = split_initializer(value);
+                match target {
+                    Node::Identifier(ident) => BindingPatternTypeObject::SingleName {
+                        ident: ident.as_ref().into(),
+                        property_name: property_name.clone(),
+                        default_init,
+                    },
+                    target => BindingPatternTypeObject::BindingPattern {
+                        property_name: property_name.clone(),
+                        pattern: expression_to_pattern(target)?,
+                        default_init,
+                    },
+                }
+            }
+            PropertyDefinition::SpreadObject(Node::Identifier(ident))
+                if i + 1 == properties.len() =>
+            {
+                BindingPatternTypeObject::RestProperty {
+                    ident: ident.as_ref().into(),
+                    excluded_keys: property_names.clone(),
+                }
+            }
+            _ => return None,
+        };
+
+        if let BindingPatternTypeObject::SingleName { property_name, .. }
+        | BindingPatternTypeObject::BindingPattern { property_name, .. } = &binding
+        {
+            property_names.push(property_name.clone());
+        }
+        bindings.push(binding);
+    }
+
+    Some(bindings)
+}
+
+/// Reinterprets the elements of an array literal as an array binding pattern.
+fn array_to_bindings(array: &ArrayDecl) -> Option<Vec<BindingPatternTypeArray>> {
+    let elements = array.as_ref();
+    let mut bindings = Vec::with_capacity(elements.len());
+
+    for (i, element) in elements.iter().enumerate() {
+        let binding = match element {
+            // Holes of array literals are represented as `undefined` constants.
+            Node::Const(Const::Undefined) => BindingPatternTypeArray::Elision,
+            Node::Spread(spread) if i + 1 == elements.len() => match spread.val() {
+                Node::Identifier(ident) => BindingPatternTypeArray::SingleNameRest {
+                    ident: ident.as_ref().into(),
+                },
+                target => BindingPatternTypeArray::BindingPatternRest {
+                    pattern: expression_to_pattern(target)?,
+                },
+            },
+            element => {
+                let (target, default_init) = split_initializer(element);
+                match target {
+                    Node::Identifier(ident) => BindingPatternTypeArray::SingleName {
+                        ident: ident.as_ref().into(),
+                        default_init,
+                    },
+                    target => BindingPatternTypeArray::BindingPattern {
+                        pattern: expression_to_pattern(target)?,
+                        default_init,
+                    },
+                }
+            }
+        };
+        bindings.push(binding);
+    }
+
+    Some(bindings)
+}
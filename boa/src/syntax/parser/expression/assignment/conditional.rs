@@ -67,19 +67,23 @@ where
 
         let lhs = ShortCircuitExpression::new(self.allow_in, self.allow_yield, self.allow_await)
             .parse(cursor)?;
+        let start = cursor.last_expression_start();
 
         if let Some(tok) = cursor.peek(0)? {
             if tok.kind() == &TokenKind::Punctuator(Punctuator::Question) {
                 cursor.next()?.expect("? character vanished"); // Consume the token.
+
+                // The `in` operator is always allowed in the first branch, as the `:` delimits it.
                 let then_clause =
-                    AssignmentExpression::new(self.allow_in, self.allow_yield, self.allow_await)
+                    AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                         .parse(cursor)?;
                 cursor.expect(Punctuator::Colon, "conditional expression")?;
 
                 let else_clause =
                     AssignmentExpression::new(self.allow_in, self.allow_yield, self.allow_await)
                         .parse(cursor)?;
-                return Ok(ConditionalOp::new(lhs, then_clause, else_clause).into());
+                let node = ConditionalOp::new(lhs, then_clause, else_clause);
+                return Ok(cursor.record_expression(node, start));
             }
         }
 
@@ -85,14 +85,29 @@ where
         let _timer = BoaProfiler::global().start_event("ExponentiationExpression", "Parsing");
 
         if is_unary_expression(cursor)? {
-            return UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor);
+            let unary = UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+            // The base of an exponentiation can't be an unparenthesized unary expression, since
+            // `-2 ** 2` would be ambiguous.
+            if let Some(tok) = cursor.peek(0)? {
+                if let TokenKind::Punctuator(Punctuator::Exp) = tok.kind() {
+                    return Err(ParseError::general(
+                        "unparenthesized unary expression can't appear on the left-hand side of '**'",
+                        tok.span().start(),
+                    ));
+                }
+            }
+
+            return Ok(unary);
         }
 
         let lhs = UpdateExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let start = cursor.last_expression_start();
         if let Some(tok) = cursor.peek(0)? {
             if let TokenKind::Punctuator(Punctuator::Exp) = tok.kind() {
                 cursor.next()?.expect("** token vanished"); // Consume the token.
-                return Ok(BinOp::new(NumOp::Exp, lhs, self.parse(cursor)?).into());
+                let rhs = self.parse(cursor)?;
+                return Ok(cursor.record_expression(BinOp::new(NumOp::Exp, lhs, rhs), start));
             }
         }
         Ok(lhs)
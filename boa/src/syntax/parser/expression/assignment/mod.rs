@@ -10,8 +10,9 @@
 mod arrow_function;
 mod conditional;
 mod exponentiation;
+mod r#yield;
 
-use self::{arrow_function::ArrowFunction, conditional::ConditionalExpression};
+use self::{conditional::ConditionalExpression, r#yield::YieldExpression};
 use crate::syntax::lexer::{Error as LexError, InputElement, TokenKind};
 use crate::{
     syntax::{
@@ -23,6 +24,7 @@ use crate::{
     },
     BoaProfiler,
 };
+pub(super) use arrow_function::ArrowFunction;
 pub(super) use exponentiation::ExponentiationExpression;
 
 use std::io::Read;
@@ -32,7 +34,7 @@ use std::io::Read;
 /// This can be one of the following:
 ///
 ///  - [`ConditionalExpression`](../conditional_operator/struct.ConditionalExpression.html)
-///  - `YieldExpression`
+///  - [`YieldExpression`](./yield/struct.YieldExpression.html)
 ///  - [`ArrowFunction`](../../function/arrow_function/struct.ArrowFunction.html)
 ///  - `AsyncArrowFunction`
 ///  - [`LeftHandSideExpression`][lhs] `=` `AssignmentExpression`
@@ -82,8 +84,15 @@ where
         let _timer = BoaProfiler::global().start_event("AssignmentExpression", "Parsing");
         cursor.set_goal(InputElement::Div);
 
-        // Arrow function
-        match cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind() {
+        let next = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let start = next.span().start();
+        match next.kind() {
+            // [+Yield]YieldExpression
+            TokenKind::Keyword(Keyword::Yield) if self.allow_yield.0 => {
+                return YieldExpression::new(self.allow_in, self.allow_await).parse(cursor);
+            }
+
+            // Arrow function
             // a=>{}
             TokenKind::Identifier(_)
             | TokenKind::Keyword(Keyword::Yield)
@@ -96,7 +105,7 @@ where
                             self.allow_await,
                         )
                         .parse(cursor)
-                        .map(Node::ArrowFunctionDecl);
+                        .map(|decl| cursor.record_expression(decl, start));
                     }
                 }
             }
@@ -116,7 +125,7 @@ where
                                         self.allow_await,
                                     )
                                     .parse(cursor)
-                                    .map(Node::ArrowFunctionDecl);
+                                    .map(|decl| cursor.record_expression(decl, start));
                                 }
                             }
                         }
@@ -127,21 +136,11 @@ where
                                 self.allow_await,
                             )
                             .parse(cursor)
-                            .map(Node::ArrowFunctionDecl);
+                            .map(|decl| cursor.record_expression(decl, start));
                         }
                         TokenKind::Identifier(_) => {
                             if let Some(t) = cursor.peek(2)? {
                                 match *t.kind() {
-                                    TokenKind::Punctuator(Punctuator::Comma) => {
-                                        // This must be an argument list and therefore (a, b) => {}
-                                        return ArrowFunction::new(
-                                            self.allow_in,
-                                            self.allow_yield,
-                                            self.allow_await,
-                                        )
-                                        .parse(cursor)
-                                        .map(Node::ArrowFunctionDecl);
-                                    }
                                     TokenKind::Punctuator(Punctuator::CloseParen) => {
                                         // Need to check if the token after the close paren is an arrow, if so then this is an ArrowFunction
                                         // otherwise it is an expression of the form (b).
@@ -154,7 +153,7 @@ where
                                                     self.allow_await,
                                                 )
                                                 .parse(cursor)
-                                                .map(Node::ArrowFunctionDecl);
+                                                .map(|decl| cursor.record_expression(decl, start));
                                             }
                                         }
                                     }
@@ -182,7 +181,10 @@ where
                 TokenKind::Punctuator(Punctuator::Assign) => {
                     cursor.next()?.expect("= token vanished"); // Consume the token.
                     if is_assignable(&lhs) {
-                        lhs = Assign::new(lhs, self.parse(cursor)?).into();
+                        let strict = cursor.strict_mode();
+                        let rhs = self.parse(cursor)?;
+                        lhs = cursor
+                            .record_expression(Assign::with_strict_mode(lhs, rhs, strict), start);
                     } else {
                         return Err(ParseError::lex(LexError::Syntax(
                             "Invalid left-hand side in assignment".into(),
@@ -196,7 +198,7 @@ where
                         let binop = p.as_binop().expect("binop disappeared");
                         let expr = self.parse(cursor)?;
 
-                        lhs = BinOp::new(binop, lhs, expr).into();
+                        lhs = cursor.record_expression(BinOp::new(binop, lhs, expr), start);
                     } else {
                         return Err(ParseError::lex(LexError::Syntax(
                             "Invalid left-hand side in assignment".into(),
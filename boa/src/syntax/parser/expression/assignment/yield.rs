@@ -0,0 +1,99 @@
+//! Yield expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+//! [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+
+use super::AssignmentExpression;
+use crate::{
+    syntax::{
+        ast::{
+            node::{Node, Yield},
+            Keyword, Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{AllowAwait, AllowIn, Cursor, ParseError, ParseResult, TokenParser},
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Yield expression parsing.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/yield
+/// [spec]: https://tc39.es/ecma262/#prod-YieldExpression
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct YieldExpression {
+    allow_in: AllowIn,
+    allow_await: AllowAwait,
+}
+
+impl YieldExpression {
+    /// Creates a new `YieldExpression` parser.
+    pub(in crate::syntax::parser) fn new<I, A>(allow_in: I, allow_await: A) -> Self
+    where
+        I: Into<AllowIn>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_in: allow_in.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for YieldExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("YieldExpression", "Parsing");
+
+        let start = cursor
+            .expect(TokenKind::Keyword(Keyword::Yield), "yield expression")?
+            .span()
+            .start();
+
+        // A line terminator after `yield` ends the expression.
+        let token = match cursor.peek_expect_no_lineterminator(0, "yield expression") {
+            Ok(token) => token,
+            Err(_) => {
+                let node = Yield::new::<Node, Option<Node>>(None, false);
+                return Ok(cursor.record_expression(node, start));
+            }
+        };
+
+        let node = match token.kind() {
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                cursor.next()?.expect("* token vanished");
+                let expr = AssignmentExpression::new(self.allow_in, true, self.allow_await)
+                    .parse(cursor)?;
+                Yield::new(expr, true)
+            }
+            TokenKind::Punctuator(Punctuator::CloseParen)
+            | TokenKind::Punctuator(Punctuator::CloseBracket)
+            | TokenKind::Punctuator(Punctuator::CloseBlock)
+            | TokenKind::Punctuator(Punctuator::Comma)
+            | TokenKind::Punctuator(Punctuator::Semicolon)
+            | TokenKind::Punctuator(Punctuator::Colon) => {
+                Yield::new::<Node, Option<Node>>(None, false)
+            }
+            _ => {
+                let expr = AssignmentExpression::new(self.allow_in, true, self.allow_await)
+                    .parse(cursor)?;
+                Yield::new(expr, false)
+            }
+        };
+        Ok(cursor.record_expression(node, start))
+    }
+}
@@ -10,9 +10,12 @@
 use super::unary::UnaryExpression;
 
 use crate::syntax::{
-    ast::{node::AwaitExpr, Keyword},
+    ast::{
+        node::{AwaitExpr, Node},
+        Keyword,
+    },
     lexer::TokenKind,
-    parser::{AllowYield, Cursor, ParseError, TokenParser},
+    parser::{AllowYield, Cursor, ParseResult, TokenParser},
 };
 use std::io::Read;
 
@@ -45,14 +48,17 @@ impl<R> TokenParser<R> for AwaitExpression
 where
     R: Read,
 {
-    type Output = AwaitExpr;
+    type Output = Node;
 
-    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        cursor.expect(
-            TokenKind::Keyword(Keyword::Await),
-            "Await expression parsing",
-        )?;
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let start = cursor
+            .expect(
+                TokenKind::Keyword(Keyword::Await),
+                "Await expression parsing",
+            )?
+            .span()
+            .start();
         let expr = UnaryExpression::new(self.allow_yield, true).parse(cursor)?;
-        Ok(expr.into())
+        Ok(cursor.record_expression(AwaitExpr::from(expr), start))
     }
 }
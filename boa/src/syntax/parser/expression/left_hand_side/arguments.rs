@@ -94,14 +94,10 @@ where
                 }
             }
 
-            if cursor.next_if(Punctuator::Spread)?.is_some() {
-                args.push(
-                    Spread::new(
-                        AssignmentExpression::new(true, self.allow_yield, self.allow_await)
-                            .parse(cursor)?,
-                    )
-                    .into(),
-                );
+            if let Some(spread) = cursor.next_if(Punctuator::Spread)? {
+                let val = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                    .parse(cursor)?;
+                args.push(cursor.record_expression(Spread::new(val), spread.span().start()));
             } else {
                 cursor.set_goal(InputElement::RegExp);
                 args.push(
@@ -65,11 +65,13 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("CallExpression", "Parsing");
 
+        // The callee is the last recorded expression.
+        let start = cursor.last_expression_start();
         let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
         let mut lhs = if token.kind() == &TokenKind::Punctuator(Punctuator::OpenParen) {
             let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
-            Node::from(Call::new(self.first_member_expr, args))
+            cursor.record_expression(Call::new(self.first_member_expr, args), start)
         } else {
             let next_token = cursor.next()?.expect("token vanished");
             return Err(ParseError::expected(
@@ -84,17 +86,19 @@ where
             match token.kind() {
                 TokenKind::Punctuator(Punctuator::OpenParen) => {
                     let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
-                    lhs = Node::from(Call::new(lhs, args));
+                    lhs = cursor.record_expression(Call::new(lhs, args), start);
                 }
                 TokenKind::Punctuator(Punctuator::Dot) => {
                     cursor.next()?.ok_or(ParseError::AbruptEnd)?; // We move the parser forward.
 
                     match &cursor.next()?.ok_or(ParseError::AbruptEnd)?.kind() {
                         TokenKind::Identifier(name) => {
-                            lhs = GetConstField::new(lhs, name.clone()).into();
+                            lhs = cursor
+                                .record_expression(GetConstField::new(lhs, name.clone()), start);
                         }
                         TokenKind::Keyword(kw) => {
-                            lhs = GetConstField::new(lhs, kw.to_string()).into();
+                            lhs = cursor
+                                .record_expression(GetConstField::new(lhs, kw.to_string()), start);
                         }
                         _ => {
                             return Err(ParseError::expected(
@@ -110,7 +114,7 @@ where
                     let idx =
                         Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
                     cursor.expect(Punctuator::CloseBracket, "call expression")?;
-                    lhs = GetField::new(lhs, idx).into();
+                    lhs = cursor.record_expression(GetField::new(lhs, idx), start);
                 }
                 TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
                     lhs = TaggedTemplateLiteral::new(
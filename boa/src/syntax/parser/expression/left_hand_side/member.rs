@@ -64,15 +64,15 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("MemberExpression", "Parsing");
 
-        let mut lhs = if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
-            == &TokenKind::Keyword(Keyword::New)
-        {
+        let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let start = token.span().start();
+        let mut lhs = if token.kind() == &TokenKind::Keyword(Keyword::New) {
             let _ = cursor.next().expect("new keyword disappeared");
             let lhs = self.parse(cursor)?;
             let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
             let call_node = Call::new(lhs, args);
 
-            Node::from(New::from(call_node))
+            cursor.record_expression(New::from(call_node), start)
         } else {
             PrimaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?
         };
@@ -85,10 +85,12 @@ where
 
                     match token.kind() {
                         TokenKind::Identifier(name) => {
-                            lhs = GetConstField::new(lhs, name.clone()).into()
+                            lhs = cursor
+                                .record_expression(GetConstField::new(lhs, name.clone()), start)
                         }
                         TokenKind::Keyword(kw) => {
-                            lhs = GetConstField::new(lhs, kw.to_string()).into()
+                            lhs = cursor
+                                .record_expression(GetConstField::new(lhs, kw.to_string()), start)
                         }
                         _ => {
                             return Err(ParseError::expected(
@@ -106,7 +108,7 @@ where
                     let idx =
                         Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
                     cursor.expect(Punctuator::CloseBracket, "member expression")?;
-                    lhs = GetField::new(lhs, idx).into();
+                    lhs = cursor.record_expression(GetField::new(lhs, idx), start);
                 }
                 TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
                     lhs = TaggedTemplateLiteral::new(
@@ -0,0 +1,150 @@
+//! Optional chaining expression parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+//! [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+
+use super::arguments::Arguments;
+use crate::{
+    syntax::{
+        ast::{
+            node::{Node, Optional, OptionalOperation, OptionalOperationKind},
+            Punctuator,
+        },
+        lexer::TokenKind,
+        parser::{
+            expression::Expression, AllowAwait, AllowYield, Cursor, ParseError, ParseResult,
+            TokenParser,
+        },
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Parses an optional expression.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Operators/Optional_chaining
+/// [spec]: https://tc39.es/ecma262/#prod-OptionalExpression
+#[derive(Debug)]
+pub(super) struct OptionalExpression {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+    target: Node,
+}
+
+impl OptionalExpression {
+    /// Creates a new `OptionalExpression` parser.
+    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, target: Node) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+            target,
+        }
+    }
+}
+
+impl<R> TokenParser<R> for OptionalExpression
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
+        let _timer = BoaProfiler::global().start_event("OptionalExpression", "Parsing");
+
+        // The target is the last recorded expression.
+        let start = cursor.last_expression_start();
+        let mut chain = Vec::new();
+        while let Some(token) = cursor.peek(0)? {
+            let optional = match token.kind() {
+                TokenKind::Punctuator(Punctuator::Optional) => {
+                    cursor.next()?.expect("?. token vanished"); // We move the parser forward.
+                    true
+                }
+                TokenKind::Punctuator(Punctuator::Dot)
+                | TokenKind::Punctuator(Punctuator::OpenBracket)
+                | TokenKind::Punctuator(Punctuator::OpenParen) => false,
+                TokenKind::TemplateNoSubstitution { .. } | TokenKind::TemplateMiddle { .. } => {
+                    return Err(ParseError::general(
+                        "tagged template cannot be used in optional chain",
+                        token.span().start(),
+                    ));
+                }
+                _ => break,
+            };
+
+            let token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.clone();
+            let kind = match token.kind() {
+                TokenKind::Identifier(name) if optional => {
+                    cursor.next()?.expect("identifier token vanished");
+                    OptionalOperationKind::SimplePropertyAccess {
+                        field: name.clone(),
+                    }
+                }
+                TokenKind::Keyword(kw) if optional => {
+                    cursor.next()?.expect("keyword token vanished");
+                    OptionalOperationKind::SimplePropertyAccess {
+                        field: kw.to_string().into(),
+                    }
+                }
+                TokenKind::Punctuator(Punctuator::Dot) if !optional => {
+                    cursor.next()?.expect("dot punctuator token vanished");
+
+                    let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                    let field = match token.kind() {
+                        TokenKind::Identifier(name) => name.clone(),
+                        TokenKind::Keyword(kw) => kw.to_string().into(),
+                        _ => {
+                            return Err(ParseError::expected(
+                                vec![TokenKind::identifier("identifier")],
+                                token,
+                                "optional chain",
+                            ));
+                        }
+                    };
+                    OptionalOperationKind::SimplePropertyAccess { field }
+                }
+                TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                    cursor
+                        .next()?
+                        .expect("open bracket punctuator token vanished");
+                    let field =
+                        Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+                    cursor.expect(Punctuator::CloseBracket, "optional chain")?;
+                    OptionalOperationKind::PropertyAccess { field }
+                }
+                TokenKind::Punctuator(Punctuator::OpenParen) => {
+                    let args = Arguments::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                    OptionalOperationKind::Call { args }
+                }
+                _ => {
+                    return Err(ParseError::expected(
+                        vec![
+                            TokenKind::identifier("identifier"),
+                            TokenKind::Punctuator(Punctuator::OpenBracket),
+                            TokenKind::Punctuator(Punctuator::OpenParen),
+                        ],
+                        token,
+                        "optional chain",
+                    ));
+                }
+            };
+
+            chain.push(OptionalOperation::new(kind, optional));
+        }
+
+        Ok(cursor.record_expression(Optional::new(self.target, chain), start))
+    }
+}
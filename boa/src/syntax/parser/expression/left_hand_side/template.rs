@@ -51,6 +51,8 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> ParseResult {
         let _timer = BoaProfiler::global().start_event("TaggedTemplateLiteral", "Parsing");
 
+        // The tag is the last recorded expression.
+        let tag_start = cursor.last_expression_start();
         let mut raws = Vec::new();
         let mut cookeds = Vec::new();
         let mut exprs = Vec::new();
@@ -65,17 +67,20 @@ where
                     exprs.push(
                         Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?,
                     );
-                    cursor.expect(
-                        TokenKind::Punctuator(Punctuator::CloseBlock),
-                        "template literal",
-                    )?;
+                    let start = cursor
+                        .expect(
+                            TokenKind::Punctuator(Punctuator::CloseBlock),
+                            "template literal",
+                        )?
+                        .span()
+                        .start();
+                    token = cursor.lex_template(start)?;
                 }
                 TokenKind::TemplateNoSubstitution(template_string) => {
                     raws.push(template_string.as_raw().to_owned().into_boxed_str());
                     cookeds.push(template_string.to_owned_cooked().ok());
-                    return Ok(Node::from(TaggedTemplate::new(
-                        self.tag, raws, cookeds, exprs,
-                    )));
+                    let node = TaggedTemplate::new(self.tag, raws, cookeds, exprs);
+                    return Ok(cursor.record_expression(node, tag_start));
                 }
                 _ => {
                     return Err(ParseError::general(
@@ -84,7 +89,6 @@ where
                     ))
                 }
             }
-            token = cursor.lex_template(self.start)?;
         }
     }
 }
@@ -80,23 +80,26 @@ macro_rules! expression { ($name:ident, $lower:ident, [$( $op:path ),*], [$( $lo
             }
 
             let mut lhs = $lower::new($( self.$low_param ),*).parse(cursor)?;
+            let start = cursor.last_expression_start();
             while let Some(tok) = cursor.peek(0)? {
                 match *tok.kind() {
                     TokenKind::Punctuator(op) if $( op == $op )||* => {
                         let _ = cursor.next().expect("token disappeared");
-                        lhs = BinOp::new(
+                        let rhs = $lower::new($( self.$low_param ),*).parse(cursor)?;
+                        lhs = cursor.record_expression(BinOp::new(
                             op.as_binop().expect("Could not get binary operation."),
                             lhs,
-                            $lower::new($( self.$low_param ),*).parse(cursor)?
-                        ).into();
+                            rhs
+                        ), start);
                     }
                     TokenKind::Keyword(op) if $( op == $op )||* => {
                         let _ = cursor.next().expect("token disappeared");
-                        lhs = BinOp::new(
+                        let rhs = $lower::new($( self.$low_param ),*).parse(cursor)?;
+                        lhs = cursor.record_expression(BinOp::new(
                             op.as_binop().expect("Could not get binary operation."),
                             lhs,
-                            $lower::new($( self.$low_param ),*).parse(cursor)?
-                        ).into();
+                            rhs
+                        ), start);
                     }
                     _ => break
                 }
@@ -217,6 +220,7 @@ where
         let mut current_node =
             BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
                 .parse(cursor)?;
+        let start = cursor.last_expression_start();
         let mut previous = self.previous;
 
         while let Some(tok) = cursor.peek(0)? {
@@ -235,7 +239,8 @@ where
                         BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
                             .parse(cursor)?;
 
-                    current_node = BinOp::new(LogOp::And, current_node, rhs).into();
+                    current_node =
+                        cursor.record_expression(BinOp::new(LogOp::And, current_node, rhs), start);
                 }
                 TokenKind::Punctuator(Punctuator::BoolOr) => {
                     if previous == PreviousExpr::Coalesce {
@@ -254,7 +259,8 @@ where
                         PreviousExpr::Logical,
                     )
                     .parse(cursor)?;
-                    current_node = BinOp::new(LogOp::Or, current_node, rhs).into();
+                    current_node =
+                        cursor.record_expression(BinOp::new(LogOp::Or, current_node, rhs), start);
                 }
                 TokenKind::Punctuator(Punctuator::Coalesce) => {
                     if previous == PreviousExpr::Logical {
@@ -272,7 +278,8 @@ where
                     let rhs =
                         BitwiseORExpression::new(self.allow_in, self.allow_yield, self.allow_await)
                             .parse(cursor)?;
-                    current_node = BinOp::new(LogOp::Coalesce, current_node, rhs).into();
+                    current_node = cursor
+                        .record_expression(BinOp::new(LogOp::Coalesce, current_node, rhs), start);
                 }
                 _ => break,
             }
@@ -487,6 +494,7 @@ where
         }
 
         let mut lhs = ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let start = cursor.last_expression_start();
         while let Some(tok) = cursor.peek(0)? {
             match *tok.kind() {
                 TokenKind::Punctuator(op)
@@ -496,24 +504,32 @@ where
                         || op == Punctuator::GreaterThanOrEq =>
                 {
                     let _ = cursor.next().expect("token disappeared");
-                    lhs = BinOp::new(
-                        op.as_binop().expect("Could not get binary operation."),
-                        lhs,
-                        ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
-                    )
-                    .into();
+                    let rhs =
+                        ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                    lhs = cursor.record_expression(
+                        BinOp::new(
+                            op.as_binop().expect("Could not get binary operation."),
+                            lhs,
+                            rhs,
+                        ),
+                        start,
+                    );
                 }
                 TokenKind::Keyword(op)
                     if op == Keyword::InstanceOf
                         || (op == Keyword::In && self.allow_in == AllowIn(true)) =>
                 {
                     let _ = cursor.next().expect("token disappeared");
-                    lhs = BinOp::new(
-                        op.as_binop().expect("Could not get binary operation."),
-                        lhs,
-                        ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
-                    )
-                    .into();
+                    let rhs =
+                        ShiftExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                    lhs = cursor.record_expression(
+                        BinOp::new(
+                            op.as_binop().expect("Could not get binary operation."),
+                            lhs,
+                            rhs,
+                        ),
+                        start,
+                    );
                 }
                 _ => break,
             }
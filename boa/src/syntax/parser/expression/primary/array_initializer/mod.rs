@@ -13,7 +13,7 @@ mod tests;
 use crate::{
     syntax::{
         ast::{
-            node::{ArrayDecl, Node, Spread},
+            node::{ArrayDecl, Spread},
             Const, Punctuator,
         },
         parser::{
@@ -66,8 +66,9 @@ where
 
         loop {
             // TODO: Support all features.
-            while cursor.next_if(Punctuator::Comma)?.is_some() {
-                elements.push(Node::Const(Const::Undefined));
+            while let Some(comma) = cursor.next_if(Punctuator::Comma)? {
+                // An elision has the span of its comma.
+                elements.push(cursor.record_expression(Const::Undefined, comma.span().start()));
             }
 
             if cursor.next_if(Punctuator::CloseBracket)?.is_some() {
@@ -76,10 +77,10 @@ where
 
             let _ = cursor.peek(0)?.ok_or(ParseError::AbruptEnd); // Check that there are more tokens to read.
 
-            if cursor.next_if(Punctuator::Spread)?.is_some() {
+            if let Some(spread) = cursor.next_if(Punctuator::Spread)? {
                 let node = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                     .parse(cursor)?;
-                elements.push(Spread::new(node).into());
+                elements.push(cursor.record_expression(Spread::new(node), spread.span().start()));
             } else {
                 elements.push(
                     AssignmentExpression::new(true, self.allow_yield, self.allow_await)
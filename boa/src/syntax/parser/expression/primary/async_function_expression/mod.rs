@@ -6,7 +6,7 @@ use crate::{
         ast::{node::AsyncFunctionExpr, Keyword, Punctuator},
         lexer::{Error as LexError, Position, TokenKind},
         parser::{
-            function::{FormalParameters, FunctionBody},
+            function::{check_strict_body_parameters, FormalParameters, FunctionBody},
             statement::BindingIdentifier,
             AllowYield, Cursor, ParseError, TokenParser,
         },
@@ -68,21 +68,26 @@ where
         let params = FormalParameters::new(false, true).parse(cursor)?;
 
         cursor.expect(Punctuator::CloseParen, "async function expression")?;
-        cursor.expect(Punctuator::OpenBlock, "async function expression")?;
+        let body_start = cursor
+            .expect(Punctuator::OpenBlock, "async function expression")?
+            .span()
+            .start();
 
         let body = FunctionBody::new(false, true).parse(cursor)?;
 
         cursor.expect(Punctuator::CloseBlock, "async function expression")?;
 
+        check_strict_body_parameters(&params, &body, body_start)?;
+
         // It is a Syntax Error if any element of the BoundNames of FormalParameters
         // also occurs in the LexicallyDeclaredNames of FunctionBody.
         // https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
         {
             let lexically_declared_names = body.lexically_declared_names();
-            for param in params.as_ref() {
-                if lexically_declared_names.contains(param.name()) {
+            for param in params.iter().flat_map(|param| param.names()) {
+                if lexically_declared_names.contains(param) {
                     return Err(ParseError::lex(LexError::Syntax(
-                        format!("Redeclaration of formal parameter `{}`", param.name()).into(),
+                        format!("Redeclaration of formal parameter `{}`", param).into(),
                         match cursor.peek(0)? {
                             Some(token) => token.span().end(),
                             None => Position::new(1, 1),
@@ -0,0 +1,73 @@
+use crate::syntax::{
+    ast::{
+        node::{Declaration, DeclarationList, GeneratorExpr, Node, StatementList, Yield},
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Checks generator expression parsing.
+#[test]
+fn check_generator_expression() {
+    check_parser(
+        "const gen = function*() {
+            yield 1;
+        };
+        ",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                "gen",
+                Some(
+                    GeneratorExpr::new::<Option<Box<str>>, _, StatementList>(
+                        None,
+                        [],
+                        vec![Yield::new(Const::from(1), false).into()].into(),
+                    )
+                    .into(),
+                ),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks that a `yield` expression can be used as an operand.
+#[test]
+fn check_generator_expression_yield_operand() {
+    check_parser(
+        "const gen = function* named() {
+            const a = yield;
+        };
+        ",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                "gen",
+                Some(
+                    GeneratorExpr::new::<_, _, StatementList>(
+                        Some("named".into()),
+                        [],
+                        vec![DeclarationList::Const(
+                            vec![Declaration::new(
+                                "a",
+                                Some(Yield::new::<Node, Option<Node>>(None, false).into()),
+                            )]
+                            .into(),
+                        )
+                        .into()]
+                        .into(),
+                    )
+                    .into(),
+                ),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// A generator expression can't be named `yield`.
+#[test]
+fn check_generator_expression_yield_name() {
+    check_invalid("(function* yield() {})");
+}
@@ -10,6 +10,7 @@
 mod array_initializer;
 mod async_function_expression;
 mod function_expression;
+mod generator_expression;
 mod object_initializer;
 mod template;
 #[cfg(test)]
@@ -17,20 +18,21 @@ mod tests;
 
 use self::{
     array_initializer::ArrayLiteral, async_function_expression::AsyncFunctionExpression,
-    function_expression::FunctionExpression, object_initializer::ObjectLiteral,
+    function_expression::FunctionExpression, generator_expression::GeneratorExpression,
+    object_initializer::ObjectLiteral,
 };
-use super::Expression;
+use super::{assignment::ArrowFunction, AssignmentExpression};
 use crate::{
     profiler::BoaProfiler,
     syntax::{
         ast::{
-            node::{Call, Identifier, New, Node},
-            Const, Keyword, Punctuator,
+            node::{BinOp, Call, Identifier, New, Node},
+            op, Const, Keyword, Position, Punctuator, Span,
         },
         lexer::{token::Numeric, InputElement, TokenKind},
         parser::{
-            expression::primary::template::TemplateLiteral, AllowAwait, AllowYield, Cursor,
-            ParseError, ParseResult, TokenParser,
+            expression::primary::template::TemplateLiteral, function::FunctionRestParameter,
+            AllowAwait, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
         },
     },
 };
@@ -76,81 +78,190 @@ where
         let _timer = BoaProfiler::global().start_event("PrimaryExpression", "Parsing");
 
         let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+        let start = tok.span().start();
 
-        match tok.kind() {
-            TokenKind::Keyword(Keyword::This) => Ok(Node::This),
+        let node = match tok.kind() {
+            TokenKind::Keyword(Keyword::This) => Node::This,
+            TokenKind::Keyword(Keyword::Import) => {
+                cursor.expect(Punctuator::Dot, "import.meta")?;
+                let meta = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                match meta.kind() {
+                    TokenKind::Identifier(name) if name.as_ref() == "meta" => {}
+                    _ => {
+                        return Err(ParseError::expected(
+                            vec![TokenKind::identifier("meta")],
+                            meta,
+                            "import.meta",
+                        ))
+                    }
+                }
+
+                if !cursor.module() {
+                    return Err(ParseError::general(
+                        "import.meta may only be used in module code",
+                        tok.span().start(),
+                    ));
+                }
+                Node::ImportMeta
+            }
             TokenKind::Keyword(Keyword::Function) => {
-                FunctionExpression.parse(cursor).map(Node::from)
+                let next = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+                if next.kind() == &TokenKind::Punctuator(Punctuator::Mul) {
+                    GeneratorExpression.parse(cursor)?.into()
+                } else {
+                    FunctionExpression.parse(cursor)?.into()
+                }
             }
             TokenKind::Keyword(Keyword::Async) => AsyncFunctionExpression::new(self.allow_yield)
-                .parse(cursor)
-                .map(Node::from),
+                .parse(cursor)?
+                .into(),
             TokenKind::Punctuator(Punctuator::OpenParen) => {
+                // CoverParenthesizedExpressionAndArrowParameterList: the contents are parsed as
+                // expressions and only reinterpreted as parameters if an arrow follows.
                 cursor.set_goal(InputElement::RegExp);
-                let expr =
-                    Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
+
+                let mark = cursor.expression_mark();
+                let mut items = Vec::new();
+                let mut item_spans = Vec::new();
+                let mut rest = None;
+                let mut trailing_comma = false;
+                loop {
+                    let next = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+                    match next.kind() {
+                        TokenKind::Punctuator(Punctuator::CloseParen) => {
+                            trailing_comma = !items.is_empty();
+                            break;
+                        }
+                        TokenKind::Punctuator(Punctuator::Spread) => {
+                            rest = Some(
+                                FunctionRestParameter::new(self.allow_yield, self.allow_await)
+                                    .parse(cursor)?,
+                            );
+                            break;
+                        }
+                        _ => {}
+                    }
+                    items.push(
+                        AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                            .parse(cursor)?,
+                    );
+                    item_spans.push((
+                        cursor.expression_mark(),
+                        Span::new(cursor.last_expression_start(), cursor.last_token_end()),
+                    ));
+                    if cursor.next_if(Punctuator::Comma)?.is_none() {
+                        break;
+                    }
+                }
                 cursor.expect(Punctuator::CloseParen, "primary expression")?;
-                Ok(expr)
+
+                if let Some(next) = cursor.peek(0)? {
+                    if next.kind() == &TokenKind::Punctuator(Punctuator::Arrow) {
+                        // Arrow function parameters have no span.
+                        cursor.truncate_expressions(mark);
+                        return ArrowFunction::new(true, self.allow_yield, self.allow_await)
+                            .parse_with_cover(&items, rest, start, cursor)
+                            .map(|decl| cursor.record_expression(decl, start));
+                    }
+                }
+
+                if rest.is_some() || items.is_empty() || trailing_comma {
+                    return Err(ParseError::general(
+                        "expected arrow function after parameter list",
+                        start,
+                    ));
+                }
+
+                // The comma operators are only built now, so their spans are inserted after the
+                // spans of their right operands.
+                let mut items = items.into_iter().zip(item_spans);
+                let (mut node, (_, first_span)) =
+                    items.next().expect("checked that the list is not empty");
+                for (offset, (item, (index, span))) in items.enumerate() {
+                    node = BinOp::new(op::BinOp::Comma, node, item).into();
+                    cursor.insert_expression(
+                        index + offset,
+                        &node,
+                        Span::new(first_span.start(), span.end()),
+                    );
+                }
+
+                // The span of a parenthesized expression includes the parentheses.
+                cursor.extend_last_expression(start);
+                return Ok(node);
             }
             TokenKind::Punctuator(Punctuator::OpenBracket) => {
                 cursor.set_goal(InputElement::RegExp);
-                ArrayLiteral::new(self.allow_yield, self.allow_await)
-                    .parse(cursor)
-                    .map(Node::ArrayDecl)
+                Node::ArrayDecl(
+                    ArrayLiteral::new(self.allow_yield, self.allow_await).parse(cursor)?,
+                )
             }
             TokenKind::Punctuator(Punctuator::OpenBlock) => {
                 cursor.set_goal(InputElement::RegExp);
-                Ok(ObjectLiteral::new(self.allow_yield, self.allow_await)
+                ObjectLiteral::new(self.allow_yield, self.allow_await)
                     .parse(cursor)?
-                    .into())
+                    .into()
             }
-            TokenKind::BooleanLiteral(boolean) => Ok(Const::from(*boolean).into()),
-            TokenKind::NullLiteral => Ok(Const::Null.into()),
-            TokenKind::Identifier(ident) => Ok(Identifier::from(ident.as_ref()).into()), // TODO: IdentifierReference
-            TokenKind::StringLiteral(s) => Ok(Const::from(s.as_ref()).into()),
+            TokenKind::BooleanLiteral(boolean) => Const::from(*boolean).into(),
+            TokenKind::NullLiteral => Const::Null.into(),
+            TokenKind::Identifier(ident) => Identifier::from(ident.as_ref()).into(), // TODO: IdentifierReference
+            TokenKind::StringLiteral(s) => Const::from(s.as_ref()).into(),
             TokenKind::TemplateNoSubstitution(template_string) => {
-                Ok(Const::from(template_string.to_owned_cooked().map_err(ParseError::lex)?).into())
-            }
-            TokenKind::NumericLiteral(Numeric::Integer(num)) => Ok(Const::from(*num).into()),
-            TokenKind::NumericLiteral(Numeric::Rational(num)) => Ok(Const::from(*num).into()),
-            TokenKind::NumericLiteral(Numeric::BigInt(num)) => Ok(Const::from(num.clone()).into()),
-            TokenKind::RegularExpressionLiteral(body, flags) => {
-                Ok(Node::from(New::from(Call::new(
-                    Identifier::from("RegExp"),
-                    vec![
-                        Const::from(body.as_ref()).into(),
-                        Const::from(flags.to_string()).into(),
-                    ],
-                ))))
+                Const::from(template_string.to_owned_cooked().map_err(ParseError::lex)?).into()
             }
+            TokenKind::NumericLiteral(Numeric::Integer(num)) => Const::from(*num).into(),
+            TokenKind::NumericLiteral(Numeric::Rational(num)) => Const::from(*num).into(),
+            TokenKind::NumericLiteral(Numeric::BigInt(num)) => Const::from(num.clone()).into(),
+            TokenKind::RegularExpressionLiteral(body, flags) => regexp_literal(
+                cursor,
+                Const::from(body.as_ref()),
+                Const::from(flags.to_string()),
+                start,
+            ),
             TokenKind::Punctuator(Punctuator::Div) => {
                 let tok = cursor.lex_regex(tok.span().start())?;
 
                 if let TokenKind::RegularExpressionLiteral(body, flags) = tok.kind() {
-                    Ok(Node::from(New::from(Call::new(
-                        Identifier::from("RegExp"),
-                        vec![
-                            Const::from(body.as_ref()).into(),
-                            Const::from(flags.to_string()).into(),
-                        ],
-                    ))))
+                    regexp_literal(
+                        cursor,
+                        Const::from(body.as_ref()),
+                        Const::from(flags.to_string()),
+                        start,
+                    )
                 } else {
                     // A regex was expected and nothing else.
-                    Err(ParseError::unexpected(tok, "regular expression literal"))
+                    return Err(ParseError::unexpected(tok, "regular expression literal"));
                 }
             }
-            TokenKind::TemplateMiddle(template_string) => TemplateLiteral::new(
-                self.allow_yield,
-                self.allow_await,
-                tok.span().start(),
-                template_string
-                    .to_owned_cooked()
-                    .map_err(ParseError::lex)?
-                    .as_ref(),
-            )
-            .parse(cursor)
-            .map(Node::TemplateLit),
-            _ => Err(ParseError::unexpected(tok.clone(), "primary expression")),
-        }
+            TokenKind::TemplateMiddle(template_string) => Node::TemplateLit(
+                TemplateLiteral::new(
+                    self.allow_yield,
+                    self.allow_await,
+                    tok.span().start(),
+                    template_string
+                        .to_owned_cooked()
+                        .map_err(ParseError::lex)?
+                        .as_ref(),
+                )
+                .parse(cursor)?,
+            ),
+            _ => return Err(ParseError::unexpected(tok.clone(), "primary expression")),
+        };
+
+        Ok(cursor.record_expression(node, start))
     }
 }
+
+/// Creates the `new RegExp(body, flags)` expression of a regular expression literal.
+///
+/// The span of the literal is recorded for the nodes of the arguments of the constructor, which
+/// don't appear in the source code, so that they match the nodes of the expression.
+fn regexp_literal<R>(cursor: &mut Cursor<R>, body: Const, flags: Const, start: Position) -> Node
+where
+    R: Read,
+{
+    let constructor = cursor.record_expression(Identifier::from("RegExp"), start);
+    let body = cursor.record_expression(body, start);
+    let flags = cursor.record_expression(flags, start);
+    New::from(Call::new(constructor, vec![body, flags])).into()
+}
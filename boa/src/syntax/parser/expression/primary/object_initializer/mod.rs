@@ -9,7 +9,7 @@
 
 #[cfg(test)]
 mod tests;
-use crate::syntax::lexer::TokenKind;
+use crate::syntax::lexer::{Token, TokenKind};
 use crate::{
     syntax::{
         ast::{
@@ -134,7 +134,23 @@ where
             return Ok(node::PropertyDefinition::SpreadObject(node));
         }
 
-        let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
+        // Shorthand property, e.g. `{ x, y }`.
+        let ident = match cursor.peek(0)?.map(Token::kind) {
+            Some(TokenKind::Identifier(ident)) => Some(ident.clone()),
+            _ => None,
+        };
+        if let Some(ident) = ident {
+            if matches!(
+                cursor.peek(1)?.map(Token::kind),
+                Some(&TokenKind::Punctuator(Punctuator::Comma))
+                    | Some(&TokenKind::Punctuator(Punctuator::CloseBlock))
+            ) {
+                let _ = cursor.next()?.expect("identifier disappeared");
+                return Ok(node::PropertyDefinition::identifier_reference(ident));
+            }
+        }
+
+        let prop_name = PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
         if cursor.next_if(Punctuator::Colon)?.is_some() {
             let val = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
                 .parse(cursor)?;
@@ -144,7 +160,7 @@ where
         // TODO GeneratorMethod
         // https://tc39.es/ecma262/#prod-GeneratorMethod
 
-        if prop_name.as_str() == "async" {
+        if matches!(&prop_name, node::PropertyName::Literal(name) if name.as_ref() == "async") {
             // TODO - AsyncMethod.
             // https://tc39.es/ecma262/#prod-AsyncMethod
 
@@ -152,11 +168,13 @@ where
             // https://tc39.es/ecma262/#prod-AsyncGeneratorMethod
         }
 
-        if cursor
-            .next_if(TokenKind::Punctuator(Punctuator::OpenParen))?
-            .is_some()
-            || ["get", "set"].contains(&prop_name.as_str())
-        {
+        if matches!(
+            cursor.peek(0)?.map(Token::kind),
+            Some(&TokenKind::Punctuator(Punctuator::OpenParen))
+        ) || matches!(
+            &prop_name,
+            node::PropertyName::Literal(name) if ["get", "set"].contains(&name.as_ref())
+        ) {
             return MethodDefinition::new(self.allow_yield, self.allow_await, prop_name)
                 .parse(cursor);
         }
@@ -176,7 +194,7 @@ where
 struct MethodDefinition {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
-    identifier: String,
+    identifier: node::PropertyName,
 }
 
 impl MethodDefinition {
@@ -185,7 +203,7 @@ impl MethodDefinition {
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
-        I: Into<String>,
+        I: Into<node::PropertyName>,
     {
         Self {
             allow_yield: allow_yield.into(),
@@ -204,18 +222,21 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("MethodDefinition", "Parsing");
 
-        let (methodkind, prop_name, params) = match self.identifier.as_str() {
-            idn @ "get" | idn @ "set"
-                if matches!(
-                    cursor.peek(0)?.map(|t| t.kind()),
-                    Some(&TokenKind::Identifier(_))
-                        | Some(&TokenKind::Keyword(_))
-                        | Some(&TokenKind::BooleanLiteral(_))
-                        | Some(&TokenKind::NullLiteral)
-                        | Some(&TokenKind::NumericLiteral(_))
-                ) =>
+        let (methodkind, prop_name, params) = match self.identifier {
+            node::PropertyName::Literal(ref idn)
+                if (idn.as_ref() == "get" || idn.as_ref() == "set")
+                    && matches!(
+                        cursor.peek(0)?.map(|t| t.kind()),
+                        Some(&TokenKind::Identifier(_))
+                            | Some(&TokenKind::Keyword(_))
+                            | Some(&TokenKind::BooleanLiteral(_))
+                            | Some(&TokenKind::NullLiteral)
+                            | Some(&TokenKind::NumericLiteral(_))
+                            | Some(&TokenKind::Punctuator(Punctuator::OpenBracket))
+                    ) =>
             {
-                let prop_name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
+                let prop_name =
+                    PropertyName::new(self.allow_yield, self.allow_await).parse(cursor)?;
                 cursor.expect(
                     TokenKind::Punctuator(Punctuator::OpenParen),
                     "property method definition",
@@ -223,7 +244,7 @@ where
                 let first_param = cursor.peek(0)?.expect("current token disappeared").clone();
                 let params = FormalParameters::new(false, false).parse(cursor)?;
                 cursor.expect(Punctuator::CloseParen, "method definition")?;
-                if idn == "get" {
+                if idn.as_ref() == "get" {
                     if !params.is_empty() {
                         return Err(ParseError::unexpected(
                             first_param,
@@ -242,13 +263,10 @@ where
                 }
             }
             prop_name => {
+                cursor.expect(Punctuator::OpenParen, "method definition")?;
                 let params = FormalParameters::new(false, false).parse(cursor)?;
                 cursor.expect(Punctuator::CloseParen, "method definition")?;
-                (
-                    MethodDefinitionKind::Ordinary,
-                    prop_name.to_string(),
-                    params,
-                )
+                (MethodDefinitionKind::Ordinary, prop_name, params)
             }
         };
 
@@ -270,6 +288,54 @@ where
     }
 }
 
+/// Parses a property name.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-PropertyName
+#[derive(Debug, Clone, Copy)]
+struct PropertyName {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl PropertyName {
+    /// Creates a new `PropertyName` parser.
+    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for PropertyName
+where
+    R: Read,
+{
+    type Output = node::PropertyName;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("PropertyName", "Parsing");
+
+        // ComputedPropertyName[Yield, Await] : [ AssignmentExpression[+In, ?Yield, ?Await] ]
+        if cursor.next_if(Punctuator::OpenBracket)?.is_some() {
+            let node = AssignmentExpression::new(true, self.allow_yield, self.allow_await)
+                .parse(cursor)?;
+            cursor.expect(Punctuator::CloseBracket, "computed property name")?;
+            return Ok(node::PropertyName::Computed(node));
+        }
+
+        let name = cursor.next()?.ok_or(ParseError::AbruptEnd)?.to_string();
+        Ok(node::PropertyName::Literal(name.into()))
+    }
+}
+
 /// Initializer parsing.
 ///
 /// More information:
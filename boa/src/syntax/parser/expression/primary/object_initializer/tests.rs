@@ -1,12 +1,12 @@
 use crate::syntax::{
     ast::{
         node::{
-            Declaration, DeclarationList, FormalParameter, FunctionExpr, MethodDefinitionKind,
-            Object, PropertyDefinition,
+            Declaration, DeclarationList, FormalParameter, FunctionExpr, Identifier,
+            MethodDefinitionKind, Node, Object, PropertyDefinition,
         },
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 /// Checks object literal parsing.
@@ -203,3 +203,110 @@ fn check_object_short_function_set() {
         .into()],
     );
 }
+
+#[test]
+fn check_object_short_function_get_set_arguments() {
+    let object_properties = vec![
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Ordinary,
+            "get",
+            FunctionExpr::new(
+                None,
+                vec![FormalParameter::new("target", None, false)],
+                vec![],
+            ),
+        ),
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Ordinary,
+            "set",
+            FunctionExpr::new(
+                None,
+                vec![
+                    FormalParameter::new("target", None, false),
+                    FormalParameter::new("value", None, false),
+                ],
+                vec![],
+            ),
+        ),
+    ];
+
+    check_parser(
+        "const x = {
+            get(target) {},
+            set(target, value) {}
+         };
+        ",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                "x",
+                Some(Object::from(object_properties).into()),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks parsing of computed property names.
+#[test]
+fn check_object_computed_property_names() {
+    let object_properties = vec![
+        PropertyDefinition::property(Node::from(Identifier::from("a")), Const::from(true)),
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Ordinary,
+            Node::from(Const::from("b")),
+            FunctionExpr::new(None, vec![], vec![]),
+        ),
+        PropertyDefinition::method_definition(
+            MethodDefinitionKind::Get,
+            Node::from(Identifier::from("c")),
+            FunctionExpr::new(None, vec![], vec![]),
+        ),
+    ];
+
+    check_parser(
+        "const x = {
+            [a]: true,
+            [\"b\"]() {},
+            get [c]() {},
+        };
+        ",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                "x",
+                Some(Object::from(object_properties).into()),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks parsing of shorthand properties.
+#[test]
+fn check_object_shorthand_properties() {
+    let object_properties = vec![
+        PropertyDefinition::identifier_reference("a"),
+        PropertyDefinition::property("b", Const::from(true)),
+        PropertyDefinition::identifier_reference("c"),
+    ];
+
+    check_parser(
+        "const x = { a, b: true, c };",
+        vec![DeclarationList::Const(
+            vec![Declaration::new(
+                "x",
+                Some(Object::from(object_properties).into()),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks that only identifiers can be shorthand properties.
+#[test]
+fn check_object_invalid_shorthand_property() {
+    check_invalid("const x = { \"a\" };");
+    check_invalid("const x = { 1 };");
+}
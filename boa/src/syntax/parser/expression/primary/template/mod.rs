@@ -68,13 +68,16 @@ where
                 Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?,
             ),
         ];
-        cursor.expect(
-            TokenKind::Punctuator(Punctuator::CloseBlock),
-            "template literal",
-        )?;
+        let mut start = cursor
+            .expect(
+                TokenKind::Punctuator(Punctuator::CloseBlock),
+                "template literal",
+            )?
+            .span()
+            .start();
 
         loop {
-            match cursor.lex_template(self.start)?.kind() {
+            match cursor.lex_template(start)?.kind() {
                 TokenKind::TemplateMiddle(template_string) => {
                     let cooked = template_string.to_owned_cooked().map_err(ParseError::lex)?;
 
@@ -82,10 +85,13 @@ where
                     elements.push(TemplateElement::Expr(
                         Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?,
                     ));
-                    cursor.expect(
-                        TokenKind::Punctuator(Punctuator::CloseBlock),
-                        "template literal",
-                    )?;
+                    start = cursor
+                        .expect(
+                            TokenKind::Punctuator(Punctuator::CloseBlock),
+                            "template literal",
+                        )?
+                        .span()
+                        .start();
                 }
                 TokenKind::TemplateNoSubstitution(template_string) => {
                     let cooked = template_string.to_owned_cooked().map_err(ParseError::lex)?;
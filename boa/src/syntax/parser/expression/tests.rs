@@ -1,7 +1,7 @@
 use crate::syntax::{
-    ast::op::{AssignOp, BitOp, CompOp, LogOp, NumOp},
+    ast::op::{self, AssignOp, BitOp, CompOp, LogOp, NumOp},
     ast::{
-        node::{BinOp, Identifier},
+        node::{ArrayDecl, Assign, BinOp, Call, ConditionalOp, Identifier, Node, UnaryOp},
         Const,
     },
     parser::tests::{check_invalid, check_parser},
@@ -82,6 +82,51 @@ fn check_complex_numeric_operations() {
     );
 }
 
+/// Checks the associativity and precedence of the exponentiation operator.
+#[test]
+fn check_exponentiation_operations() {
+    check_parser(
+        "a ** b ** c",
+        vec![BinOp::new(
+            NumOp::Exp,
+            Identifier::from("a"),
+            BinOp::new(NumOp::Exp, Identifier::from("b"), Identifier::from("c")),
+        )
+        .into()],
+    );
+    check_parser(
+        "a * b ** c",
+        vec![BinOp::new(
+            NumOp::Mul,
+            Identifier::from("a"),
+            BinOp::new(NumOp::Exp, Identifier::from("b"), Identifier::from("c")),
+        )
+        .into()],
+    );
+    check_parser(
+        "(-a) ** b",
+        vec![BinOp::new(
+            NumOp::Exp,
+            UnaryOp::new(op::UnaryOp::Minus, Identifier::from("a")),
+            Identifier::from("b"),
+        )
+        .into()],
+    );
+    check_parser(
+        "a ** -b",
+        vec![BinOp::new(
+            NumOp::Exp,
+            Identifier::from("a"),
+            UnaryOp::new(op::UnaryOp::Minus, Identifier::from("b")),
+        )
+        .into()],
+    );
+
+    check_invalid("-a ** b");
+    check_invalid("typeof a ** b");
+    check_invalid("!a ** b");
+}
+
 /// Checks bitwise operations.
 #[test]
 fn check_bitwise_operations() {
@@ -280,4 +325,117 @@ fn check_logical_expressions() {
     check_invalid("a && b ?? c");
     check_invalid("a ?? b || c");
     check_invalid("a || b ?? c");
+
+    check_parser(
+        "(a ?? b) || c",
+        vec![BinOp::new(
+            LogOp::Or,
+            BinOp::new(
+                LogOp::Coalesce,
+                Identifier::from("a"),
+                Identifier::from("b"),
+            ),
+            Identifier::from("c"),
+        )
+        .into()],
+    );
+}
+
+/// Checks that conditional expressions are right-associative.
+#[test]
+fn check_conditional_expressions() {
+    check_parser(
+        "a ? b : c ? d : e",
+        vec![ConditionalOp::new(
+            Identifier::from("a"),
+            Identifier::from("b"),
+            ConditionalOp::new(
+                Identifier::from("c"),
+                Identifier::from("d"),
+                Identifier::from("e"),
+            ),
+        )
+        .into()],
+    );
+
+    check_parser(
+        "a ? b ? c : d : e",
+        vec![ConditionalOp::new(
+            Identifier::from("a"),
+            ConditionalOp::new(
+                Identifier::from("b"),
+                Identifier::from("c"),
+                Identifier::from("d"),
+            ),
+            Identifier::from("e"),
+        )
+        .into()],
+    );
+
+    check_parser(
+        "a ? b = 1 : c = 2",
+        vec![ConditionalOp::new(
+            Identifier::from("a"),
+            Assign::new(Identifier::from("b"), Const::from(1)),
+            Assign::new(Identifier::from("c"), Const::from(2)),
+        )
+        .into()],
+    );
+
+    check_invalid("a ? b");
+    check_invalid("a ? b : c : d");
+}
+
+/// Checks that a parenthesized comma expression isn't mistaken for an arrow parameter list.
+#[test]
+fn check_parenthesized_comma_expression() {
+    check_parser(
+        "(a, b)",
+        vec![BinOp::new(
+            op::BinOp::Comma,
+            Identifier::from("a"),
+            Identifier::from("b"),
+        )
+        .into()],
+    );
+    check_parser(
+        "(a, b, 1)",
+        vec![BinOp::new(
+            op::BinOp::Comma,
+            BinOp::new(
+                op::BinOp::Comma,
+                Identifier::from("a"),
+                Identifier::from("b"),
+            ),
+            Const::from(1),
+        )
+        .into()],
+    );
+
+    check_invalid("()");
+    check_invalid("(a, )");
+    check_invalid("(a, ...b)");
+}
+
+/// Checks that comma expressions aren't confused with argument lists or array elements.
+#[test]
+fn check_comma_expression_in_lists() {
+    let comma: Node = BinOp::new(
+        op::BinOp::Comma,
+        Identifier::from("a"),
+        Identifier::from("b"),
+    )
+    .into();
+
+    let call: Node = Call::new(
+        Identifier::from("f"),
+        vec![comma.clone(), Identifier::from("c").into()],
+    )
+    .into();
+    check_parser("f((a, b), c)", vec![call.clone()]);
+    assert_eq!(call.to_string(), "f((a, b), c)");
+
+    let array: Node = ArrayDecl::from(vec![comma, Identifier::from("c").into()]).into();
+    check_parser("[(a, b), c]", vec![array.clone()]);
+    assert_eq!(array.to_string(), "[(a, b), c]");
 }
@@ -56,22 +56,21 @@ where
         let _timer = BoaProfiler::global().start_event("UpdateExpression", "Parsing");
 
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let start = tok.span().start();
         match tok.kind() {
             TokenKind::Punctuator(Punctuator::Inc) => {
                 cursor.next()?.expect("Punctuator::Inc token disappeared");
-                return Ok(node::UnaryOp::new(
-                    UnaryOp::IncrementPre,
-                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
-                )
-                .into());
+                let target =
+                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let node = node::UnaryOp::new(UnaryOp::IncrementPre, target);
+                return Ok(cursor.record_expression(node, start));
             }
             TokenKind::Punctuator(Punctuator::Dec) => {
                 cursor.next()?.expect("Punctuator::Dec token disappeared");
-                return Ok(node::UnaryOp::new(
-                    UnaryOp::DecrementPre,
-                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?,
-                )
-                .into());
+                let target =
+                    UnaryExpression::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let node = node::UnaryOp::new(UnaryOp::DecrementPre, target);
+                return Ok(cursor.record_expression(node, start));
             }
             _ => {}
         }
@@ -81,11 +80,13 @@ where
             match tok.kind() {
                 TokenKind::Punctuator(Punctuator::Inc) => {
                     cursor.next()?.expect("Punctuator::Inc token disappeared");
-                    return Ok(node::UnaryOp::new(UnaryOp::IncrementPost, lhs).into());
+                    let node = node::UnaryOp::new(UnaryOp::IncrementPost, lhs);
+                    return Ok(cursor.record_expression(node, start));
                 }
                 TokenKind::Punctuator(Punctuator::Dec) => {
                     cursor.next()?.expect("Punctuator::Dec token disappeared");
-                    return Ok(node::UnaryOp::new(UnaryOp::DecrementPost, lhs).into());
+                    let node = node::UnaryOp::new(UnaryOp::DecrementPost, lhs);
+                    return Ok(cursor.record_expression(node, start));
                 }
                 _ => {}
             }
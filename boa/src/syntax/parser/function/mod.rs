@@ -14,18 +14,20 @@ use crate::{
     syntax::{
         ast::{
             node::{self},
-            Punctuator,
+            Const, Node, Punctuator,
         },
-        lexer::{InputElement, TokenKind},
+        lexer::{Error as LexError, InputElement, Position, TokenKind},
         parser::{
             expression::Initializer,
-            statement::{BindingIdentifier, StatementList},
+            statement::{
+                ArrayBindingPattern, BindingIdentifier, ObjectBindingPattern, StatementList,
+            },
             AllowAwait, AllowYield, Cursor, ParseError, TokenParser,
         },
     },
     BoaProfiler,
 };
-use std::io::Read;
+use std::{collections::HashSet, io::Read};
 
 /// Formal parameters parsing.
 ///
@@ -73,17 +75,32 @@ where
             return Ok(params.into_boxed_slice());
         }
 
+        let mut param_names = HashSet::new();
+        let mut duplicate = None;
+        let mut is_simple = true;
+
         loop {
             let mut rest_param = false;
 
-            let next_param = match cursor.peek(0)? {
-                Some(tok) if tok.kind() == &TokenKind::Punctuator(Punctuator::Spread) => {
+            let next_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+            let position = next_token.span().start();
+            let next_param = match next_token.kind() {
+                TokenKind::Punctuator(Punctuator::Spread) => {
                     rest_param = true;
                     FunctionRestParameter::new(self.allow_yield, self.allow_await).parse(cursor)?
                 }
                 _ => FormalParameter::new(self.allow_yield, self.allow_await).parse(cursor)?,
             };
 
+            if rest_param || !next_param.is_identifier() || next_param.init().is_some() {
+                is_simple = false;
+            }
+            for name in next_param.names() {
+                if !param_names.insert(name.to_owned()) && duplicate.is_none() {
+                    duplicate = Some((name.to_owned(), position));
+                }
+            }
+
             params.push(next_param);
 
             if cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.kind()
@@ -102,6 +119,18 @@ where
             cursor.expect(Punctuator::Comma, "parameter list")?;
         }
 
+        // Duplicate parameter names are only allowed in non-strict functions with a simple
+        // parameter list.
+        // https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
+        if let Some((name, position)) = duplicate {
+            if cursor.strict_mode() || !is_simple {
+                return Err(ParseError::lex(LexError::Syntax(
+                    format!("Duplicate parameter name `{}`", name).into(),
+                    position,
+                )));
+            }
+        }
+
         Ok(params.into_boxed_slice())
     }
 }
@@ -114,7 +143,7 @@ where
 ///
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/rest_parameters
 /// [spec]: https://tc39.es/ecma262/#prod-FunctionRestParameter
-type FunctionRestParameter = BindingRestElement;
+pub(in crate::syntax::parser) type FunctionRestParameter = BindingRestElement;
 
 /// Rest parameter parsing.
 ///
@@ -125,14 +154,14 @@ type FunctionRestParameter = BindingRestElement;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Functions/rest_parameters
 /// [spec]: https://tc39.es/ecma262/#prod-BindingRestElement
 #[derive(Debug, Clone, Copy)]
-struct BindingRestElement {
+pub(in crate::syntax::parser) struct BindingRestElement {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl BindingRestElement {
     /// Creates a new `BindingRestElement` parser.
-    fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -154,10 +183,35 @@ where
         let _timer = BoaProfiler::global().start_event("BindingRestElement", "Parsing");
         cursor.expect(Punctuator::Spread, "rest parameter")?;
 
-        let param = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
-        // TODO: BindingPattern
+        let next = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let declaration = match next.kind() {
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                let bindings =
+                    ObjectBindingPattern::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                node::Declaration::new_with_object_pattern(bindings, None)
+            }
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                let bindings =
+                    ArrayBindingPattern::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                node::Declaration::new_with_array_pattern(bindings, None)
+            }
+            _ => {
+                let param =
+                    BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                node::Declaration::new(param, None)
+            }
+        };
+
+        if let Some(tok) = cursor.peek(0)? {
+            if tok.kind() == &TokenKind::Punctuator(Punctuator::Assign) {
+                return Err(ParseError::general(
+                    "rest parameter may not have a default initializer",
+                    tok.span().start(),
+                ));
+            }
+        }
 
-        Ok(Self::Output::new(param, None, true))
+        Ok(Self::Output::new_with_declaration(declaration, true))
     }
 }
 
@@ -187,6 +241,22 @@ impl FormalParameter {
             allow_await: allow_await.into(),
         }
     }
+
+    /// Parses the initializer of the parameter, if there is one.
+    fn parse_initializer<R>(&self, cursor: &mut Cursor<R>) -> Result<Option<node::Node>, ParseError>
+    where
+        R: Read,
+    {
+        if let Some(t) = cursor.peek(0)? {
+            // Check that this is an initilizer before attempting parse.
+            if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
+                return Ok(Some(
+                    Initializer::new(true, self.allow_yield, self.allow_await).parse(cursor)?,
+                ));
+            }
+        }
+        Ok(None)
+    }
 }
 
 impl<R> TokenParser<R> for FormalParameter
@@ -198,23 +268,72 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("FormalParameter", "Parsing");
 
-        // TODO: BindingPattern
-
-        let param = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
-
-        let init = if let Some(t) = cursor.peek(0)? {
-            // Check that this is an initilizer before attempting parse.
-            if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
-                Some(Initializer::new(true, self.allow_yield, self.allow_await).parse(cursor)?)
-            } else {
-                None
+        let next = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let declaration = match next.kind() {
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                let bindings =
+                    ObjectBindingPattern::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let init = self.parse_initializer(cursor)?;
+                node::Declaration::new_with_object_pattern(bindings, init)
+            }
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                let bindings =
+                    ArrayBindingPattern::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let init = self.parse_initializer(cursor)?;
+                node::Declaration::new_with_array_pattern(bindings, init)
+            }
+            _ => {
+                let param =
+                    BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let init = self.parse_initializer(cursor)?;
+                node::Declaration::new(param, init)
             }
-        } else {
-            None
         };
 
-        Ok(Self::Output::new(param, init, false))
+        Ok(Self::Output::new_with_declaration(declaration, false))
+    }
+}
+
+/// Checks for duplicate parameter names in a function whose directive prologue contains a
+/// `"use strict"` directive.
+///
+/// `FormalParameters` are parsed before the body, so they can only be checked against the strict
+/// mode of the surrounding code. The body's directive makes the whole function strict, including
+/// its parameters.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-function-definitions-static-semantics-early-errors
+pub(in crate::syntax::parser) fn check_strict_body_parameters(
+    params: &[node::FormalParameter],
+    body: &node::StatementList,
+    position: Position,
+) -> Result<(), ParseError> {
+    // The directive prologue is the sequence of string literal statements at the start of the
+    // body, such as `"a"; "use strict";`.
+    let is_strict_body = body
+        .items()
+        .iter()
+        .take_while(|item| matches!(item, Node::Const(Const::String(_))))
+        .any(|item| match item {
+            Node::Const(Const::String(directive)) => &**directive == "use strict",
+            _ => false,
+        });
+    if !is_strict_body {
+        return Ok(());
     }
+
+    let mut param_names = HashSet::new();
+    for name in params.iter().flat_map(|param| param.names()) {
+        if !param_names.insert(name) {
+            return Err(ParseError::lex(LexError::Syntax(
+                format!("Duplicate parameter name `{}`", name).into(),
+                position,
+            )));
+        }
+    }
+    Ok(())
 }
 
 /// A `FunctionBody` is equivalent to a `FunctionStatementList`.
@@ -276,6 +395,9 @@ where
             }
         }
 
+        // The labels of the statements around the function cannot be targeted from its body.
+        let outer_labels = cursor.replace_labels(Vec::new());
+
         let stmlist = StatementList::new(
             self.allow_yield,
             self.allow_await,
@@ -285,8 +407,9 @@ where
         )
         .parse(cursor);
 
-        // Reset strict mode back to the global scope.
+        // Reset strict mode and labels back to the enclosing scope.
         cursor.set_strict_mode(global_strict_mode);
+        cursor.replace_labels(outer_labels);
         stmlist
     }
 }
@@ -1,10 +1,14 @@
 use crate::syntax::{
     ast::node::{
-        ArrowFunctionDecl, BinOp, Declaration, DeclarationList, FormalParameter, FunctionDecl,
-        Identifier, Node, Return,
+        ArrowFunctionDecl, BinOp, BindingPatternTypeArray, BindingPatternTypeObject, Declaration,
+        DeclarationList, FormalParameter, FunctionDecl, Identifier, Node, Object,
+        PropertyDefinition, Return,
+    },
+    ast::{op::NumOp, Const},
+    parser::{
+        tests::{check_invalid, check_parser},
+        Parser,
     },
-    ast::op::NumOp,
-    parser::tests::check_parser,
 };
 
 /// Checks basic function declaration parsing.
@@ -126,6 +130,23 @@ fn check_arrow() {
     );
 }
 
+/// Checks an arrow function whose expression body is a parenthesized object literal.
+#[test]
+fn check_arrow_object_literal_body() {
+    check_parser(
+        "() => ({})",
+        vec![ArrowFunctionDecl::new(
+            vec![],
+            vec![Return::new::<Node, Option<_>, Option<_>>(
+                Some(Object::from(Vec::<PropertyDefinition>::new()).into()),
+                None,
+            )
+            .into()],
+        )
+        .into()],
+    );
+}
+
 /// Checks an arrow function with expression return and automatic semicolon insertion
 #[test]
 fn check_arrow_semicolon_insertion() {
@@ -391,3 +412,197 @@ fn check_arrow_assignment_3arg_nobrackets() {
         .into()],
     );
 }
+
+/// Checks parsing of function parameters with binding patterns.
+#[test]
+fn check_pattern_parameters() {
+    check_parser(
+        "function foo({ x, y = 2 }, [a, b] = c) {}",
+        vec![FunctionDecl::new(
+            Box::from("foo"),
+            vec![
+                FormalParameter::new_with_declaration(
+                    Declaration::new_with_object_pattern(
+                        vec![
+                            BindingPatternTypeObject::SingleName {
+                                ident: "x".into(),
+                                property_name: "x".into(),
+                                default_init: None,
+                            },
+                            BindingPatternTypeObject::SingleName {
+                                ident: "y".into(),
+                                property_name: "y".into(),
+                                default_init: Some(Const::from(2).into()),
+                            },
+                        ],
+                        None,
+                    ),
+                    false,
+                ),
+                FormalParameter::new_with_declaration(
+                    Declaration::new_with_array_pattern(
+                        vec![
+                            BindingPatternTypeArray::SingleName {
+                                ident: "a".into(),
+                                default_init: None,
+                            },
+                            BindingPatternTypeArray::SingleName {
+                                ident: "b".into(),
+                                default_init: None,
+                            },
+                        ],
+                        Some(Identifier::from("c").into()),
+                    ),
+                    false,
+                ),
+            ],
+            vec![],
+        )
+        .into()],
+    );
+}
+
+/// Checks parsing of default parameter values.
+#[test]
+fn check_default_parameters() {
+    check_parser(
+        "function foo(a, b = a + 1) {}",
+        vec![FunctionDecl::new(
+            Box::from("foo"),
+            vec![
+                FormalParameter::new("a", None, false),
+                FormalParameter::new(
+                    "b",
+                    Some(BinOp::new(NumOp::Add, Identifier::from("a"), Const::from(1)).into()),
+                    false,
+                ),
+            ],
+            vec![],
+        )
+        .into()],
+    );
+}
+
+/// Checks that a rest parameter can't have a default value.
+#[test]
+fn check_rest_parameter_default() {
+    check_invalid("function foo(...a = []) {}");
+    check_invalid("function foo(a, ...[b] = []) {}");
+}
+
+/// Checks that duplicate parameter names are allowed in non-strict functions with a simple
+/// parameter list.
+#[test]
+fn check_duplicate_parameters_non_strict() {
+    check_parser(
+        "function foo(a, a) {}",
+        vec![FunctionDecl::new(
+            Box::from("foo"),
+            vec![
+                FormalParameter::new("a", None, false),
+                FormalParameter::new("a", None, false),
+            ],
+            vec![],
+        )
+        .into()],
+    );
+}
+
+/// Checks that duplicate parameter names are an error in strict mode, and in functions with
+/// non-simple parameter lists.
+#[test]
+fn check_duplicate_parameters_strict() {
+    check_invalid("'use strict'; function foo(a, a) {}");
+    check_invalid("function foo(a, a = 1) {}");
+    check_invalid("function foo(a, ...a) {}");
+    check_invalid("function foo(a, { a }) {}");
+    check_invalid("function foo(a, a) { 'use strict'; }");
+    check_invalid("function foo(a, a) { 'a'; 'use strict'; }");
+    check_invalid("(function (a, a) { 'use strict'; })");
+    check_invalid("function* foo(a, a) { 'use strict'; }");
+
+    let error = Parser::new("function foo(a, b, a) {}".as_bytes(), true)
+        .parse_all()
+        .expect_err("duplicate parameters should be an error in strict mode");
+    assert_eq!(
+        error.to_string(),
+        "Syntax Error: Duplicate parameter name `a` at position: 1:20"
+    );
+}
+
+/// Checks arrow function parameters with default values.
+#[test]
+fn check_arrow_default_parameters() {
+    check_parser(
+        "(a = 1, b) => a",
+        vec![ArrowFunctionDecl::new(
+            vec![
+                FormalParameter::new("a", Some(Const::from(1).into()), false),
+                FormalParameter::new("b", None, false),
+            ],
+            vec![Return::new::<Node, Option<_>, Option<_>>(
+                Some(Identifier::from("a").into()),
+                None,
+            )
+            .into()],
+        )
+        .into()],
+    );
+}
+
+/// Checks arrow function parameters with binding patterns.
+#[test]
+fn check_arrow_pattern_parameters() {
+    check_parser(
+        "({ x: x, y: z = 2 }, [a, b]) => {}",
+        vec![ArrowFunctionDecl::new(
+            vec![
+                FormalParameter::new_with_declaration(
+                    Declaration::new_with_object_pattern(
+                        vec![
+                            BindingPatternTypeObject::SingleName {
+                                ident: "x".into(),
+                                property_name: "x".into(),
+                                default_init: None,
+                            },
+                            BindingPatternTypeObject::SingleName {
+                                ident: "z".into(),
+                                property_name: "y".into(),
+                                default_init: Some(Const::from(2).into()),
+                            },
+                        ],
+                        None,
+                    ),
+                    false,
+                ),
+                FormalParameter::new_with_declaration(
+                    Declaration::new_with_array_pattern(
+                        vec![
+                            BindingPatternTypeArray::SingleName {
+                                ident: "a".into(),
+                                default_init: None,
+                            },
+                            BindingPatternTypeArray::SingleName {
+                                ident: "b".into(),
+                                default_init: None,
+                            },
+                        ],
+                        None,
+                    ),
+                    false,
+                ),
+            ],
+            vec![],
+        )
+        .into()],
+    );
+}
+
+/// Checks invalid arrow function parameter lists.
+#[test]
+fn check_arrow_invalid_parameters() {
+    check_invalid("(a, a) => {}");
+    check_invalid("(a + 1) => {}");
+    check_invalid("(1, b) => {}");
+    check_invalid("(a, ...b, c) => {}");
+}
@@ -4,6 +4,7 @@ mod cursor;
 pub mod error;
 mod expression;
 mod function;
+mod module;
 mod statement;
 #[cfg(test)]
 mod tests;
@@ -98,12 +99,77 @@ impl<R> Parser<R> {
         Self { cursor }
     }
 
+    /// Creates a new parser for module code.
+    ///
+    /// Module code is always strict, and can use module-only syntax such as `import.meta`.
+    pub fn new_module(reader: R) -> Self
+    where
+        R: Read,
+    {
+        let mut cursor = Cursor::new(reader);
+        cursor.set_strict_mode(true);
+        cursor.set_module(true);
+
+        Self { cursor }
+    }
+
     pub fn parse_all(&mut self) -> Result<StatementList, ParseError>
     where
         R: Read,
     {
         Script.parse(&mut self.cursor)
     }
+
+    /// Parses the full source as a module.
+    ///
+    /// Module code is always strict, its top level can contain `import` and `export` declarations,
+    /// and `await` expressions, and it can use module-only syntax such as `import.meta`.
+    ///
+    /// More information:
+    ///  - [ECMAScript specification][spec]
+    ///
+    /// [spec]: https://tc39.es/ecma262/#prod-Module
+    pub fn parse_module(&mut self) -> Result<StatementList, ParseError>
+    where
+        R: Read,
+    {
+        self::module::Module.parse(&mut self.cursor)
+    }
+
+    /// Parses the full script, without stopping at the first syntax error.
+    ///
+    /// When a statement can't be parsed, the error is recorded and parsing resumes after the end
+    /// of the statement (the next `;` or `}`), so that all the independent errors of a script can
+    /// be reported at once.
+    ///
+    /// The statement list is only returned if no error was found.
+    pub fn parse_all_recoverable(&mut self) -> (Option<StatementList>, Vec<ParseError>)
+    where
+        R: Read,
+    {
+        let mut errors = Vec::new();
+
+        match self.cursor.peek(0) {
+            Ok(Some(tok)) => match tok.kind() {
+                TokenKind::StringLiteral(string) if string.as_ref() == "use strict" => {
+                    self.cursor.set_strict_mode(true);
+                }
+                _ => {}
+            },
+            Ok(None) => return (Some(StatementList::from(Vec::new())), errors),
+            Err(e) => errors.push(e),
+        }
+
+        let mut list = self::statement::StatementList::new(false, false, false, true, &[])
+            .parse_recoverable(&mut self.cursor, &mut errors);
+        list.set_expression_spans(self.cursor.take_expression_spans());
+
+        if errors.is_empty() {
+            (Some(list), errors)
+        } else {
+            (None, errors)
+        }
+    }
 }
 
 /// Parses a full script.
@@ -130,7 +196,9 @@ where
                     }
                     _ => {}
                 }
-                ScriptBody.parse(cursor)
+                let mut list = ScriptBody.parse(cursor)?;
+                list.set_expression_spans(cursor.take_expression_spans());
+                Ok(list)
             }
             None => Ok(StatementList::from(Vec::new())),
         }
@@ -0,0 +1,477 @@
+//! Module parsing.
+//!
+//! More information:
+//!  - [MDN documentation][mdn]
+//!  - [ECMAScript specification][spec]
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Guide/Modules
+//! [spec]: https://tc39.es/ecma262/#sec-modules
+
+#[cfg(test)]
+mod tests;
+
+use super::{
+    expression::AssignmentExpression,
+    statement::{
+        BindingIdentifier, Declaration, StatementList, StatementListItem, VariableStatement,
+    },
+    Cursor, ParseError, TokenParser,
+};
+use crate::{
+    syntax::{
+        ast::{
+            node::{self, ExportDecl, ExportSpecifier, ImportDecl, ImportSpecifier},
+            Keyword, Node, Position, Punctuator, Span,
+        },
+        lexer::{Token, TokenKind},
+    },
+    BoaProfiler,
+};
+
+use std::io::Read;
+
+/// Parses a full module.
+///
+/// Module code is always strict, and its top level can contain `import` and `export`
+/// declarations, as well as `await` expressions.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-Module
+#[derive(Debug, Clone, Copy)]
+pub(super) struct Module;
+
+impl<R> TokenParser<R> for Module
+where
+    R: Read,
+{
+    type Output = node::StatementList;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        cursor.set_strict_mode(true);
+        cursor.set_module(true);
+
+        let mut list = ModuleItemList.parse(cursor)?;
+        list.set_expression_spans(cursor.take_expression_spans());
+        Ok(list)
+    }
+}
+
+/// Parses the list of items of a module.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItemList
+#[derive(Debug, Clone, Copy)]
+struct ModuleItemList;
+
+impl<R> TokenParser<R> for ModuleItemList
+where
+    R: Read,
+{
+    type Output = node::StatementList;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ModuleItemList", "Parsing");
+        let mut items = Vec::new();
+        let mut spans = Vec::new();
+        let mut expression_spans = Vec::new();
+
+        while let Some(token) = cursor.peek(0)? {
+            let start = token.span().start();
+
+            let mark = cursor.expression_mark();
+            let item = ModuleItem.parse(cursor)?;
+            expression_spans.push(cursor.register_expressions(mark, &item));
+            items.push(item);
+            spans.push(Span::new(start, cursor.last_token_end()));
+
+            // move the cursor forward for any consecutive semicolon.
+            while cursor.next_if(Punctuator::Semicolon)?.is_some() {}
+        }
+
+        StatementList::check_redeclarations(&items, &spans)?;
+
+        Ok(StatementList::hoist(cursor, items, spans, expression_spans))
+    }
+}
+
+/// Parses an item of a module.
+///
+/// This can be an `ImportDeclaration`, an `ExportDeclaration` or a `StatementListItem`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleItem
+#[derive(Debug, Clone, Copy)]
+struct ModuleItem;
+
+impl<R> TokenParser<R> for ModuleItem
+where
+    R: Read,
+{
+    type Output = Node;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ModuleItem", "Parsing");
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+
+        match tok.kind() {
+            TokenKind::Keyword(Keyword::Import) => {
+                if let Some(TokenKind::Punctuator(Punctuator::Dot)) =
+                    cursor.peek(1)?.map(Token::kind)
+                {
+                    // `import.meta` starts an expression statement.
+                    return StatementListItem::new(false, true, false, false).parse(cursor);
+                }
+
+                ImportDeclaration.parse(cursor).map(Node::from)
+            }
+            TokenKind::Keyword(Keyword::Export) => ExportDeclaration.parse(cursor).map(Node::from),
+            _ => StatementListItem::new(false, true, false, false).parse(cursor),
+        }
+    }
+}
+
+/// Parses an `import` declaration.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/import
+/// [spec]: https://tc39.es/ecma262/#prod-ImportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ImportDeclaration;
+
+impl<R> TokenParser<R> for ImportDeclaration
+where
+    R: Read,
+{
+    type Output = ImportDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ImportDeclaration", "Parsing");
+        cursor.expect(Keyword::Import, "import declaration")?;
+
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        if let TokenKind::StringLiteral(_) = tok.kind() {
+            let module = module_specifier(cursor)?;
+            cursor.expect_semicolon("import declaration")?;
+            return Ok(ImportDecl::new(None, None, Vec::new(), module));
+        }
+
+        let mut default = None;
+        if !matches!(
+            tok.kind(),
+            TokenKind::Punctuator(Punctuator::Mul) | TokenKind::Punctuator(Punctuator::OpenBlock)
+        ) {
+            default = Some(BindingIdentifier::new(false, true).parse(cursor)?);
+        }
+
+        let mut namespace = None;
+        let mut specifiers = Vec::new();
+        if default.is_none() || cursor.next_if(Punctuator::Comma)?.is_some() {
+            let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+            if let TokenKind::Punctuator(Punctuator::Mul) = tok.kind() {
+                cursor.next()?.expect("* token vanished");
+                expect_contextual(cursor, "as", "namespace import")?;
+                namespace = Some(BindingIdentifier::new(false, true).parse(cursor)?);
+            } else {
+                specifiers = NamedImports.parse(cursor)?;
+            }
+        }
+
+        expect_contextual(cursor, "from", "import declaration")?;
+        let module = module_specifier(cursor)?;
+        cursor.expect_semicolon("import declaration")?;
+
+        Ok(ImportDecl::new(default, namespace, specifiers, module))
+    }
+}
+
+/// Parses the named imports of an `import` declaration: `{ a, b as c }`.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-NamedImports
+#[derive(Debug, Clone, Copy)]
+struct NamedImports;
+
+impl<R> TokenParser<R> for NamedImports
+where
+    R: Read,
+{
+    type Output = Vec<ImportSpecifier>;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        cursor.expect(Punctuator::OpenBlock, "named imports")?;
+
+        let mut specifiers = Vec::new();
+        while cursor.next_if(Punctuator::CloseBlock)?.is_none() {
+            let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+            let start = tok.span().start();
+            let (imported, is_identifier) = module_export_name(cursor)?;
+
+            let local = if next_is_contextual(cursor, "as")? {
+                cursor.next()?.expect("as token vanished");
+                BindingIdentifier::new(false, true).parse(cursor)?
+            } else if is_identifier {
+                imported.clone()
+            } else {
+                return Err(ParseError::general(
+                    "an import of a reserved word or a string must be renamed with `as`",
+                    start,
+                ));
+            };
+            specifiers.push(ImportSpecifier::new(imported, local));
+
+            if cursor.next_if(Punctuator::Comma)?.is_none() {
+                cursor.expect(Punctuator::CloseBlock, "named imports")?;
+                break;
+            }
+        }
+
+        Ok(specifiers)
+    }
+}
+
+/// Parses an `export` declaration.
+///
+/// More information:
+///  - [MDN documentation][mdn]
+///  - [ECMAScript specification][spec]
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/export
+/// [spec]: https://tc39.es/ecma262/#prod-ExportDeclaration
+#[derive(Debug, Clone, Copy)]
+struct ExportDeclaration;
+
+impl<R> TokenParser<R> for ExportDeclaration
+where
+    R: Read,
+{
+    type Output = ExportDecl;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ExportDeclaration", "Parsing");
+        cursor.expect(Keyword::Export, "export declaration")?;
+
+        let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        match tok.kind() {
+            TokenKind::Punctuator(Punctuator::Mul) => {
+                cursor.next()?.expect("* token vanished");
+                expect_contextual(cursor, "from", "export declaration")?;
+                let module = module_specifier(cursor)?;
+                cursor.expect_semicolon("export declaration")?;
+
+                Ok(ExportDecl::All { module })
+            }
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                let (specifiers, reserved) = ExportClause.parse(cursor)?;
+                let module = if next_is_contextual(cursor, "from")? {
+                    cursor.next()?.expect("from token vanished");
+                    Some(module_specifier(cursor)?)
+                } else if let Some(position) = reserved {
+                    return Err(ParseError::general(
+                        "only bindings can be exported without a `from` clause",
+                        position,
+                    ));
+                } else {
+                    None
+                };
+                cursor.expect_semicolon("export declaration")?;
+
+                Ok(ExportDecl::Named {
+                    specifiers: specifiers.into(),
+                    module,
+                })
+            }
+            TokenKind::Keyword(Keyword::Var) => {
+                let list = VariableStatement::new(false, true).parse(cursor)?;
+                Ok(ExportDecl::Declaration(Box::new(list.into())))
+            }
+            TokenKind::Keyword(Keyword::Function)
+            | TokenKind::Keyword(Keyword::Async)
+            | TokenKind::Keyword(Keyword::Const)
+            | TokenKind::Keyword(Keyword::Let) => {
+                let decl = Declaration::new(false, true, true).parse(cursor)?;
+                Ok(ExportDecl::Declaration(Box::new(decl)))
+            }
+            TokenKind::Keyword(Keyword::Default) => {
+                cursor.next()?.expect("default token vanished");
+
+                if is_named_function(cursor)? {
+                    let decl = Declaration::new(false, true, true).parse(cursor)?;
+                    return Ok(ExportDecl::Default(Box::new(decl)));
+                }
+
+                let expr = AssignmentExpression::new(true, false, true).parse(cursor)?;
+                cursor.expect_semicolon("export declaration")?;
+
+                Ok(ExportDecl::Default(Box::new(expr)))
+            }
+            _ => Err(ParseError::unexpected(tok.clone(), "export declaration")),
+        }
+    }
+}
+
+/// Parses the export specifiers of an `export` declaration: `{ a, b as c }`.
+///
+/// Along with the specifiers, it returns the position of the first one that exports a reserved
+/// word or a string, which is only allowed when re-exporting the bindings of another module.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ExportClause
+#[derive(Debug, Clone, Copy)]
+struct ExportClause;
+
+impl<R> TokenParser<R> for ExportClause
+where
+    R: Read,
+{
+    type Output = (Vec<ExportSpecifier>, Option<Position>);
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        cursor.expect(Punctuator::OpenBlock, "export clause")?;
+
+        let mut specifiers = Vec::new();
+        let mut reserved = None;
+        while cursor.next_if(Punctuator::CloseBlock)?.is_none() {
+            let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+            let start = tok.span().start();
+            let (local, is_identifier) = module_export_name(cursor)?;
+            if !is_identifier && reserved.is_none() {
+                reserved = Some(start);
+            }
+
+            let exported = if next_is_contextual(cursor, "as")? {
+                cursor.next()?.expect("as token vanished");
+                module_export_name(cursor)?.0
+            } else {
+                local.clone()
+            };
+            specifiers.push(ExportSpecifier::new(local, exported));
+
+            if cursor.next_if(Punctuator::Comma)?.is_none() {
+                cursor.expect(Punctuator::CloseBlock, "export clause")?;
+                break;
+            }
+        }
+
+        Ok((specifiers, reserved))
+    }
+}
+
+/// Parses the name of an export, which can be any identifier name, including reserved words,
+/// or a string literal.
+///
+/// Returns the name, and whether it is also a valid identifier reference.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleExportName
+fn module_export_name<R>(cursor: &mut Cursor<R>) -> Result<(Box<str>, bool), ParseError>
+where
+    R: Read,
+{
+    let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+    match tok.kind() {
+        TokenKind::Identifier(ref name) => Ok((name.clone(), true)),
+        TokenKind::Keyword(keyword) => Ok((keyword.as_str().into(), false)),
+        TokenKind::BooleanLiteral(value) => Ok((value.to_string().into(), false)),
+        TokenKind::NullLiteral => Ok(("null".into(), false)),
+        TokenKind::StringLiteral(ref name) => Ok((name.clone(), false)),
+        _ => Err(ParseError::expected(
+            vec![TokenKind::identifier("identifier")],
+            tok,
+            "module export name",
+        )),
+    }
+}
+
+/// Parses the string literal specifying the module of an import or export.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ModuleSpecifier
+fn module_specifier<R>(cursor: &mut Cursor<R>) -> Result<Box<str>, ParseError>
+where
+    R: Read,
+{
+    let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+    match tok.kind() {
+        TokenKind::StringLiteral(ref module) => Ok(module.clone()),
+        _ => Err(ParseError::expected(
+            vec![TokenKind::string_literal("module specifier")],
+            tok,
+            "module specifier",
+        )),
+    }
+}
+
+/// Checks if the next token is the contextual keyword `name`, such as `as` or `from`.
+fn next_is_contextual<R>(cursor: &mut Cursor<R>, name: &str) -> Result<bool, ParseError>
+where
+    R: Read,
+{
+    Ok(matches!(
+        cursor.peek(0)?.map(Token::kind),
+        Some(TokenKind::Identifier(ref ident)) if ident.as_ref() == name
+    ))
+}
+
+/// Consumes the contextual keyword `name`, or returns an error if it's not the next token.
+fn expect_contextual<R>(
+    cursor: &mut Cursor<R>,
+    name: &str,
+    context: &'static str,
+) -> Result<(), ParseError>
+where
+    R: Read,
+{
+    let tok = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+    match tok.kind() {
+        TokenKind::Identifier(ref ident) if ident.as_ref() == name => Ok(()),
+        _ => Err(ParseError::expected(
+            vec![TokenKind::identifier(name)],
+            tok,
+            context,
+        )),
+    }
+}
+
+/// Checks if the cursor is at a named function declaration, which is exported under its own
+/// name by `export default`, unlike other expressions.
+fn is_named_function<R>(cursor: &mut Cursor<R>) -> Result<bool, ParseError>
+where
+    R: Read,
+{
+    let mut skip = 0;
+    if let Some(TokenKind::Keyword(Keyword::Async)) = cursor.peek(skip)?.map(Token::kind) {
+        skip += 1;
+    }
+    if !matches!(
+        cursor.peek(skip)?.map(Token::kind),
+        Some(TokenKind::Keyword(Keyword::Function))
+    ) {
+        return Ok(false);
+    }
+    skip += 1;
+    if let Some(TokenKind::Punctuator(Punctuator::Mul)) = cursor.peek(skip)?.map(Token::kind) {
+        skip += 1;
+    }
+
+    Ok(matches!(
+        cursor.peek(skip)?.map(Token::kind),
+        Some(TokenKind::Identifier(_))
+    ))
+}
@@ -0,0 +1,148 @@
+use crate::syntax::{
+    ast::{
+        node::{
+            AwaitExpr, BinOp, Call, Declaration, DeclarationList, ExportDecl, ExportSpecifier,
+            FunctionDecl, Identifier, ImportDecl, ImportSpecifier, Node, StatementList,
+        },
+        op::NumOp,
+        Const,
+    },
+    parser::{tests::check_invalid, Parser},
+};
+
+/// Checks that the given JavaScript module gives the expected statement list.
+#[track_caller]
+fn check_module<L>(js: &str, expr: L)
+where
+    L: Into<Box<[Node]>>,
+{
+    assert_eq!(
+        Parser::new(js.as_bytes(), false)
+            .parse_module()
+            .expect("failed to parse module"),
+        StatementList::from(expr)
+    );
+}
+
+/// Checks that the given JavaScript module creates a parse error.
+#[track_caller]
+fn check_invalid_module(js: &str) {
+    assert!(Parser::new(js.as_bytes(), false).parse_module().is_err());
+}
+
+#[test]
+fn export_const() {
+    check_module(
+        "export const x = 1;",
+        vec![ExportDecl::Declaration(Box::new(
+            DeclarationList::Const(vec![Declaration::new("x", Some(Const::from(1).into()))].into())
+                .into(),
+        ))
+        .into()],
+    );
+}
+
+#[test]
+fn import_named() {
+    check_module(
+        r#"import { y } from "m";"#,
+        vec![ImportDecl::new(None, None, vec![ImportSpecifier::new("y", "y")], "m").into()],
+    );
+}
+
+#[test]
+fn import_forms() {
+    check_module(
+        r#"
+        import "side-effect";
+        import def, * as ns from "m";
+        import { a as b, default as c, } from "n";
+        "#,
+        vec![
+            ImportDecl::new(None, None, vec![], "side-effect").into(),
+            ImportDecl::new(Some("def".into()), Some("ns".into()), vec![], "m").into(),
+            ImportDecl::new(
+                None,
+                None,
+                vec![
+                    ImportSpecifier::new("a", "b"),
+                    ImportSpecifier::new("default", "c"),
+                ],
+                "n",
+            )
+            .into(),
+        ],
+    );
+}
+
+#[test]
+fn export_forms() {
+    check_module(
+        r#"
+        export { a, b as default };
+        export { c as d } from "m";
+        export * from "n";
+        export default a + 1;
+        "#,
+        vec![
+            ExportDecl::Named {
+                specifiers: vec![
+                    ExportSpecifier::new("a", "a"),
+                    ExportSpecifier::new("b", "default"),
+                ]
+                .into(),
+                module: None,
+            }
+            .into(),
+            ExportDecl::Named {
+                specifiers: vec![ExportSpecifier::new("c", "d")].into(),
+                module: Some("m".into()),
+            }
+            .into(),
+            ExportDecl::All { module: "n".into() }.into(),
+            ExportDecl::Default(Box::new(
+                BinOp::new(NumOp::Add, Identifier::from("a"), Const::from(1)).into(),
+            ))
+            .into(),
+        ],
+    );
+}
+
+#[test]
+fn export_function_is_hoisted() {
+    check_module(
+        "f(); export function f() {}",
+        vec![
+            ExportDecl::Declaration(Box::new(
+                FunctionDecl::new(Box::from("f"), vec![], vec![]).into(),
+            ))
+            .into(),
+            Call::new(Identifier::from("f"), vec![]).into(),
+        ],
+    );
+}
+
+#[test]
+fn top_level_await() {
+    check_module(
+        "await x;",
+        vec![AwaitExpr::from(Node::from(Identifier::from("x"))).into()],
+    );
+    check_invalid_module("function f() { await x; }");
+}
+
+#[test]
+fn invalid_module_items() {
+    check_invalid_module("{ export const x = 1; }");
+    check_invalid_module("function f() { import { y } from 'm'; }");
+    check_invalid_module("export { if };");
+    check_invalid_module("import { if } from 'm';");
+    check_invalid_module("var await = 1;");
+    check_invalid_module("export const x = 1; let x = 2;");
+}
+
+#[test]
+fn import_export_in_script() {
+    check_invalid(r#"import { y } from "m";"#);
+    check_invalid("export const x = 1;");
+}
@@ -12,7 +12,7 @@ mod tests;
 
 use super::LabelIdentifier;
 
-use crate::syntax::lexer::TokenKind;
+use crate::syntax::lexer::{Error as LexError, TokenKind};
 use crate::{
     syntax::{
         ast::{node::Break, Keyword, Punctuator},
@@ -74,7 +74,17 @@ where
 
             None
         } else {
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
             let label = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+            // It is a Syntax Error if the label is not the label of an enclosing statement.
+            // <https://tc39.es/ecma262/#sec-static-semantics-containsundefinedbreaktarget>
+            if cursor.label(&label).is_none() {
+                return Err(ParseError::lex(LexError::Syntax(
+                    format!("undefined break target `{}`", label).into(),
+                    position,
+                )));
+            }
             cursor.expect_semicolon("break statement")?;
 
             Some(label)
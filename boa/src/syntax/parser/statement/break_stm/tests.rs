@@ -3,7 +3,7 @@ use crate::syntax::{
         node::{Block, Break, Node, WhileLoop},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 #[test]
@@ -41,15 +41,17 @@ fn inline_block_semicolon_insertion() {
 
 #[test]
 fn new_line_semicolon_insertion() {
+    let mut while_loop = WhileLoop::new(
+        Const::from(true),
+        Block::from(vec![Break::new("test").into()]),
+    );
+    while_loop.set_label("test".into());
+
     check_parser(
-        "while (true) {
+        "test: while (true) {
             break test
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
-            Block::from(vec![Break::new("test").into()]),
-        )
-        .into()],
+        vec![while_loop.into()],
     );
 }
 
@@ -67,40 +69,46 @@ fn inline_block() {
 
 #[test]
 fn new_line_block() {
+    let mut while_loop = WhileLoop::new(
+        Const::from(true),
+        Block::from(vec![Break::new("test").into()]),
+    );
+    while_loop.set_label("test".into());
+
     check_parser(
-        "while (true) {
+        "test: while (true) {
             break test;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
-            Block::from(vec![Break::new("test").into()]),
-        )
-        .into()],
+        vec![while_loop.into()],
     );
 }
 
 #[test]
 fn reserved_label() {
+    let mut while_loop = WhileLoop::new(
+        Const::from(true),
+        Block::from(vec![Break::new("await").into()]),
+    );
+    while_loop.set_label("await".into());
+
     check_parser(
-        "while (true) {
+        "await: while (true) {
             break await;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
-            Block::from(vec![Break::new("await").into()]),
-        )
-        .into()],
+        vec![while_loop.into()],
     );
 
+    let mut while_loop = WhileLoop::new(
+        Const::from(true),
+        Block::from(vec![Break::new("yield").into()]),
+    );
+    while_loop.set_label("yield".into());
+
     check_parser(
-        "while (true) {
+        "yield: while (true) {
             break yield;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
-            Block::from(vec![Break::new("yield").into()]),
-        )
-        .into()],
+        vec![while_loop.into()],
     );
 }
 
@@ -131,3 +139,22 @@ fn new_line_block_empty_semicolon_insertion() {
         .into()],
     );
 }
+
+#[test]
+fn undefined_label() {
+    check_invalid("while (true) { break test; }");
+    check_invalid("test: while (true) {} while (true) { break test; }");
+}
+
+#[test]
+fn label_not_visible_in_function() {
+    check_invalid("test: while (true) { function f() { break test; } }");
+}
+
+#[test]
+fn block_label() {
+    let mut block = Block::from(vec![Break::new("test").into()]);
+    block.set_label("test".into());
+
+    check_parser("test: { break test; }", vec![block.into()]);
+}
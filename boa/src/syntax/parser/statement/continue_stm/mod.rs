@@ -10,7 +10,7 @@
 #[cfg(test)]
 mod tests;
 
-use crate::syntax::lexer::TokenKind;
+use crate::syntax::lexer::{Error as LexError, TokenKind};
 use crate::{
     syntax::{
         ast::{node::Continue, Keyword, Punctuator},
@@ -73,7 +73,26 @@ where
 
             None
         } else {
+            let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
             let label = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+
+            // It is a Syntax Error if the label is not the label of an enclosing statement.
+            // <https://tc39.es/ecma262/#sec-static-semantics-containsundefinedcontinuetarget>
+            match cursor.label(&label) {
+                None => {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        format!("undefined continue target `{}`", label).into(),
+                        position,
+                    )))
+                }
+                Some(false) => {
+                    return Err(ParseError::lex(LexError::Syntax(
+                        format!("continue target `{}` is not an iteration statement", label).into(),
+                        position,
+                    )))
+                }
+                Some(true) => {}
+            }
             cursor.expect_semicolon("continue statement")?;
 
             Some(label)
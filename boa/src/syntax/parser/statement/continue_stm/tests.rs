@@ -3,7 +3,7 @@ use crate::syntax::{
         node::{Block, Continue, WhileLoop},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 #[test]
@@ -37,15 +37,17 @@ fn inline_block_semicolon_insertion() {
 
 #[test]
 fn new_line_semicolon_insertion() {
+    let mut while_loop = WhileLoop::new(
+        Const::from(true),
+        Block::from(vec![Continue::new("test").into()]),
+    );
+    while_loop.set_label("test".into());
+
     check_parser(
-        "while (true) {
+        "test: while (true) {
             continue test
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
-            Block::from(vec![Continue::new("test").into()]),
-        )
-        .into()],
+        vec![while_loop.into()],
     );
 }
 
@@ -63,40 +65,46 @@ fn inline_block() {
 
 #[test]
 fn new_line_block() {
+    let mut while_loop = WhileLoop::new(
+        Const::from(true),
+        Block::from(vec![Continue::new("test").into()]),
+    );
+    while_loop.set_label("test".into());
+
     check_parser(
-        "while (true) {
+        "test: while (true) {
             continue test;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
-            Block::from(vec![Continue::new("test").into()]),
-        )
-        .into()],
+        vec![while_loop.into()],
     );
 }
 
 #[test]
 fn reserved_label() {
+    let mut while_loop = WhileLoop::new(
+        Const::from(true),
+        Block::from(vec![Continue::new("await").into()]),
+    );
+    while_loop.set_label("await".into());
+
     check_parser(
-        "while (true) {
+        "await: while (true) {
             continue await;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
-            Block::from(vec![Continue::new("await").into()]),
-        )
-        .into()],
+        vec![while_loop.into()],
     );
 
+    let mut while_loop = WhileLoop::new(
+        Const::from(true),
+        Block::from(vec![Continue::new("yield").into()]),
+    );
+    while_loop.set_label("yield".into());
+
     check_parser(
-        "while (true) {
+        "yield: while (true) {
             continue yield;
         }",
-        vec![WhileLoop::new(
-            Const::from(true),
-            Block::from(vec![Continue::new("yield").into()]),
-        )
-        .into()],
+        vec![while_loop.into()],
     );
 }
 
@@ -127,3 +135,30 @@ fn new_line_block_empty_semicolon_insertion() {
         .into()],
     );
 }
+
+#[test]
+fn undefined_label() {
+    check_invalid("while (true) { continue test; }");
+    check_invalid("test: while (true) {} while (true) { continue test; }");
+}
+
+#[test]
+fn label_not_visible_in_function() {
+    check_invalid("test: while (true) { function f() { while (true) { continue test; } } }");
+}
+
+#[test]
+fn non_iteration_label() {
+    check_invalid("test: { while (true) { continue test; } }");
+}
+
+#[test]
+fn nested_labels() {
+    let mut while_loop = WhileLoop::new(Const::from(true), Continue::new("outer"));
+    while_loop.set_label("outer".into());
+
+    check_parser(
+        "outer: inner: while (true) continue outer;",
+        vec![while_loop.into()],
+    );
+}
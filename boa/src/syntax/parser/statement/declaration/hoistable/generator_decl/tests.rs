@@ -0,0 +1,53 @@
+use crate::syntax::{
+    ast::{
+        node::{Call, GeneratorDecl, Identifier, Node, Yield},
+        Const,
+    },
+    parser::tests::{check_invalid, check_parser},
+};
+
+/// Generator declaration parsing.
+#[test]
+fn generator_declaration() {
+    check_parser(
+        "function* gen() {}",
+        vec![GeneratorDecl::new(Box::from("gen"), vec![], vec![]).into()],
+    );
+}
+
+/// Generator declaration parsing with `yield` and `yield*` expressions.
+#[test]
+fn generator_declaration_yield() {
+    check_parser(
+        "function* gen() { yield 1; yield; yield* other(); }",
+        vec![GeneratorDecl::new(
+            Box::from("gen"),
+            vec![],
+            vec![
+                Yield::new(Const::from(1), false).into(),
+                Yield::new::<Node, Option<Node>>(None, false).into(),
+                Yield::new(Call::new(Identifier::from("other"), vec![]), true).into(),
+            ],
+        )
+        .into()],
+    );
+}
+
+/// `yield` can't be used as an identifier in a generator body.
+#[test]
+fn generator_declaration_yield_identifier() {
+    check_invalid("function* gen() { var yield = 1; }");
+}
+
+/// Nested functions don't inherit the ability to yield.
+#[test]
+fn yield_in_nested_function() {
+    check_invalid("function* gen() { function inner() { yield 1; } }");
+}
+
+/// `yield` expressions are only allowed in generator bodies.
+#[test]
+fn yield_outside_generator() {
+    check_invalid("yield 1;");
+    check_invalid("function gen() { yield 1; }");
+}
@@ -10,13 +10,15 @@ mod tests;
 
 mod async_function_decl;
 mod function_decl;
+mod generator_decl;
 
 use async_function_decl::AsyncFunctionDeclaration;
 use function_decl::FunctionDeclaration;
+use generator_decl::GeneratorDeclaration;
 
 use crate::{
     syntax::{
-        ast::{Keyword, Node},
+        ast::{Keyword, Node, Punctuator},
         lexer::TokenKind,
         parser::{
             AllowAwait, AllowDefault, AllowYield, Cursor, ParseError, ParseResult, TokenParser,
@@ -67,6 +69,20 @@ where
 
         match tok.kind() {
             TokenKind::Keyword(Keyword::Function) => {
+                let is_generator = cursor
+                    .peek(1)?
+                    .map(|token| token.kind() == &TokenKind::Punctuator(Punctuator::Mul))
+                    .unwrap_or(false);
+                if is_generator {
+                    return GeneratorDeclaration::new(
+                        self.allow_yield,
+                        self.allow_await,
+                        self.is_default,
+                    )
+                    .parse(cursor)
+                    .map(Node::from);
+                }
+
                 FunctionDeclaration::new(self.allow_yield, self.allow_await, self.is_default)
                     .parse(cursor)
                     .map(Node::from)
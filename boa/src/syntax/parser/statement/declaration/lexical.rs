@@ -20,7 +20,7 @@ use crate::{
         parser::{
             cursor::{Cursor, SemicolonResult},
             expression::Initializer,
-            statement::BindingIdentifier,
+            statement::{ArrayBindingPattern, BindingIdentifier, ObjectBindingPattern},
             AllowAwait, AllowIn, AllowYield, ParseError, ParseResult, TokenParser,
         },
     },
@@ -154,14 +154,13 @@ where
         let mut const_decls = Vec::new();
 
         loop {
-            let (ident, init) =
-                LexicalBinding::new(self.allow_in, self.allow_yield, self.allow_await)
-                    .parse(cursor)?;
+            let decl = LexicalBinding::new(self.allow_in, self.allow_yield, self.allow_await)
+                .parse(cursor)?;
 
             if self.is_const {
                 if self.const_init_required {
-                    if init.is_some() {
-                        const_decls.push(Declaration::new(ident, init));
+                    if decl.init().is_some() {
+                        const_decls.push(decl);
                     } else {
                         return Err(ParseError::expected(
                             vec![TokenKind::Punctuator(Punctuator::Assign)],
@@ -170,10 +169,22 @@ where
                         ));
                     }
                 } else {
-                    const_decls.push(Declaration::new(ident, init))
+                    const_decls.push(decl)
                 }
             } else {
-                let_decls.push(Declaration::new(ident, init));
+                // Destructuring patterns always need an initializer, unless they are in the head
+                // of a `for` loop.
+                if self.const_init_required
+                    && decl.init().is_none()
+                    && matches!(decl, Declaration::Pattern(_))
+                {
+                    return Err(ParseError::expected(
+                        vec![TokenKind::Punctuator(Punctuator::Assign)],
+                        cursor.next()?.ok_or(ParseError::AbruptEnd)?,
+                        "let declaration",
+                    ));
+                }
+                let_decls.push(decl);
             }
 
             match cursor.peek_semicolon()? {
@@ -239,30 +250,55 @@ impl LexicalBinding {
     }
 }
 
+impl LexicalBinding {
+    /// Parses the initializer of the binding, if there is one.
+    fn parse_initializer<R>(&self, cursor: &mut Cursor<R>) -> Result<Option<Node>, ParseError>
+    where
+        R: Read,
+    {
+        if let Some(t) = cursor.peek(0)? {
+            if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
+                return Ok(Some(
+                    Initializer::new(self.allow_in, self.allow_yield, self.allow_await)
+                        .parse(cursor)?,
+                ));
+            }
+        }
+        Ok(None)
+    }
+}
+
 impl<R> TokenParser<R> for LexicalBinding
 where
     R: Read,
 {
-    type Output = (Box<str>, Option<Node>);
+    type Output = Declaration;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("LexicalBinding", "Parsing");
 
-        let ident = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let peek_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        match peek_token.kind() {
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                let bindings =
+                    ObjectBindingPattern::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let init = self.parse_initializer(cursor)?;
 
-        let init = if let Some(t) = cursor.peek(0)? {
-            if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
-                Some(
-                    Initializer::new(self.allow_in, self.allow_yield, self.allow_await)
-                        .parse(cursor)?,
-                )
-            } else {
-                None
+                return Ok(Declaration::new_with_object_pattern(bindings, init));
             }
-        } else {
-            None
-        };
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                let bindings =
+                    ArrayBindingPattern::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let init = self.parse_initializer(cursor)?;
+
+                return Ok(Declaration::new_with_array_pattern(bindings, init));
+            }
+            _ => {}
+        }
+
+        let ident = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let init = self.parse_initializer(cursor)?;
 
-        Ok((ident, init))
+        Ok(Declaration::new(ident, init))
     }
 }
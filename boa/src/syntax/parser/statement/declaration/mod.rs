@@ -32,14 +32,18 @@ use std::io::Read;
 ///
 /// [spec]: https://tc39.es/ecma262/#prod-Declaration
 #[derive(Debug, Clone, Copy)]
-pub(super) struct Declaration {
+pub(in crate::syntax::parser) struct Declaration {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     const_init_required: bool,
 }
 
 impl Declaration {
-    pub(super) fn new<Y, A>(allow_yield: Y, allow_await: A, const_init_required: bool) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(
+        allow_yield: Y,
+        allow_await: A,
+        const_init_required: bool,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -1,9 +1,16 @@
 use crate::syntax::{
     ast::{
-        node::{Declaration, DeclarationList, Node},
+        node::{
+            BindingPatternTypeArray, BindingPatternTypeObject, Declaration, DeclarationList,
+            DeclarationPattern, DeclarationPatternArray, DeclarationPatternObject, Identifier,
+            Node,
+        },
         Const,
     },
-    parser::tests::{check_invalid, check_parser},
+    parser::{
+        tests::{check_invalid, check_parser},
+        Parser,
+    },
 };
 
 /// Checks `var` declaration parsing.
@@ -211,3 +218,190 @@ fn multiple_const_declaration() {
         .into()],
     );
 }
+
+/// Checks object destructuring declaration parsing.
+#[test]
+fn object_pattern_declaration() {
+    check_parser(
+        "const { a, b: c, d = 1 } = obj;",
+        vec![DeclarationList::Const(
+            vec![Declaration::new_with_object_pattern(
+                vec![
+                    BindingPatternTypeObject::SingleName {
+                        ident: "a".into(),
+                        property_name: "a".into(),
+                        default_init: None,
+                    },
+                    BindingPatternTypeObject::SingleName {
+                        ident: "c".into(),
+                        property_name: "b".into(),
+                        default_init: None,
+                    },
+                    BindingPatternTypeObject::SingleName {
+                        ident: "d".into(),
+                        property_name: "d".into(),
+                        default_init: Some(Const::from(1).into()),
+                    },
+                ],
+                Some(Identifier::from("obj").into()),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks nested object destructuring declaration parsing.
+#[test]
+fn nested_object_pattern_declaration() {
+    check_parser(
+        "let { a: { b }, ...rest } = obj;",
+        vec![DeclarationList::Let(
+            vec![Declaration::new_with_object_pattern(
+                vec![
+                    BindingPatternTypeObject::BindingPattern {
+                        property_name: "a".into(),
+                        pattern: DeclarationPattern::Object(DeclarationPatternObject::new(
+                            vec![BindingPatternTypeObject::SingleName {
+                                ident: "b".into(),
+                                property_name: "b".into(),
+                                default_init: None,
+                            }],
+                            None,
+                        )),
+                        default_init: None,
+                    },
+                    BindingPatternTypeObject::RestProperty {
+                        ident: "rest".into(),
+                        excluded_keys: vec!["a".into()],
+                    },
+                ],
+                Some(Identifier::from("obj").into()),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks that destructuring declarations require an initializer.
+#[test]
+fn object_pattern_declaration_without_initializer() {
+    check_invalid("var { a };");
+    check_invalid("let { a };");
+    check_invalid("const { a };");
+}
+
+/// Checks that the rest element must be the last element of an object pattern.
+#[test]
+fn object_pattern_rest_not_last() {
+    check_invalid("let { ...a, b } = obj;");
+}
+
+/// Checks that an object pattern can't redeclare a lexical binding.
+#[test]
+fn object_pattern_redeclaration() {
+    check_invalid("let a = 1; let { a } = obj;");
+    check_invalid("let { a, b: a } = obj;");
+}
+
+/// Checks array destructuring declaration parsing.
+#[test]
+fn array_pattern_declaration() {
+    check_parser(
+        "const [a, , b = 1, ...rest] = arr;",
+        vec![DeclarationList::Const(
+            vec![Declaration::new_with_array_pattern(
+                vec![
+                    BindingPatternTypeArray::SingleName {
+                        ident: "a".into(),
+                        default_init: None,
+                    },
+                    BindingPatternTypeArray::Elision,
+                    BindingPatternTypeArray::SingleName {
+                        ident: "b".into(),
+                        default_init: Some(Const::from(1).into()),
+                    },
+                    BindingPatternTypeArray::SingleNameRest {
+                        ident: "rest".into(),
+                    },
+                ],
+                Some(Identifier::from("arr").into()),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks nested array destructuring declaration parsing.
+#[test]
+fn nested_array_pattern_declaration() {
+    check_parser(
+        "var [[a], { b }] = arr;",
+        vec![DeclarationList::Var(
+            vec![Declaration::new_with_array_pattern(
+                vec![
+                    BindingPatternTypeArray::BindingPattern {
+                        pattern: DeclarationPattern::Array(DeclarationPatternArray::new(
+                            vec![BindingPatternTypeArray::SingleName {
+                                ident: "a".into(),
+                                default_init: None,
+                            }],
+                            None,
+                        )),
+                        default_init: None,
+                    },
+                    BindingPatternTypeArray::BindingPattern {
+                        pattern: DeclarationPattern::Object(DeclarationPatternObject::new(
+                            vec![BindingPatternTypeObject::SingleName {
+                                ident: "b".into(),
+                                property_name: "b".into(),
+                                default_init: None,
+                            }],
+                            None,
+                        )),
+                        default_init: None,
+                    },
+                ],
+                Some(Identifier::from("arr").into()),
+            )]
+            .into(),
+        )
+        .into()],
+    );
+}
+
+/// Checks that the rest element must be the last element of an array pattern.
+#[test]
+fn array_pattern_rest_not_last() {
+    check_invalid("let [...a, b] = arr;");
+    check_invalid("let [...a,] = arr;");
+}
+
+/// Checks that an array pattern can't redeclare a lexical binding.
+#[test]
+fn array_pattern_redeclaration() {
+    check_invalid("let a = 1; let [a] = arr;");
+    check_invalid("const [a, [a]] = arr;");
+}
+
+/// Checks that redeclaration errors point to both the original and the conflicting declaration.
+#[test]
+fn redeclaration_error_positions() {
+    let error = Parser::new("let a = 1;\n  const a = 2;".as_bytes(), false)
+        .parse_all()
+        .expect_err("redeclaration should be an error");
+    assert_eq!(
+        error.to_string(),
+        "Syntax Error: Redeclaration of variable `a` (first declared at line 1, col 1) at position: 2:3"
+    );
+
+    let error = Parser::new("var b;\nlet c, b;".as_bytes(), false)
+        .parse_all()
+        .expect_err("redeclaration should be an error");
+    assert_eq!(
+        error.to_string(),
+        "Syntax Error: Redeclaration of variable `b` (first declared at line 1, col 1) at position: 2:1"
+    );
+}
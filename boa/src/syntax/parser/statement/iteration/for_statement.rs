@@ -99,6 +99,13 @@ where
                 return Ok(ForInLoop::new(init.unwrap(), expr, body).into());
             }
             Some(tok) if tok.kind() == &TokenKind::Keyword(Keyword::Of) && init.is_some() => {
+                let position = tok.span().start();
+                if init.as_ref().map_or(false, has_initializer) {
+                    return Err(ParseError::general(
+                        "a declaration in the head of a for-of loop can't have an initializer",
+                        position,
+                    ));
+                }
                 let _ = cursor.next();
                 let iterable =
                     Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
@@ -112,8 +119,8 @@ where
 
         cursor.expect(Punctuator::Semicolon, "for statement")?;
 
-        let cond = if cursor.next_if(Punctuator::Semicolon)?.is_some() {
-            Const::from(true).into()
+        let cond = if let Some(semicolon) = cursor.next_if(Punctuator::Semicolon)? {
+            cursor.record_expression(Const::from(true), semicolon.span().start())
         } else {
             let step = Expression::new(true, self.allow_yield, self.allow_await).parse(cursor)?;
             cursor.expect(Punctuator::Semicolon, "for statement")?;
@@ -138,3 +145,19 @@ where
         Ok(ForLoop::new(init, cond, step, body).into())
     }
 }
+
+/// Checks if the head of a `for...of` loop contains an initializer.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#sec-for-in-and-for-of-statements
+fn has_initializer(init: &Node) -> bool {
+    match init {
+        Node::VarDeclList(list) | Node::LetDeclList(list) | Node::ConstDeclList(list) => {
+            list.as_ref().iter().any(|decl| decl.init().is_some())
+        }
+        Node::Assign(_) => true,
+        _ => false,
+    }
+}
@@ -7,7 +7,7 @@ use crate::syntax::{
         op::{self, AssignOp, CompOp},
         Const,
     },
-    parser::tests::check_parser,
+    parser::tests::{check_invalid, check_parser},
 };
 
 /// Checks do-while statement parsing.
@@ -114,6 +114,30 @@ fn while_spaces() {
     );
 }
 
+/// Checks that a label is attached to the while statement it precedes.
+#[test]
+fn while_label() {
+    let mut while_loop = WhileLoop::new(Const::from(true), Break::new("outer"));
+    while_loop.set_label("outer".into());
+
+    check_parser("outer: while (true) break outer;", vec![while_loop.into()]);
+}
+
+/// Checks that a label is attached to the do-while statement it precedes.
+#[test]
+fn do_while_label() {
+    let mut do_while_loop = DoWhileLoop::new(
+        Block::from(vec![Break::new("outer").into()]),
+        Const::from(true),
+    );
+    do_while_loop.set_label("outer".into());
+
+    check_parser(
+        "outer: do { break outer; } while (true)",
+        vec![do_while_loop.into()],
+    );
+}
+
 /// Checks parsing of a while statement which is seperated out with line terminators.
 #[test]
 fn do_while_spaces() {
@@ -138,3 +162,25 @@ fn do_while_spaces() {
         .into()],
     );
 }
+
+/// Checks that an initializer in the head of a `for...of` loop is a syntax error.
+#[test]
+fn for_of_initializer() {
+    check_invalid("for (var x = 1 of [1, 2]) {}");
+    check_invalid("for (let x = 1 of [1, 2]) {}");
+    check_invalid("for (const x = 1 of [1, 2]) {}");
+}
+
+/// Checks that the `for...of` initializer error mentions the loop kind.
+#[test]
+fn for_of_initializer_message() {
+    let error = crate::syntax::Parser::new("for (let x = 1 of [1, 2]) {}".as_bytes(), false)
+        .parse_all()
+        .expect_err("parsing should fail")
+        .to_string();
+
+    assert_eq!(
+        error,
+        "a declaration in the head of a for-of loop can't have an initializer at line 1, col 16"
+    );
+}
@@ -4,7 +4,8 @@ use super::{LabelIdentifier, Statement};
 use crate::{
     syntax::ast::Node,
     syntax::{
-        ast::Punctuator,
+        ast::{Keyword, Punctuator},
+        lexer::{Error as LexError, Token, TokenKind},
         parser::{
             cursor::Cursor, error::ParseError, AllowAwait, AllowReturn, AllowYield, TokenParser,
         },
@@ -49,16 +50,59 @@ where
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("Label", "Parsing");
+        let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
         let name = LabelIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
         cursor.expect(Punctuator::Colon, "Labelled Statement")?;
-        let mut stmt =
-            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
 
+        // It is a Syntax Error if any source text is matched by this production, and the label is
+        // already the label of an enclosing statement.
+        // <https://tc39.es/ecma262/#sec-labelled-statements-static-semantics-early-errors>
+        if cursor.label(&name).is_some() {
+            return Err(ParseError::lex(LexError::Syntax(
+                format!("duplicate label `{}`", name).into(),
+                position,
+            )));
+        }
+
+        let iteration = labels_iteration_statement(cursor)?;
+        cursor.push_label(name.clone(), iteration);
+        let stmt =
+            Statement::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor);
+        cursor.pop_label();
+
+        let mut stmt = stmt?;
         set_label_for_node(&mut stmt, name);
         Ok(stmt)
     }
 }
 
+/// Checks if the statement following the label of a labelled statement is an iteration
+/// statement.
+///
+/// Returns `None` if it is another labelled statement.
+fn labels_iteration_statement<R>(cursor: &mut Cursor<R>) -> Result<Option<bool>, ParseError>
+where
+    R: Read,
+{
+    let kind = cursor.peek(0)?.map(Token::kind);
+    match kind {
+        Some(TokenKind::Keyword(Keyword::For))
+        | Some(TokenKind::Keyword(Keyword::While))
+        | Some(TokenKind::Keyword(Keyword::Do)) => Ok(Some(true)),
+        Some(TokenKind::Identifier(_))
+        | Some(TokenKind::Keyword(Keyword::Await))
+        | Some(TokenKind::Keyword(Keyword::Yield)) => {
+            let next = cursor.peek(1)?.map(Token::kind);
+            if next == Some(&TokenKind::Punctuator(Punctuator::Colon)) {
+                Ok(None)
+            } else {
+                Ok(Some(false))
+            }
+        }
+        _ => Ok(Some(false)),
+    }
+}
+
 fn set_label_for_node(stmt: &mut Node, name: Box<str>) {
     match stmt {
         Node::ForLoop(ref mut for_loop) => for_loop.set_label(name),
@@ -66,6 +110,8 @@ fn set_label_for_node(stmt: &mut Node, name: Box<str>) {
         Node::ForInLoop(ref mut for_in_loop) => for_in_loop.set_label(name),
         Node::DoWhileLoop(ref mut do_while_loop) => do_while_loop.set_label(name),
         Node::WhileLoop(ref mut while_loop) => while_loop.set_label(name),
+        Node::Block(ref mut block) => block.set_label(name),
+        Node::Switch(ref mut switch) => switch.set_label(name),
         _ => (),
     }
 }
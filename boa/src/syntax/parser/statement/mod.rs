@@ -25,7 +25,6 @@ use self::{
     block::BlockStatement,
     break_stm::BreakStatement,
     continue_stm::ContinueStatement,
-    declaration::Declaration,
     expression::ExpressionStatement,
     if_stm::IfStatement,
     iteration::{DoWhileStatement, ForStatement, WhileStatement},
@@ -33,22 +32,22 @@ use self::{
     switch::SwitchStatement,
     throw::ThrowStatement,
     try_stm::TryStatement,
-    variable::VariableStatement,
 };
+pub(super) use self::{declaration::Declaration, variable::VariableStatement};
 
 use super::{AllowAwait, AllowReturn, AllowYield, Cursor, ParseError, TokenParser};
 
 use crate::{
     syntax::{
-        ast::{node, Keyword, Node, Punctuator},
-        lexer::{Error as LexError, InputElement, Position, TokenKind},
-        parser::expression::await_expr::AwaitExpression,
+        ast::{node, Keyword, Node, Punctuator, Span},
+        lexer::{Error as LexError, InputElement, Position, Token, TokenKind},
+        parser::expression::{await_expr::AwaitExpression, Initializer},
     },
     BoaProfiler,
 };
 use labelled_stm::LabelledStatement;
 
-use std::collections::HashSet;
+use std::collections::HashMap;
 use std::io::Read;
 
 /// Statement parsing.
@@ -98,6 +97,19 @@ impl Statement {
             allow_return: allow_return.into(),
         }
     }
+
+    /// Checks if the identifier the cursor is at is the label of a labelled statement.
+    fn is_labelled_statement<R>(cursor: &mut Cursor<R>) -> Result<bool, ParseError>
+    where
+        R: Read,
+    {
+        cursor.set_goal(InputElement::Div);
+        let tok = cursor.peek(1)?;
+        Ok(matches!(
+            tok.map(Token::kind),
+            Some(TokenKind::Punctuator(Punctuator::Colon))
+        ))
+    }
 }
 
 impl<R> TokenParser<R> for Statement
@@ -112,9 +124,40 @@ where
         let tok = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
 
         match tok.kind() {
-            TokenKind::Keyword(Keyword::Await) => AwaitExpression::new(self.allow_yield)
-                .parse(cursor)
-                .map(Node::from),
+            TokenKind::Keyword(Keyword::Await) => {
+                if Self::is_labelled_statement(cursor)? {
+                    return LabelledStatement::new(
+                        self.allow_yield,
+                        self.allow_await,
+                        self.allow_return,
+                    )
+                    .parse(cursor)
+                    .map(Node::from);
+                }
+
+                if cursor.module() && !self.allow_await.0 {
+                    let position = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?.span().start();
+                    return Err(ParseError::general(
+                        "await is only valid in async functions and the top level of modules",
+                        position,
+                    ));
+                }
+
+                AwaitExpression::new(self.allow_yield).parse(cursor)
+            }
+            TokenKind::Keyword(Keyword::Yield) => {
+                if Self::is_labelled_statement(cursor)? {
+                    return LabelledStatement::new(
+                        self.allow_yield,
+                        self.allow_await,
+                        self.allow_return,
+                    )
+                    .parse(cursor)
+                    .map(Node::from);
+                }
+
+                ExpressionStatement::new(self.allow_yield, self.allow_await).parse(cursor)
+            }
             TokenKind::Keyword(Keyword::If) => {
                 IfStatement::new(self.allow_yield, self.allow_await, self.allow_return)
                     .parse(cursor)
@@ -185,15 +228,7 @@ where
                 Ok(Node::Empty)
             }
             TokenKind::Identifier(_) => {
-                // Labelled Statement check
-                cursor.set_goal(InputElement::Div);
-                let tok = cursor.peek(1)?;
-                if tok.is_some()
-                    && matches!(
-                        tok.unwrap().kind(),
-                        TokenKind::Punctuator(Punctuator::Colon)
-                    )
-                {
+                if Self::is_labelled_statement(cursor)? {
                     return LabelledStatement::new(
                         self.allow_yield,
                         self.allow_await,
@@ -269,14 +304,17 @@ where
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         let _timer = BoaProfiler::global().start_event("StatementList", "Parsing");
         let mut items = Vec::new();
+        let mut spans = Vec::new();
+        let mut expression_spans = Vec::new();
 
         loop {
-            match cursor.peek(0)? {
+            let start = match cursor.peek(0)? {
                 Some(token) if self.break_nodes.contains(token.kind()) => break,
+                Some(token) => token.span().start(),
                 None => break,
-                _ => {}
-            }
+            };
 
+            let mark = cursor.expression_mark();
             let item = StatementListItem::new(
                 self.allow_yield,
                 self.allow_await,
@@ -284,65 +322,175 @@ where
                 self.in_block,
             )
             .parse(cursor)?;
+            expression_spans.push(cursor.register_expressions(mark, &item));
             items.push(item);
+            spans.push(Span::new(start, cursor.last_token_end()));
 
             // move the cursor forward for any consecutive semicolon.
             while cursor.next_if(Punctuator::Semicolon)?.is_some() {}
         }
 
+        Self::check_redeclarations(&items, &spans)?;
+
+        Ok(Self::hoist(cursor, items, spans, expression_spans))
+    }
+}
+
+impl StatementList {
+    /// Parses the statement list like `parse()` does, but instead of stopping at the first error,
+    /// it records it in `errors` and resumes parsing at the next statement.
+    ///
+    /// Statements that contain errors are left out of the resulting list.
+    pub(super) fn parse_recoverable<R>(
+        self,
+        cursor: &mut Cursor<R>,
+        errors: &mut Vec<ParseError>,
+    ) -> node::StatementList
+    where
+        R: Read,
+    {
+        let _timer = BoaProfiler::global().start_event("StatementList", "Parsing");
+        let mut items = Vec::new();
+        let mut spans = Vec::new();
+        let mut expression_spans = Vec::new();
+
+        loop {
+            let position = match cursor.peek(0) {
+                Ok(Some(token)) if self.break_nodes.contains(token.kind()) => break,
+                Ok(Some(token)) => token.span().start(),
+                Ok(None) => break,
+                Err(e) => {
+                    errors.push(e);
+                    cursor.synchronize();
+                    continue;
+                }
+            };
+
+            let mark = cursor.expression_mark();
+            let item = StatementListItem::new(
+                self.allow_yield,
+                self.allow_await,
+                self.allow_return,
+                self.in_block,
+            )
+            .parse(cursor);
+
+            match item {
+                Ok(item) => {
+                    expression_spans.push(cursor.register_expressions(mark, &item));
+                    items.push(item);
+                    spans.push(Span::new(position, cursor.last_token_end()));
+                }
+                Err(e) => {
+                    errors.push(e);
+                    cursor.truncate_expressions(mark);
+                    cursor.synchronize();
+                }
+            }
+
+            // move the cursor forward for any consecutive semicolon.
+            while let Ok(Some(_)) = cursor.next_if(Punctuator::Semicolon) {}
+        }
+
+        if let Err(e) = Self::check_redeclarations(&items, &spans) {
+            errors.push(e);
+        }
+
+        Self::hoist(cursor, items, spans, expression_spans)
+    }
+
+    /// Creates the statement list of the parsed items, moving hoistable declarations first.
+    ///
+    /// `expression_spans` holds the span of each item that is an expression, which is keyed by
+    /// the item once it is in the list.
+    pub(super) fn hoist<R>(
+        cursor: &mut Cursor<R>,
+        items: Vec<Node>,
+        spans: Vec<Span>,
+        expression_spans: Vec<Option<Span>>,
+    ) -> node::StatementList
+    where
+        R: Read,
+    {
+        let mut items: Vec<_> = items
+            .into_iter()
+            .zip(spans.into_iter().zip(expression_spans))
+            .collect();
+        items.sort_by(|(a, _), (b, _)| Node::hoistable_order(a, b));
+        let (items, spans): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+        let (spans, expression_spans): (Vec<_>, Vec<_>) = spans.into_iter().unzip();
+
+        let list = node::StatementList::with_spans(items, spans);
+        for (item, span) in list.items().iter().zip(expression_spans) {
+            if let Some(span) = span {
+                cursor.register_expression(item, span);
+            }
+        }
+        list
+    }
+
+    /// Checks that no name is declared twice in the items of the list.
+    ///
+    /// `spans` holds the span of each item, so that errors can point to both the original
+    /// declaration and the conflicting one.
+    pub(super) fn check_redeclarations(items: &[Node], spans: &[Span]) -> Result<(), ParseError> {
         // Handle any redeclarations
         // https://tc39.es/ecma262/#sec-block-static-semantics-early-errors
-        {
-            let mut lexically_declared_names: HashSet<&str> = HashSet::new();
-            let mut var_declared_names: HashSet<&str> = HashSet::new();
-
-            // TODO: Use more helpful positions in errors when spans are added to Nodes
-            for item in &items {
-                match item {
-                    Node::LetDeclList(decl_list) | Node::ConstDeclList(decl_list) => {
-                        for decl in decl_list.as_ref() {
-                            // if name in VarDeclaredNames or can't be added to
-                            // LexicallyDeclaredNames, raise an error
-                            if var_declared_names.contains(decl.name())
-                                || !lexically_declared_names.insert(decl.name())
-                            {
-                                return Err(ParseError::lex(LexError::Syntax(
-                                    format!("Redeclaration of variable `{}`", decl.name()).into(),
-                                    match cursor.peek(0)? {
-                                        Some(token) => token.span().end(),
-                                        None => Position::new(1, 1),
-                                    },
-                                )));
-                            }
+        let mut lexically_declared_names: HashMap<&str, Position> = HashMap::new();
+        let mut var_declared_names: HashMap<&str, Position> = HashMap::new();
+
+        // TODO: Use the positions of the names themselves when spans are added to Nodes
+        for (item, span) in items.iter().zip(spans) {
+            let position = span.start();
+            let item = match item {
+                Node::ExportDecl(node::ExportDecl::Declaration(decl)) => decl,
+                item => item,
+            };
+            match item {
+                Node::LetDeclList(decl_list) | Node::ConstDeclList(decl_list) => {
+                    for name in decl_list.as_ref().iter().flat_map(|decl| decl.idents()) {
+                        // if name in VarDeclaredNames or LexicallyDeclaredNames, raise an error
+                        if let Some(&first) = var_declared_names
+                            .get(name)
+                            .or_else(|| lexically_declared_names.get(name))
+                        {
+                            return Err(redeclaration_error(name, first, position));
                         }
+                        lexically_declared_names.insert(name, position);
                     }
-                    Node::VarDeclList(decl_list) => {
-                        for decl in decl_list.as_ref() {
-                            // if name in LexicallyDeclaredNames, raise an error
-                            if lexically_declared_names.contains(decl.name()) {
-                                return Err(ParseError::lex(LexError::Syntax(
-                                    format!("Redeclaration of variable `{}`", decl.name()).into(),
-                                    match cursor.peek(0)? {
-                                        Some(token) => token.span().end(),
-                                        None => Position::new(1, 1),
-                                    },
-                                )));
-                            }
-                            // otherwise, add to VarDeclaredNames
-                            var_declared_names.insert(decl.name());
+                }
+                Node::VarDeclList(decl_list) => {
+                    for name in decl_list.as_ref().iter().flat_map(|decl| decl.idents()) {
+                        // if name in LexicallyDeclaredNames, raise an error
+                        if let Some(&first) = lexically_declared_names.get(name) {
+                            return Err(redeclaration_error(name, first, position));
                         }
+                        // otherwise, add to VarDeclaredNames
+                        var_declared_names.entry(name).or_insert(position);
                     }
-                    _ => (),
                 }
+                _ => (),
             }
         }
 
-        items.sort_by(Node::hoistable_order);
-
-        Ok(items.into())
+        Ok(())
     }
 }
 
+/// Creates the error for a variable declared at `position` that was already declared at `first`.
+fn redeclaration_error(name: &str, first: Position, position: Position) -> ParseError {
+    ParseError::lex(LexError::Syntax(
+        format!(
+            "Redeclaration of variable `{}` (first declared at line {}, col {})",
+            name,
+            first.line_number(),
+            first.column_number()
+        )
+        .into(),
+        position,
+    ))
+}
+
 /// Statement list item parsing
 ///
 /// A statement list item can either be an statement or a declaration.
@@ -354,7 +502,7 @@ where
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements
 /// [spec]: https://tc39.es/ecma262/#prod-StatementListItem
 #[derive(Debug, Clone, Copy)]
-struct StatementListItem {
+pub(super) struct StatementListItem {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
     allow_return: AllowReturn,
@@ -363,7 +511,12 @@ struct StatementListItem {
 
 impl StatementListItem {
     /// Creates a new `StatementListItem` parser.
-    fn new<Y, A, R>(allow_yield: Y, allow_await: A, allow_return: R, in_block: bool) -> Self
+    pub(super) fn new<Y, A, R>(
+        allow_yield: Y,
+        allow_await: A,
+        allow_return: R,
+        in_block: bool,
+    ) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -487,3 +640,272 @@ where
         }
     }
 }
+
+/// Object binding pattern parsing.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ObjectBindingPattern
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct ObjectBindingPattern {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ObjectBindingPattern {
+    /// Creates a new `ObjectBindingPattern` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ObjectBindingPattern
+where
+    R: Read,
+{
+    type Output = Vec<node::BindingPatternTypeObject>;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ObjectBindingPattern", "Parsing");
+
+        cursor.expect(Punctuator::OpenBlock, "object binding pattern")?;
+
+        let mut patterns = Vec::new();
+        let mut property_names = Vec::new();
+
+        loop {
+            if cursor.next_if(Punctuator::CloseBlock)?.is_some() {
+                break;
+            }
+
+            if cursor.next_if(Punctuator::Spread)?.is_some() {
+                let ident =
+                    BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                patterns.push(node::BindingPatternTypeObject::RestProperty {
+                    ident,
+                    excluded_keys: property_names,
+                });
+                cursor.expect(Punctuator::CloseBlock, "object binding pattern")?;
+                break;
+            }
+
+            let is_property_name = match cursor.peek(1)? {
+                Some(tok) => tok.kind() == &TokenKind::Punctuator(Punctuator::Colon),
+                None => false,
+            };
+
+            if is_property_name {
+                let token = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                let property_name: Box<str> = match token.kind() {
+                    TokenKind::Identifier(name) => name.clone(),
+                    TokenKind::Keyword(keyword) => keyword.as_str().into(),
+                    TokenKind::StringLiteral(string) => string.clone(),
+                    TokenKind::NumericLiteral(_) => token.to_string().into(),
+                    _ => {
+                        return Err(ParseError::expected(
+                            vec![TokenKind::identifier("identifier")],
+                            token,
+                            "object binding pattern",
+                        ))
+                    }
+                };
+                cursor.expect(Punctuator::Colon, "object binding pattern")?;
+
+                if let Some(pattern) =
+                    parse_nested_binding_pattern(cursor, self.allow_yield, self.allow_await)?
+                {
+                    let default_init =
+                        parse_binding_initializer(cursor, self.allow_yield, self.allow_await)?;
+                    patterns.push(node::BindingPatternTypeObject::BindingPattern {
+                        property_name: property_name.clone(),
+                        pattern,
+                        default_init,
+                    });
+                } else {
+                    let ident =
+                        BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                    let default_init =
+                        parse_binding_initializer(cursor, self.allow_yield, self.allow_await)?;
+                    patterns.push(node::BindingPatternTypeObject::SingleName {
+                        ident,
+                        property_name: property_name.clone(),
+                        default_init,
+                    });
+                }
+                property_names.push(property_name);
+            } else {
+                let ident =
+                    BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let default_init =
+                    parse_binding_initializer(cursor, self.allow_yield, self.allow_await)?;
+                patterns.push(node::BindingPatternTypeObject::SingleName {
+                    ident: ident.clone(),
+                    property_name: ident.clone(),
+                    default_init,
+                });
+                property_names.push(ident);
+            }
+
+            if cursor.next_if(Punctuator::Comma)?.is_none() {
+                cursor.expect(Punctuator::CloseBlock, "object binding pattern")?;
+                break;
+            }
+        }
+
+        Ok(patterns)
+    }
+}
+
+/// Array binding pattern parsing.
+///
+/// More information:
+///  - [ECMAScript specification][spec]
+///
+/// [spec]: https://tc39.es/ecma262/#prod-ArrayBindingPattern
+#[derive(Debug, Clone, Copy)]
+pub(in crate::syntax::parser) struct ArrayBindingPattern {
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+}
+
+impl ArrayBindingPattern {
+    /// Creates a new `ArrayBindingPattern` parser.
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    where
+        Y: Into<AllowYield>,
+        A: Into<AllowAwait>,
+    {
+        Self {
+            allow_yield: allow_yield.into(),
+            allow_await: allow_await.into(),
+        }
+    }
+}
+
+impl<R> TokenParser<R> for ArrayBindingPattern
+where
+    R: Read,
+{
+    type Output = Vec<node::BindingPatternTypeArray>;
+
+    fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
+        let _timer = BoaProfiler::global().start_event("ArrayBindingPattern", "Parsing");
+
+        cursor.expect(Punctuator::OpenBracket, "array binding pattern")?;
+
+        let mut patterns = Vec::new();
+
+        loop {
+            if cursor.next_if(Punctuator::CloseBracket)?.is_some() {
+                break;
+            }
+
+            if cursor.next_if(Punctuator::Comma)?.is_some() {
+                patterns.push(node::BindingPatternTypeArray::Elision);
+                continue;
+            }
+
+            if cursor.next_if(Punctuator::Spread)?.is_some() {
+                if let Some(pattern) =
+                    parse_nested_binding_pattern(cursor, self.allow_yield, self.allow_await)?
+                {
+                    patterns.push(node::BindingPatternTypeArray::BindingPatternRest { pattern });
+                } else {
+                    let ident =
+                        BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                    patterns.push(node::BindingPatternTypeArray::SingleNameRest { ident });
+                }
+
+                let next = cursor.next()?.ok_or(ParseError::AbruptEnd)?;
+                if next.kind() != &TokenKind::Punctuator(Punctuator::CloseBracket) {
+                    return Err(ParseError::general(
+                        "rest element must be the last element of an array binding pattern",
+                        next.span().start(),
+                    ));
+                }
+                break;
+            }
+
+            if let Some(pattern) =
+                parse_nested_binding_pattern(cursor, self.allow_yield, self.allow_await)?
+            {
+                let default_init =
+                    parse_binding_initializer(cursor, self.allow_yield, self.allow_await)?;
+                patterns.push(node::BindingPatternTypeArray::BindingPattern {
+                    pattern,
+                    default_init,
+                });
+            } else {
+                let ident =
+                    BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let default_init =
+                    parse_binding_initializer(cursor, self.allow_yield, self.allow_await)?;
+                patterns.push(node::BindingPatternTypeArray::SingleName {
+                    ident,
+                    default_init,
+                });
+            }
+
+            if cursor.next_if(Punctuator::Comma)?.is_none() {
+                cursor.expect(Punctuator::CloseBracket, "array binding pattern")?;
+                break;
+            }
+        }
+
+        Ok(patterns)
+    }
+}
+
+/// Parses a nested object or array binding pattern, if the next token starts one.
+fn parse_nested_binding_pattern<R>(
+    cursor: &mut Cursor<R>,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+) -> Result<Option<node::DeclarationPattern>, ParseError>
+where
+    R: Read,
+{
+    let next = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+    match next.kind() {
+        TokenKind::Punctuator(Punctuator::OpenBlock) => {
+            let bindings = ObjectBindingPattern::new(allow_yield, allow_await).parse(cursor)?;
+            Ok(Some(node::DeclarationPattern::Object(
+                node::DeclarationPatternObject::new(bindings, None),
+            )))
+        }
+        TokenKind::Punctuator(Punctuator::OpenBracket) => {
+            let bindings = ArrayBindingPattern::new(allow_yield, allow_await).parse(cursor)?;
+            Ok(Some(node::DeclarationPattern::Array(
+                node::DeclarationPatternArray::new(bindings, None),
+            )))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Parses the optional initializer of an element of a binding pattern.
+///
+/// The initializer always allows the `in` operator, even in the head of a `for` loop.
+fn parse_binding_initializer<R>(
+    cursor: &mut Cursor<R>,
+    allow_yield: AllowYield,
+    allow_await: AllowAwait,
+) -> Result<Option<Node>, ParseError>
+where
+    R: Read,
+{
+    match cursor.peek(0)? {
+        Some(tok) if tok.kind() == &TokenKind::Punctuator(Punctuator::Assign) => Ok(Some(
+            Initializer::new(true, allow_yield, allow_await).parse(cursor)?,
+        )),
+        _ => Ok(None),
+    }
+}
@@ -68,10 +68,15 @@ where
 
         cursor.expect(Punctuator::CloseParen, "switch statement")?;
 
-        let (cases, default) =
+        let (cases, default, default_position) =
             CaseBlock::new(self.allow_yield, self.allow_await, self.allow_return).parse(cursor)?;
 
-        Ok(Switch::new(condition, cases, default))
+        Ok(Switch::with_default_position(
+            condition,
+            cases,
+            default,
+            default_position,
+        ))
     }
 }
 
@@ -108,13 +113,15 @@ impl<R> TokenParser<R> for CaseBlock
 where
     R: Read,
 {
-    type Output = (Box<[node::Case]>, Option<node::StatementList>);
+    /// The cases, the default clause if any, and the number of cases before the default clause.
+    type Output = (Box<[node::Case]>, Option<node::StatementList>, usize);
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
         cursor.expect(Punctuator::OpenBlock, "switch case block")?;
 
         let mut cases = Vec::new();
         let mut default = None;
+        let mut default_position = 0;
 
         loop {
             match cursor.next()? {
@@ -157,6 +164,7 @@ where
                     .parse(cursor)?;
 
                     default = Some(statement_list);
+                    default_position = cases.len();
                 }
                 Some(token) if token.kind() == &TokenKind::Punctuator(Punctuator::CloseBlock) => {
                     break
@@ -176,6 +184,10 @@ where
             }
         }
 
-        Ok((cases.into_boxed_slice(), default))
+        if default.is_none() {
+            default_position = cases.len();
+        }
+
+        Ok((cases.into_boxed_slice(), default, default_position))
     }
 }
@@ -189,3 +189,28 @@ fn check_seperated_switch() {
         ],
     );
 }
+
+/// Checks parsing of a switch with the default clause between cases.
+#[test]
+fn check_switch_default_between_cases() {
+    check_parser(
+        r#"
+        switch (a) {
+            case 1:
+            default:
+                b;
+            case 2:
+        }
+        "#,
+        vec![Switch::with_default_position(
+            Identifier::from("a"),
+            vec![
+                Case::new(Const::from(1), Vec::<Node>::new()),
+                Case::new(Const::from(2), Vec::<Node>::new()),
+            ],
+            Some(vec![Node::from(Identifier::from("b"))]),
+            1,
+        )
+        .into()],
+    );
+}
@@ -3,14 +3,14 @@
 use crate::{
     syntax::{
         ast::{
-            node::{Declaration, DeclarationList},
+            node::{Declaration, DeclarationList, Node},
             Keyword, Punctuator,
         },
         lexer::TokenKind,
         parser::{
             cursor::{Cursor, SemicolonResult},
             expression::Initializer,
-            statement::BindingIdentifier,
+            statement::{ArrayBindingPattern, BindingIdentifier, ObjectBindingPattern},
             AllowAwait, AllowIn, AllowYield, ParseError, TokenParser,
         },
     },
@@ -29,14 +29,14 @@ use std::io::Read;
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Statements/var
 /// [spec]: https://tc39.es/ecma262/#prod-VariableStatement
 #[derive(Debug, Clone, Copy)]
-pub(in crate::syntax::parser::statement) struct VariableStatement {
+pub(in crate::syntax::parser) struct VariableStatement {
     allow_yield: AllowYield,
     allow_await: AllowAwait,
 }
 
 impl VariableStatement {
     /// Creates a new `VariableStatement` parser.
-    pub(in crate::syntax::parser::statement) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
+    pub(in crate::syntax::parser) fn new<Y, A>(allow_yield: Y, allow_await: A) -> Self
     where
         Y: Into<AllowYield>,
         A: Into<AllowAwait>,
@@ -160,6 +160,23 @@ impl VariableDeclaration {
     }
 }
 
+impl VariableDeclaration {
+    /// Parses the initializer of the declaration, if there is one.
+    fn parse_initializer<R>(&self, cursor: &mut Cursor<R>) -> Result<Option<Node>, ParseError>
+    where
+        R: Read,
+    {
+        if let Some(t) = cursor.peek(0)? {
+            if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
+                return Ok(Some(
+                    Initializer::new(true, self.allow_yield, self.allow_await).parse(cursor)?,
+                ));
+            }
+        }
+        Ok(None)
+    }
+}
+
 impl<R> TokenParser<R> for VariableDeclaration
 where
     R: Read,
@@ -167,20 +184,44 @@ where
     type Output = Declaration;
 
     fn parse(self, cursor: &mut Cursor<R>) -> Result<Self::Output, ParseError> {
-        // TODO: BindingPattern
-
-        let name = BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+        let peek_token = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+        let declaration = match peek_token.kind() {
+            TokenKind::Punctuator(Punctuator::OpenBlock) => {
+                let bindings =
+                    ObjectBindingPattern::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let init = self.parse_initializer(cursor)?;
+                Declaration::new_with_object_pattern(bindings, init)
+            }
+            TokenKind::Punctuator(Punctuator::OpenBracket) => {
+                let bindings =
+                    ArrayBindingPattern::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let init = self.parse_initializer(cursor)?;
+                Declaration::new_with_array_pattern(bindings, init)
+            }
+            _ => {
+                let name =
+                    BindingIdentifier::new(self.allow_yield, self.allow_await).parse(cursor)?;
+                let init = self.parse_initializer(cursor)?;
 
-        let init = if let Some(t) = cursor.peek(0)? {
-            if *t.kind() == TokenKind::Punctuator(Punctuator::Assign) {
-                Some(Initializer::new(true, self.allow_yield, self.allow_await).parse(cursor)?)
-            } else {
-                None
+                return Ok(Declaration::new(name, init));
             }
-        } else {
-            None
         };
 
-        Ok(Declaration::new(name, init))
+        // Destructuring patterns always need an initializer, unless they are in the head of a
+        // `for-in` or `for-of` loop.
+        if declaration.init().is_none() {
+            let next = cursor.peek(0)?.ok_or(ParseError::AbruptEnd)?;
+            if next.kind() != &TokenKind::Keyword(Keyword::In)
+                && next.kind() != &TokenKind::Keyword(Keyword::Of)
+            {
+                return Err(ParseError::expected(
+                    vec![TokenKind::Punctuator(Punctuator::Assign)],
+                    cursor.next()?.ok_or(ParseError::AbruptEnd)?,
+                    "variable declaration",
+                ));
+            }
+        }
+
+        Ok(declaration)
     }
 }
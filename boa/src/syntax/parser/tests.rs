@@ -338,3 +338,76 @@ fn empty_statement() {
         ],
     );
 }
+
+/// Checks that the recoverable parser reports every independent syntax error of a script.
+#[test]
+fn recoverable_parse_collects_errors() {
+    let js = r#"
+        let a = ;
+        let b = 1;
+        var = 2;
+        let c = b;
+    "#;
+
+    let (list, errors) = Parser::new(js.as_bytes(), false).parse_all_recoverable();
+    assert!(list.is_none());
+    assert_eq!(errors.len(), 2);
+}
+
+/// Checks that the recoverable parser resumes after an error inside a block.
+#[test]
+fn recoverable_parse_synchronizes_on_block_end() {
+    let js = "function f() { return ) } let a = 1; let b = ;";
+
+    let (list, errors) = Parser::new(js.as_bytes(), false).parse_all_recoverable();
+    assert!(list.is_none());
+    assert_eq!(errors.len(), 2);
+}
+
+/// Checks that the recoverable parser returns the statement list of a valid script.
+#[test]
+fn recoverable_parse_valid_script() {
+    let js = "let a = 1; a + 2;";
+
+    let (list, errors) = Parser::new(js.as_bytes(), false).parse_all_recoverable();
+    assert!(errors.is_empty());
+    assert_eq!(
+        list,
+        Some(
+            Parser::new(js.as_bytes(), false)
+                .parse_all()
+                .expect("failed to parse")
+        )
+    );
+}
+
+/// Checks that `import.meta` is only allowed in module code.
+#[test]
+fn import_meta() {
+    assert_eq!(
+        Parser::new_module("import.meta.url;".as_bytes())
+            .parse_all()
+            .expect("failed to parse"),
+        StatementList::from(vec![GetConstField::new(Node::ImportMeta, "url").into()])
+    );
+
+    check_invalid("import.meta;");
+    assert!(Parser::new_module("import.url;".as_bytes())
+        .parse_all()
+        .is_err());
+}
+
+#[test]
+fn multi_line_template_error_line_numbers() {
+    let js = "let a = `x\n${\n1\n}\ny`;\nlet b = ;";
+    let error = Parser::new(js.as_bytes(), false)
+        .parse_all()
+        .expect_err("parsing should fail");
+    assert!(error.to_string().ends_with("at line 6, col 9"), "{}", error);
+
+    let js = "let a = `x\n${\n1\n}\n\\8`;";
+    let error = Parser::new(js.as_bytes(), false)
+        .parse_all()
+        .expect_err("parsing should fail");
+    assert!(error.to_string().ends_with("at position: 5:1"), "{}", error);
+}
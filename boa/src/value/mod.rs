@@ -267,6 +267,7 @@ impl Value {
         matches!(self, Self::Object(_))
     }
 
+    /// Returns the object if the value is an object, otherwise `None`.
     #[inline]
     pub fn as_object(&self) -> Option<GcObject> {
         match *self {
@@ -339,6 +340,9 @@ impl Value {
         matches!(self, Self::Rational(_) | Self::Integer(_))
     }
 
+    /// Returns the number if the value is a number, otherwise `None`.
+    ///
+    /// Unlike [`Value::to_number`], this never performs a type conversion.
     #[inline]
     pub fn as_number(&self) -> Option<f64> {
         match *self {
@@ -354,7 +358,7 @@ impl Value {
         matches!(self, Self::String(_))
     }
 
-    /// Returns the string if the values is a string, otherwise `None`.
+    /// Returns the string if the value is a string, otherwise `None`.
     #[inline]
     pub fn as_string(&self) -> Option<&RcString> {
         match self {
@@ -369,6 +373,7 @@ impl Value {
         matches!(self, Self::Boolean(_))
     }
 
+    /// Returns the boolean if the value is a boolean, otherwise `None`.
     #[inline]
     pub fn as_boolean(&self) -> Option<bool> {
         match self {
@@ -635,6 +635,112 @@ fn to_primitive() {
     assert_eq!(forward(&mut context, "primitive"), "42");
 }
 
+#[test]
+fn to_primitive_hints() {
+    let mut context = Context::new();
+    let src = r#"
+    let hints = [];
+    let money = {
+        [Symbol.toPrimitive](hint) {
+            hints.push(hint);
+            if (hint === "number") {
+                return 42;
+            }
+            if (hint === "string") {
+                return "forty-two";
+            }
+            return "default";
+        }
+    };
+    "#;
+    context.eval(src).unwrap();
+    assert_eq!(forward(&mut context, "money + 1"), r#""default1""#);
+    assert_eq!(forward(&mut context, "money * 2"), "84");
+    assert_eq!(forward(&mut context, "+money"), "42");
+    assert_eq!(forward(&mut context, "`${money}`"), r#""forty-two""#);
+    assert_eq!(forward(&mut context, "String(money)"), r#""forty-two""#);
+    assert_eq!(forward(&mut context, "money == 'default'"), "true");
+    assert_eq!(
+        forward(&mut context, "hints.join()"),
+        r#""default,number,number,string,string,default""#
+    );
+}
+
+#[test]
+fn to_primitive_returning_object() {
+    let mut context = Context::new();
+    let src = r#"
+    let a = { [Symbol.toPrimitive]() { return {}; } };
+    "#;
+    context.eval(src).unwrap();
+    assert_eq!(
+        forward(&mut context, "a + 1"),
+        r#"Uncaught "TypeError": "Symbol.toPrimitive cannot return an object""#
+    );
+}
+
+#[test]
+fn typed_accessors() {
+    let mut context = Context::new();
+
+    assert_eq!(Value::integer(2).as_number(), Some(2.0));
+    assert_eq!(Value::rational(1.5).as_number(), Some(1.5));
+    assert_eq!(
+        Value::string("hi").as_string().map(|s| s.as_str()),
+        Some("hi")
+    );
+    assert_eq!(Value::boolean(false).as_boolean(), Some(false));
+
+    let object = Value::new_object(&mut context);
+    assert!(object.as_object().is_some());
+}
+
+#[test]
+fn typed_accessors_do_not_coerce() {
+    let mut context = Context::new();
+
+    assert_eq!(Value::string("1").as_number(), None);
+    assert_eq!(Value::boolean(true).as_number(), None);
+    assert_eq!(Value::integer(1).as_string(), None);
+    assert_eq!(Value::integer(1).as_boolean(), None);
+    assert_eq!(Value::null().as_boolean(), None);
+    assert!(Value::undefined().as_object().is_none());
+    assert!(Value::string("obj").as_object().is_none());
+
+    let object = Value::new_object(&mut context);
+    assert_eq!(object.as_number(), None);
+    assert_eq!(object.as_string(), None);
+    assert_eq!(object.as_boolean(), None);
+}
+
+#[test]
+fn json_round_trip() {
+    let mut context = Context::new();
+    let json = serde_json::json!({
+        "a": 1,
+        "b": [true, null, "str", 2.5],
+        "c": { "d": -3 }
+    });
+
+    let value = Value::from_json(json.clone(), &mut context);
+    assert_eq!(value.to_json(&mut context).unwrap(), Some(json));
+}
+
+#[test]
+fn to_json_skips_unserializable_properties() {
+    let mut context = Context::new();
+    let value = forward_val(
+        &mut context,
+        "({ x: [1, 'two', { y: false }], z: null, f: function() {}, u: undefined })",
+    )
+    .unwrap();
+
+    assert_eq!(
+        value.to_json(&mut context).unwrap(),
+        Some(serde_json::json!({ "x": [1, "two", { "y": false }], "z": null }))
+    );
+}
+
 /// Test cyclic conversions that previously caused stack overflows
 /// Relevant mitigations for these are in `GcObject::ordinary_to_primitive` and
 /// `GcObject::to_json`
@@ -656,6 +762,14 @@ mod cyclic_conversions {
         );
     }
 
+    #[test]
+    fn value_to_json_cyclic() {
+        let mut context = Context::new();
+        let value = forward_val(&mut context, "let a = {}; a.self = a; a").unwrap();
+
+        assert!(value.to_json(&mut context).is_err());
+    }
+
     #[test]
     fn to_json_noncyclic() {
         let mut context = Context::new();
@@ -710,8 +824,7 @@ mod cyclic_conversions {
         "#;
 
         let value = forward_val(&mut context, src).unwrap();
-        // There isn't an as_boolean function for some reason?
-        assert_eq!(value, Value::Boolean(true));
+        assert_eq!(value.as_boolean(), Some(true));
     }
 
     #[test]
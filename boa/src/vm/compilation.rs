@@ -1,5 +1,11 @@
 use super::*;
-use crate::{syntax::ast::Const, syntax::ast::Node, value::RcBigInt, value::RcString};
+use crate::{
+    syntax::ast::{
+        node::{Declaration, DeclarationList},
+        Const, Node,
+    },
+    value::{RcBigInt, RcString},
+};
 
 #[derive(Debug, Default)]
 /// The compiler struct holds all the instructions.
@@ -65,42 +71,66 @@ impl CodeGen for Node {
             Node::UnaryOp(ref op) => op.compile(compiler),
             Node::VarDeclList(ref list) => {
                 for var_decl in list.as_ref() {
-                    let name = var_decl.name();
-                    let index = compiler.pool.len();
-                    compiler.add_instruction(Instruction::DefVar(index));
-                    compiler.pool.push(name.into());
+                    match var_decl {
+                        Declaration::Identifier { ident, init } => {
+                            let index = compiler.pool.len();
+                            compiler.add_instruction(Instruction::DefVar(index));
+                            compiler.pool.push(ident.as_ref().into());
 
-                    if let Some(v) = var_decl.init() {
-                        v.compile(compiler);
-                        compiler.add_instruction(Instruction::InitLexical(index))
-                    };
+                            if let Some(v) = init {
+                                v.compile(compiler);
+                                compiler.add_instruction(Instruction::InitLexical(index))
+                            };
+                        }
+                        Declaration::Pattern(_) => {
+                            let decl_list = DeclarationList::Var(var_decl.clone().into());
+                            compiler
+                                .add_instruction(Instruction::Interpret(Box::new(decl_list.into())))
+                        }
+                    }
                 }
             }
             Node::LetDeclList(ref list) => {
                 for let_decl in list.as_ref() {
-                    let name = let_decl.name();
-                    let index = compiler.pool.len();
-                    compiler.add_instruction(Instruction::DefLet(index));
-                    compiler.pool.push(name.into());
+                    match let_decl {
+                        Declaration::Identifier { ident, init } => {
+                            let index = compiler.pool.len();
+                            compiler.add_instruction(Instruction::DefLet(index));
+                            compiler.pool.push(ident.as_ref().into());
 
-                    // If name has a value we can init here too
-                    if let Some(v) = let_decl.init() {
-                        v.compile(compiler);
-                        compiler.add_instruction(Instruction::InitLexical(index))
-                    };
+                            // If name has a value we can init here too
+                            if let Some(v) = init {
+                                v.compile(compiler);
+                                compiler.add_instruction(Instruction::InitLexical(index))
+                            };
+                        }
+                        Declaration::Pattern(_) => {
+                            let decl_list = DeclarationList::Let(let_decl.clone().into());
+                            compiler
+                                .add_instruction(Instruction::Interpret(Box::new(decl_list.into())))
+                        }
+                    }
                 }
             }
             Node::ConstDeclList(ref list) => {
                 for const_decl in list.as_ref() {
-                    let name = const_decl.name();
-                    let index = compiler.pool.len();
-                    compiler.add_instruction(Instruction::DefConst(index));
-                    compiler.pool.push(name.into());
+                    match const_decl {
+                        Declaration::Identifier { ident, init } => {
+                            let index = compiler.pool.len();
+                            compiler.add_instruction(Instruction::DefConst(index));
+                            compiler.pool.push(ident.as_ref().into());
 
-                    if let Some(v) = const_decl.init() {
-                        v.compile(compiler);
-                        compiler.add_instruction(Instruction::InitLexical(index))
-                    };
+                            if let Some(v) = init {
+                                v.compile(compiler);
+                                compiler.add_instruction(Instruction::InitLexical(index))
+                            };
+                        }
+                        Declaration::Pattern(_) => {
+                            let decl_list = DeclarationList::Const(const_decl.clone().into());
+                            compiler
+                                .add_instruction(Instruction::Interpret(Box::new(decl_list.into())))
+                        }
+                    }
                 }
             }
             Node::Identifier(ref name) => name.compile(compiler),
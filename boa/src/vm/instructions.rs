@@ -1,4 +1,6 @@
-#[derive(Debug)]
+use crate::syntax::ast::Node;
+
+#[derive(Debug, Clone)]
 pub enum Instruction {
     Undefined,
     Null,
@@ -70,6 +72,11 @@ pub enum Instruction {
     // Binding values
     /// Find a binding on the environment chain and push its value.
     GetName(String),
+
+    // Fallback
+    /// Run a node with the tree-walking interpreter, for syntax the VM can't compile yet.
+    Interpret(Box<Node>),
+
     // Objects
     // Create and push a new object onto the stack
     NewObject,
@@ -121,6 +128,7 @@ impl std::fmt::Display for Instruction {
             Self::DefLet(name) => write!(f, "DefLet({})", name),
             Self::DefConst(name) => write!(f, "DefConst({})", name),
             Self::InitLexical(value) => write!(f, "InitLexical({})", value),
+            Self::Interpret(ref node) => write!(f, "Interpret({})", node),
             Self::NewObject => write!(f, "NewObject"),
         }
     }
@@ -2,8 +2,9 @@
 //! This module will provide an instruction set for the AST to use, various traits, plus an interpreter to execute those instructions
 
 use crate::{
-    environment::lexical_environment::VariableScope, exec::InterpreterState, BoaProfiler, Context,
-    Result, Value,
+    environment::lexical_environment::VariableScope,
+    exec::{Executable, InterpreterState},
+    BoaProfiler, Context, Result, Value,
 };
 
 pub(crate) mod compilation;
@@ -13,6 +14,27 @@ pub use compilation::Compiler;
 pub use instructions::Instruction;
 use std::time::{Duration, Instant};
 
+/// The compiled bytecode of a script, which can be run any number of times without parsing or
+/// compiling it again.
+///
+/// It is created by [`Context::compile`] and run by [`Context::execute`].
+#[derive(Debug, Clone)]
+pub struct CodeBlock {
+    /// Vector of instructions
+    instructions: Vec<Instruction>,
+    /// The constant data indexed by the instructions
+    pool: Vec<Value>,
+}
+
+impl From<Compiler> for CodeBlock {
+    fn from(compiler: Compiler) -> Self {
+        Self {
+            instructions: compiler.instructions,
+            pool: compiler.pool,
+        }
+    }
+}
+
 /// Virtual Machine.
 #[derive(Debug)]
 pub struct VM<'a> {
@@ -39,12 +61,17 @@ mod tests;
 
 impl<'a> VM<'a> {
     pub fn new(compiler: Compiler, ctx: &'a mut Context) -> Self {
+        Self::with_code_block(compiler.into(), ctx)
+    }
+
+    /// Creates a virtual machine running the given compiled code.
+    pub fn with_code_block(code_block: CodeBlock, ctx: &'a mut Context) -> Self {
         let trace = ctx.trace;
         Self {
             ctx,
             idx: 0,
-            instructions: compiler.instructions,
-            pool: compiler.pool,
+            instructions: code_block.instructions,
+            pool: code_block.pool,
             stack: vec![],
             stack_pointer: 0,
             is_trace: trace,
@@ -183,7 +210,7 @@ impl<'a> VM<'a> {
                         ));
                     }
                     let key = l.to_property_key(self.ctx)?;
-                    Some(self.ctx.has_property(&r, &key).into())
+                    Some(self.ctx.has_property(&r, &key)?.into())
                 }
                 Instruction::InstanceOf => {
                     let r = self.pop();
@@ -280,6 +307,11 @@ impl<'a> VM<'a> {
                         Some(val)
                     }
                 },
+                Instruction::Interpret(ref node) => {
+                    node.run(self.ctx)?;
+
+                    None
+                }
                 // Create a new object and push to the stack
                 Instruction::NewObject => Some(Value::new_object(self.ctx)),
             };
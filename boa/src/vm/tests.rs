@@ -1,4 +1,4 @@
-use crate::exec;
+use crate::{exec, parse, property::Attribute, Context};
 
 #[test]
 fn typeof_string() {
@@ -27,3 +27,38 @@ fn basic_op() {
     "#;
     assert_eq!(&exec(basic_op), "3");
 }
+
+#[test]
+fn destructuring_declaration_falls_back_to_interpreter() {
+    let destructuring = r#"
+        const o = {};
+        let { a = 5 } = o;
+        a
+    "#;
+    assert_eq!(&exec(destructuring), "5");
+}
+
+#[test]
+fn compile_once_execute_twice() {
+    let mut context = Context::new();
+    let statement_list = parse("a * 2", false).expect("failed to parse");
+    let code_block = context.compile(&statement_list);
+
+    for (a, expected) in [(1, 2.0), (21, 42.0)].iter() {
+        let mut global = context.construct_object();
+        global.insert_property("a", *a, Attribute::all());
+        context.set_global_object(global);
+
+        let value = context.execute(&code_block).expect("failed to execute");
+        assert_eq!(value.as_number(), Some(*expected));
+    }
+}
+
+#[test]
+fn execute_after_error() {
+    let mut context = Context::new();
+    let _ = context.eval("not_defined");
+
+    let value = context.eval("1 + 2").expect("failed to execute");
+    assert_eq!(value.as_number(), Some(3.0));
+}